@@ -113,6 +113,7 @@ impl SamplingClockSourceOperation for EnsembleClkProtocol {
 
 /// Parameters of sample format converter.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnsembleConverterParameters {
     /// The target of sample format converter.
     pub format_target: FormatConvertTarget,
@@ -164,6 +165,7 @@ impl EnsembleParameterProtocol<EnsembleConverterParameters> for EnsembleConverte
 
 /// Parameters of display meters.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnsembleDisplayParameters {
     /// Whether to enable/disable display.
     pub enabled: bool,
@@ -219,6 +221,7 @@ impl EnsembleParameterProtocol<EnsembleDisplayParameters> for EnsembleDisplayPro
 /// Parameters of analog/digital inputs. The gains, phantoms, and polarities parameters
 /// are available when channel 0-3 levels are for mic.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnsembleInputParameters {
     /// Whether to enable/disable limitter of analog inputs.
     pub limits: [bool; 8],
@@ -333,6 +336,7 @@ impl EnsembleParameterProtocol<EnsembleInputParameters> for EnsembleInputProtoco
 
 /// Parameters of analog/digital outputs.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnsembleOutputParameters {
     /// The volume of 1st pair of analog outputs.
     pub vol: u8,
@@ -423,6 +427,7 @@ impl EnsembleParameterProtocol<EnsembleOutputParameters> for EnsembleOutputProto
 
 /// Parameters of input/output source.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnsembleSourceParameters {
     /// To (18):
     ///   analog-output-0, analog-output-1, analog-output-2, analog-output-3,
@@ -613,6 +618,7 @@ impl EnsembleParameterProtocol<EnsembleSourceParameters> for EnsembleSourceProto
 
 /// Parameters of signal multiplexer.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnsembleMixerParameters {
     /// To (4):
     ///   mixer-output-0, mixer-output-1, mixer-output-2, mixer-output-3
@@ -628,9 +634,39 @@ pub struct EnsembleMixerParameters {
     /// adat-input-0, adat-input-1, adat-input-2, adat-input-3,
     /// adat-input-4, adat-input-5, adat-input-6, adat-input-7,
     /// spdif-input-0, spdif-input-1,
+    #[cfg_attr(feature = "serde", serde(with = "src_gains_serde"))]
     pub src_gains: [[i16; 36]; 4],
 }
 
+// Serde supports arrays up to 32 elements, thus the gains are expressed by sequence instead.
+#[cfg(feature = "serde")]
+mod src_gains_serde {
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        gains: &[[i16; 36]; 4],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let seqs: Vec<&[i16]> = gains.iter().map(|gains| &gains[..]).collect();
+        seqs.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<[[i16; 36]; 4], D::Error> {
+        let seqs = Vec::<Vec<i16>>::deserialize(deserializer)?;
+        if seqs.len() != 4 || seqs.iter().any(|seq| seq.len() != 36) {
+            Err(D::Error::custom("Unexpected number of gains"))?;
+        }
+        let mut gains = [[0; 36]; 4];
+        gains
+            .iter_mut()
+            .zip(&seqs)
+            .for_each(|(gains, seq)| gains.copy_from_slice(seq));
+        Ok(gains)
+    }
+}
+
 impl Default for EnsembleMixerParameters {
     fn default() -> Self {
         let mut src_gains = [[0; 36]; 4];
@@ -795,6 +831,7 @@ impl EnsembleParameterProtocol<EnsembleMixerParameters> for EnsembleMixerProtoco
 
 /// Parameters of stream mode.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnsembleStreamParameters {
     /// The mode of isochronous stream in IEEE 1394 bus.
     pub mode: StreamMode,
@@ -892,6 +929,7 @@ pub trait EnsembleParameterProtocol<T: Copy>: EnsembleParametersConverter<T> {
 
 /// The target of input for knob.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum KnobInputTarget {
     /// 1st microphone.
     Mic0,
@@ -911,6 +949,7 @@ impl Default for KnobInputTarget {
 
 /// The target of output for knob.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum KnobOutputTarget {
     /// 1st pair of analog outputs.
     AnalogOutputPair0,
@@ -1055,6 +1094,7 @@ impl EnsembleMeterProtocol {
 
 /// The nominal level of analog input 0-7.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InputNominalLevel {
     /// +4 dBu.
     Professional,
@@ -1072,6 +1112,7 @@ impl Default for InputNominalLevel {
 
 /// The nominal level of analog ouput 0-7.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OutputNominalLevel {
     /// +4 dBu.
     Professional,
@@ -1087,6 +1128,7 @@ impl Default for OutputNominalLevel {
 
 /// The mode of signal in optical interface.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OptIfaceMode {
     Spdif,
     Adat,
@@ -1116,6 +1158,7 @@ fn opt_iface_mode_from_val(val: u8) -> OptIfaceMode {
 
 /// The target to convert sample format from 24 bit to 16 bit.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FormatConvertTarget {
     /// Disabled.
     Disabled,
@@ -1145,6 +1188,7 @@ impl Default for FormatConvertTarget {
 
 /// The target to convert sample rate.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RateConvertTarget {
     /// Disabled.
     Disabled,
@@ -1166,6 +1210,7 @@ impl Default for RateConvertTarget {
 
 /// The rate to convert sample rate.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RateConvertRate {
     /// To 44.1 kHz.
     R44100,
@@ -1527,6 +1572,7 @@ impl From<&[u8]> for EnsembleCmd {
 
 /// The mode of stream format.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StreamMode {
     /// For 18 channels capture and 18 channels playback.
     Format18x18,
@@ -1544,6 +1590,7 @@ impl Default for StreamMode {
 
 /// The target of display meter.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DisplayMeterTarget {
     /// For outputs.
     Output,
@@ -1655,10 +1702,7 @@ impl Default for EnsembleOperation {
     fn default() -> Self {
         Self {
             cmd: Default::default(),
-            op: VendorDependent {
-                company_id: APOGEE_OUI,
-                data: Default::default(),
-            },
+            op: VendorDependent::new(&APOGEE_OUI),
         }
     }
 }
@@ -1689,6 +1733,7 @@ impl AvcControl for EnsembleOperation {
     }
 
     fn parse_operands(&mut self, addr: &AvcAddr, operands: &[u8]) -> Result<(), AvcRespParseError> {
+        self.op.check_company_id(operands)?;
         AvcControl::parse_operands(&mut self.op, addr, operands).map(|_| {
             // NOTE: parameters are retrieved by HwStatus command only.
             match &mut self.cmd {
@@ -1734,6 +1779,46 @@ mod test {
         assert_eq!(params, p);
     }
 
+    #[test]
+    fn display_cmds_operand_layout() {
+        [
+            (HwCmd::DisplayMode(true), [HwCmd::DISPLAY_MODE, 0x01]),
+            (
+                HwCmd::DisplayIlluminate(true),
+                [HwCmd::DISPLAY_ILLUMINATE, 0x01],
+            ),
+            (
+                HwCmd::DisplayIlluminate(false),
+                [HwCmd::DISPLAY_ILLUMINATE, 0x00],
+            ),
+            (
+                HwCmd::DisplayTarget(DisplayMeterTarget::Input),
+                [HwCmd::DISPLAY_TARGET, 0x01],
+            ),
+            (
+                HwCmd::DisplayTarget(DisplayMeterTarget::Output),
+                [HwCmd::DISPLAY_TARGET, 0x00],
+            ),
+            (
+                HwCmd::DisplayOverhold(true),
+                [HwCmd::DISPLAY_OVERHOLD, 0x01],
+            ),
+        ]
+        .iter()
+        .for_each(|(hw_cmd, data)| {
+            let cmd = EnsembleCmd::Hw(hw_cmd.clone());
+            let mut op = EnsembleOperation::new(cmd.clone());
+            let operands = AvcControl::build_operands(&mut op, &AvcAddr::Unit).unwrap();
+
+            // The data is padded to align to 3 quadlets.
+            let mut expected = APOGEE_OUI.to_vec();
+            expected.extend_from_slice(&[EnsembleCmd::HW, data[0], data[1], 0xff, 0xff, 0xff]);
+            assert_eq!(operands, expected);
+
+            assert_eq!(EnsembleCmd::from(&operands[3..]), cmd);
+        });
+    }
+
     #[test]
     fn input_params_and_cmds() {
         let params = EnsembleInputParameters {
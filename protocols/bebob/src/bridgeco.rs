@@ -594,9 +594,9 @@ impl Default for BcoLocation {
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct BcoChannelInfo {
     /// The position of channel in data frame.
-    pos: u8,
+    pub pos: u8,
     /// The location of channel for playback or capture.
-    loc: BcoLocation,
+    pub loc: BcoLocation,
 }
 
 impl BcoChannelInfo {
@@ -634,7 +634,7 @@ impl Default for BcoChannelInfo {
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct BcoCluster {
     /// The entries of cluster.
-    entries: Vec<BcoChannelInfo>,
+    pub entries: Vec<BcoChannelInfo>,
 }
 
 impl BcoCluster {
@@ -1044,6 +1044,168 @@ impl AvcStatus for ExtendedPlugInfo {
     }
 }
 
+fn plug_avc_addr(plug_addr: &BcoPlugAddr) -> AvcAddr {
+    match plug_addr.mode {
+        BcoPlugAddrMode::Unit(_) => AvcAddr::Unit,
+        _ => AvcAddr::Subunit(MUSIC_SUBUNIT_0),
+    }
+}
+
+/// Retrieve the number of channels in the plug. The command is addressed to the unit for unit
+/// plug, else to the first music subunit.
+pub fn plug_channel_count<T: Ta1394Avc<Error>>(
+    avc: &T,
+    plug_addr: &BcoPlugAddr,
+    timeout_ms: u32,
+) -> Result<usize, Error> {
+    let mut op = ExtendedPlugInfo::new(plug_addr, BcoPlugInfo::ChCount(0xff));
+    avc.status(&plug_avc_addr(plug_addr), &mut op, timeout_ms)
+        .map_err(from_avc_err)?;
+    if let BcoPlugInfo::ChCount(count) = op.info {
+        Ok(count as usize)
+    } else {
+        unreachable!();
+    }
+}
+
+/// Retrieve the list of clusters in the plug, with the position and location of channels. The
+/// command is addressed to the unit for unit plug, else to the first music subunit.
+pub fn plug_channel_positions<T: Ta1394Avc<Error>>(
+    avc: &T,
+    plug_addr: &BcoPlugAddr,
+    timeout_ms: u32,
+) -> Result<Vec<BcoCluster>, Error> {
+    let mut op = ExtendedPlugInfo::new(plug_addr, BcoPlugInfo::ChPositions(Vec::new()));
+    avc.status(&plug_avc_addr(plug_addr), &mut op, timeout_ms)
+        .map_err(from_avc_err)?;
+    if let BcoPlugInfo::ChPositions(clusters) = op.info {
+        Ok(clusters)
+    } else {
+        unreachable!();
+    }
+}
+
+// Walk the entries of stream format in the plug until the unit rejects the index, and return the
+// index of the first entry for which the predicate returns true.
+fn find_stream_format_index<T, F>(
+    avc: &T,
+    plug_addr: &BcoPlugAddr,
+    timeout_ms: u32,
+    mut predicate: F,
+) -> Result<Option<u8>, Error>
+where
+    T: Ta1394Avc<Error>,
+    F: FnMut(&BcoCompoundAm824Stream) -> bool,
+{
+    for index in 0..=u8::MAX {
+        let mut op = ExtendedStreamFormatList::new(plug_addr, index);
+        match avc.status(&plug_avc_addr(plug_addr), &mut op, timeout_ms) {
+            Ok(_) => (),
+            Err(Ta1394AvcError::RespParse(
+                AvcRespParseError::Rejected
+                | AvcRespParseError::NotImplemented
+                | AvcRespParseError::UnexpectedStatus,
+            )) => break,
+            Err(err) => Err(from_avc_err(err))?,
+        }
+        if let Some(format) = op.stream_format.as_bco_compound_am824_stream() {
+            if predicate(format) {
+                return Ok(Some(index));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Retrieve the list of nominal frequencies supported by the plug, by walking the entries of
+/// stream format until the unit rejects the index. Each frequency is listed once in the order of
+/// entries. The command is addressed to the unit for unit plug, else to the first music subunit.
+pub fn supported_frequencies<T: Ta1394Avc<Error>>(
+    avc: &T,
+    plug_addr: &BcoPlugAddr,
+    timeout_ms: u32,
+) -> Result<Vec<u32>, Error> {
+    let mut freqs = Vec::new();
+    find_stream_format_index(avc, plug_addr, timeout_ms, |format| {
+        if !freqs.contains(&format.freq) {
+            freqs.push(format.freq);
+        }
+        false
+    })?;
+    Ok(freqs)
+}
+
+/// Select the index of the first entry of stream format in the plug, which is for the nominal
+/// frequency and has audio channels at least the given number, by walking the entries until the
+/// unit rejects the index. The command is addressed to the unit for unit plug, else to the first
+/// music subunit.
+pub fn select_format_index<T: Ta1394Avc<Error>>(
+    avc: &T,
+    plug_addr: &BcoPlugAddr,
+    freq: u32,
+    min_channels: usize,
+    timeout_ms: u32,
+) -> Result<u8, Error> {
+    find_stream_format_index(avc, plug_addr, timeout_ms, |format| {
+        format.freq == freq && format.audio_channel_count() >= min_channels
+    })?
+    .ok_or_else(|| {
+        let msg = format!(
+            "No stream format for {} Hz with {} audio channels at least",
+            freq, min_channels
+        );
+        Error::new(FileError::Nxio, &msg)
+    })
+}
+
+/// Retrieve the number of isochronous and external plugs for inputs and outputs in the unit, by
+/// AV/C PLUG INFO command.
+pub fn unit_plug_counts<T: Ta1394Avc<Error>>(
+    avc: &T,
+    timeout_ms: u32,
+) -> Result<PlugInfoUnitIsocExtData, Error> {
+    let mut op = PlugInfo::new_for_unit_isoc_ext_plugs();
+    avc.status(&AvcAddr::Unit, &mut op, timeout_ms)
+        .map_err(from_avc_err)?;
+    if let PlugInfo::Unit(PlugInfoUnitData::IsocExt(data)) = op {
+        Ok(data)
+    } else {
+        unreachable!();
+    }
+}
+
+/// Retrieve the list of types of subunits attached to the unit and the number of subunits for
+/// each type, by walking the pages of AV/C SUBUNIT INFO command until any page is not filled or
+/// the unit rejects the page.
+pub fn subunit_counts<T: Ta1394Avc<Error>>(
+    avc: &T,
+    timeout_ms: u32,
+) -> Result<Vec<(AvcSubunitType, u8)>, Error> {
+    const ENTRIES_PER_PAGE: usize = 4;
+    const EXTENSION_CODE: u8 = 0x07;
+
+    let mut counts = Vec::new();
+    for page in 0..8 {
+        let mut op = SubunitInfo::new(page, EXTENSION_CODE);
+        match avc.status(&AvcAddr::Unit, &mut op, timeout_ms) {
+            Ok(_) => (),
+            Err(Ta1394AvcError::RespParse(
+                AvcRespParseError::Rejected
+                | AvcRespParseError::NotImplemented
+                | AvcRespParseError::UnexpectedStatus,
+            )) => break,
+            Err(err) => Err(from_avc_err(err))?,
+        }
+        op.entries
+            .iter()
+            .for_each(|entry| counts.push((entry.subunit_type, entry.maximum_id + 1)));
+        if op.entries.len() < ENTRIES_PER_PAGE {
+            break;
+        }
+    }
+    Ok(counts)
+}
+
 //
 // Bco Extended Subunit Info command
 //
@@ -1395,6 +1557,23 @@ impl Default for BcoCompoundAm824Stream {
     }
 }
 
+impl BcoCompoundAm824Stream {
+    /// The number of audio channels in the stream, except for MIDI conformant and reserved
+    /// entries.
+    pub fn audio_channel_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| {
+                !matches!(
+                    entry.format,
+                    BcoCompoundAm824StreamFormat::MidiConformant
+                        | BcoCompoundAm824StreamFormat::Reserved(_)
+                )
+            })
+            .fold(0, |total, entry| total + entry.count as usize)
+    }
+}
+
 /// Format of isochronous packet stream for Audio and Music data transmission.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum BcoAmStream {
@@ -1997,13 +2176,237 @@ mod test {
     use super::BcoPlugType;
     use super::ExtendedPlugInfo;
     use super::ExtendedSubunitInfo;
+    use super::{plug_channel_count, plug_channel_positions, supported_frequencies};
+    use super::{select_format_index, FileError};
+    use super::{subunit_counts, unit_plug_counts, PlugInfoUnitIsocExtData};
     use super::{BcoChannelInfo, BcoLocation};
     use super::{BcoCluster, BcoClusterInfo, BcoPortType};
     use super::{BcoIoPlugAddr, BcoIoPlugAddrMode};
     use super::{BcoPlugAddr, BcoPlugAddrMode, BcoPlugDirection};
     use super::{BcoPlugAddrFuncBlk, BcoPlugAddrSubunit, BcoPlugAddrUnit, BcoPlugAddrUnitType};
+    use glib::Error;
     use ta1394_avc_general::*;
 
+    struct TestAvc(Vec<u8>);
+
+    impl Ta1394Avc<Error> for TestAvc {
+        fn transaction(&self, command_frame: &[u8], _: u32) -> Result<Vec<u8>, Error> {
+            // The plug address and the type of information should be the same.
+            assert_eq!(&command_frame[3..10], &self.0[..7]);
+            let mut frame = command_frame[..3].to_vec();
+            frame[0] = AvcRespCode::ImplementedStable.into();
+            frame.extend_from_slice(&self.0);
+            Ok(frame)
+        }
+    }
+
+    #[test]
+    fn plug_channel_layout() {
+        let plug_addr =
+            BcoPlugAddr::new_for_unit(BcoPlugDirection::Input, BcoPlugAddrUnitType::Isoc, 0);
+        let avc = TestAvc(vec![0xc0, 0x00, 0x00, 0x00, 0x00, 0xff, 0x02, 0x0a]);
+        assert_eq!(plug_channel_count(&avc, &plug_addr, 100).unwrap(), 10);
+
+        let avc = TestAvc(vec![
+            0xc0, 0x00, 0x00, 0x00, 0x00, 0xff, 0x03, 0x02, 0x02, 0x01, 0x01, 0x02, 0x02, 0x01,
+            0x03, 0x04,
+        ]);
+        let clusters = plug_channel_positions(&avc, &plug_addr, 100).unwrap();
+        assert_eq!(
+            clusters,
+            vec![
+                BcoCluster {
+                    entries: vec![
+                        BcoChannelInfo {
+                            pos: 0x01,
+                            loc: BcoLocation::LeftFront,
+                        },
+                        BcoChannelInfo {
+                            pos: 0x02,
+                            loc: BcoLocation::RightFront,
+                        },
+                    ],
+                },
+                BcoCluster {
+                    entries: vec![BcoChannelInfo {
+                        pos: 0x03,
+                        loc: BcoLocation::LowFrequencyEffect,
+                    }],
+                },
+            ]
+        );
+
+        let plug_addr = BcoPlugAddr::new_for_subunit(BcoPlugDirection::Output, 0x01);
+        let avc = TestAvc(vec![0xc0, 0x01, 0x01, 0x01, 0xff, 0xff, 0x02, 0x08]);
+        assert_eq!(plug_channel_count(&avc, &plug_addr, 100).unwrap(), 8);
+    }
+
+    // The list of frequency codes for entries of stream format.
+    struct TestFormatListAvc(Vec<u8>);
+
+    impl Ta1394Avc<Error> for TestFormatListAvc {
+        fn transaction(&self, command_frame: &[u8], _: u32) -> Result<Vec<u8>, Error> {
+            assert_eq!(command_frame[3], 0xc1);
+            let mut frame = command_frame.to_vec();
+            match self.0.get(command_frame[10] as usize) {
+                Some(&freq_code) => {
+                    frame[0] = AvcRespCode::ImplementedStable.into();
+                    frame.extend_from_slice(&[0x90, 0x40, freq_code, 0x02, 0x01, 0x02, 0x06]);
+                }
+                None => frame[0] = AvcRespCode::Rejected.into(),
+            }
+            Ok(frame)
+        }
+    }
+
+    #[test]
+    fn plug_supported_frequencies() {
+        let plug_addr =
+            BcoPlugAddr::new_for_unit(BcoPlugDirection::Input, BcoPlugAddrUnitType::Isoc, 0);
+
+        let avc = TestFormatListAvc(vec![0x03, 0x04, 0x03, 0x04]);
+        assert_eq!(
+            supported_frequencies(&avc, &plug_addr, 100).unwrap(),
+            vec![44100, 48000]
+        );
+
+        let avc = TestFormatListAvc(Vec::new());
+        assert_eq!(
+            supported_frequencies(&avc, &plug_addr, 100).unwrap(),
+            Vec::<u32>::new()
+        );
+    }
+
+    // The list of frequency code, the number of PCM channels, and the number of MIDI channels for
+    // entries of stream format.
+    struct TestFormatChoiceAvc(Vec<(u8, u8, u8)>);
+
+    impl Ta1394Avc<Error> for TestFormatChoiceAvc {
+        fn transaction(&self, command_frame: &[u8], _: u32) -> Result<Vec<u8>, Error> {
+            assert_eq!(command_frame[3], 0xc1);
+            let mut frame = command_frame.to_vec();
+            match self.0.get(command_frame[10] as usize) {
+                Some(&(freq_code, pcm, midi)) => {
+                    frame[0] = AvcRespCode::ImplementedStable.into();
+                    frame.extend_from_slice(&[0x90, 0x40, freq_code, 0x02, 0x02]);
+                    frame.extend_from_slice(&[pcm, 0x06, midi, 0x0d]);
+                }
+                None => frame[0] = AvcRespCode::Rejected.into(),
+            }
+            Ok(frame)
+        }
+    }
+
+    #[test]
+    fn plug_select_format_index() {
+        let plug_addr =
+            BcoPlugAddr::new_for_unit(BcoPlugDirection::Input, BcoPlugAddrUnitType::Isoc, 0);
+
+        let avc = TestFormatChoiceAvc(vec![
+            (0x03, 8, 1),
+            (0x04, 2, 8),
+            (0x04, 8, 1),
+            (0x04, 10, 1),
+            (0x05, 8, 1),
+        ]);
+        assert_eq!(
+            select_format_index(&avc, &plug_addr, 44100, 8, 100).unwrap(),
+            0
+        );
+        assert_eq!(
+            select_format_index(&avc, &plug_addr, 48000, 0, 100).unwrap(),
+            1
+        );
+        assert_eq!(
+            select_format_index(&avc, &plug_addr, 48000, 4, 100).unwrap(),
+            2
+        );
+        assert_eq!(
+            select_format_index(&avc, &plug_addr, 48000, 9, 100).unwrap(),
+            3
+        );
+        assert_eq!(
+            select_format_index(&avc, &plug_addr, 96000, 8, 100).unwrap(),
+            4
+        );
+
+        let err = select_format_index(&avc, &plug_addr, 96000, 10, 100).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Nxio));
+        let err = select_format_index(&avc, &plug_addr, 88200, 2, 100).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Nxio));
+    }
+
+    struct TestPlugInfoAvc;
+
+    impl Ta1394Avc<Error> for TestPlugInfoAvc {
+        fn transaction(&self, command_frame: &[u8], _: u32) -> Result<Vec<u8>, Error> {
+            assert_eq!(
+                command_frame,
+                &[0x01, 0xff, 0x02, 0x00, 0xff, 0xff, 0xff, 0xff]
+            );
+            Ok(vec![0x0c, 0xff, 0x02, 0x00, 0x02, 0x01, 0x03, 0x04])
+        }
+    }
+
+    #[test]
+    fn plug_unit_plug_counts() {
+        let data: PlugInfoUnitIsocExtData = unit_plug_counts(&TestPlugInfoAvc, 100).unwrap();
+        assert_eq!(data.isoc_input_plugs, 2);
+        assert_eq!(data.isoc_output_plugs, 1);
+        assert_eq!(data.external_input_plugs, 3);
+        assert_eq!(data.external_output_plugs, 4);
+    }
+
+    struct TestSubunitInfoAvc(Vec<u8>);
+
+    impl Ta1394Avc<Error> for TestSubunitInfoAvc {
+        fn transaction(&self, command_frame: &[u8], _: u32) -> Result<Vec<u8>, Error> {
+            assert_eq!(&command_frame[..3], &[0x01, 0xff, 0x31]);
+            let page = ((command_frame[3] >> 4) & 0x07) as usize;
+            let mut frame = command_frame.to_vec();
+            let entries: Vec<u8> = self.0.iter().skip(page * 4).take(4).copied().collect();
+            if page > 0 && entries.is_empty() {
+                frame[0] = AvcRespCode::Rejected.into();
+            } else {
+                frame[0] = AvcRespCode::ImplementedStable.into();
+                frame[4..(4 + entries.len())].copy_from_slice(&entries);
+            }
+            Ok(frame)
+        }
+    }
+
+    #[test]
+    fn unit_subunit_counts() {
+        // Four pages, and the last page is not filled.
+        let entries = vec![
+            0x08, 0x60, 0x01, 0x12, 0x18, 0x20, 0x28, 0x30, 0x38, 0x41, 0x48, 0x50, 0xf0,
+        ];
+        let avc = TestSubunitInfoAvc(entries);
+        assert_eq!(
+            subunit_counts(&avc, 100).unwrap(),
+            vec![
+                (AvcSubunitType::Audio, 1),
+                (AvcSubunitType::Music, 1),
+                (AvcSubunitType::Monitor, 2),
+                (AvcSubunitType::Printer, 3),
+                (AvcSubunitType::Disc, 1),
+                (AvcSubunitType::Tape, 1),
+                (AvcSubunitType::Tuner, 1),
+                (AvcSubunitType::Ca, 1),
+                (AvcSubunitType::Camera, 1),
+                (AvcSubunitType::Reserved(0x08), 2),
+                (AvcSubunitType::Panel, 1),
+                (AvcSubunitType::BulletinBoard, 1),
+                (AvcSubunitType::Extended, 1),
+            ]
+        );
+
+        // Two pages filled, then the unit rejects the third page.
+        let entries = vec![0x08, 0x60, 0x01, 0x12, 0x18, 0x20, 0x28, 0x30];
+        let avc = TestSubunitInfoAvc(entries);
+        assert_eq!(subunit_counts(&avc, 100).unwrap().len(), 8);
+    }
+
     #[test]
     fn bcoplugaddr_from() {
         // Input plug for Unit.
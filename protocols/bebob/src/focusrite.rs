@@ -122,6 +122,7 @@ impl<O: SaffireParametersSerdes<T>, T: Clone> SaffireParametersOperation<T> for
 
 /// The structure for output parameters.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SaffireOutputParameters {
     /// Mute the output or not.
     pub mutes: Vec<bool>,
@@ -278,6 +279,7 @@ impl<O: SaffireOutputSpecification> SaffireOutputOperation for O {}
 
 /// The structure for signal through parameters.
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SaffireThroughParameters {
     /// For MIDI inputs.
     pub midi: bool,
@@ -311,6 +313,7 @@ impl<O: SaffireThroughSpecification> SaffireParametersSerdes<SaffireThroughParam
 
 /// The parameters of configuration save.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SaffireStoreConfigParameters;
 
 /// The specification of configuration save.
@@ -286,6 +286,7 @@ impl SaffireOutputSpecification for SaffireOutputProtocol {
 
 /// The signal source of input 2/3.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SaffireInputPair1Source {
     /// 1st pair of analog inputs.
     AnalogInputPair0,
@@ -301,6 +302,7 @@ impl Default for SaffireInputPair1Source {
 
 /// The mode of signal multiplexer.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SaffireMixerMode {
     /// Stereo mode.
     StereoPaired,
@@ -316,6 +318,7 @@ impl Default for SaffireMixerMode {
 
 /// Parameters specific to Saffire.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SaffireSpecificParameters {
     /// 176.4/192.0 kHz mode. This brings bus reset in IEEE 1394 bus.
     pub mode_192khz: bool,
@@ -694,6 +697,7 @@ impl SaffireOutputSpecification for SaffireLeOutputProtocol {
 
 /// The parameters specific to Saffire.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SaffireLeSpecificParameters {
     pub analog_input_2_3_high_gains: [bool; 2],
 }
@@ -734,6 +738,7 @@ impl SaffireParametersSerdes<SaffireLeSpecificParameters> for SaffireLeSpecificP
 
 /// Signal source of S/PDIF output.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SaffireLeSpdifOutputSource {
     /// 1st pair of mixer outputs.
     MixerOutputPair01,
@@ -749,6 +754,7 @@ impl Default for SaffireLeSpdifOutputSource {
 
 /// State of signal multiplexer in Saffire LE at 44.1/48.0 kHz.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SaffireLeMixerLowRateState {
     /// The gain of physical inputs.
     pub phys_src_gains: [[i16; 6]; 4],
@@ -944,6 +950,7 @@ impl SaffireLeMixerLowRateProtocol {
 
 /// State of signal multiplexer in Saffire LE at 88.2/96.0 kHz.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SaffireLeMixerMiddleRateState {
     /// The gain of mixer sources from physical inputs.
     pub monitor_src_phys_input_gains: [i16; 6],
@@ -1130,6 +1137,7 @@ impl SaffireStoreConfigSpecification for SaffireLeStoreConfigProtocol {
 
 /// State of signal multiplexer in Saffire.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SaffireMixerState {
     /// The level of physical inputs..
     pub phys_inputs: Vec<Vec<i16>>,
@@ -1270,6 +1278,7 @@ impl<O: SaffireMixerSpecification> SaffireMixerOperation for O {}
 
 /// State of stereo-separated reverb effect in Saffire.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SaffireReverbParameters {
     pub amounts: [i32; 2],
     pub room_sizes: [i32; 2],
@@ -1374,6 +1383,7 @@ impl SaffireReverbProtocol {
 
 /// Parameters of compressor effect in Saffire.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SaffireCompressorParameters {
     pub input_gains: [i32; 2],
     pub enables: [bool; 2],
@@ -1443,6 +1453,7 @@ impl SaffireCompressorProtocol {
 
 /// Parameters of equalizer effect in Saffire.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SaffireEqualizerParameters {
     pub enables: [bool; 2],
     pub input_gains: [i32; 2],
@@ -1535,6 +1546,7 @@ impl SaffireParametersSerdes<SaffireEqualizerParameters> for SaffireEqualizerPro
 
 /// Parameters of amplifier effect in Saffire.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SaffireAmplifierParameters {
     pub enables: [bool; 2],
     pub output_volumes: [i32; 2],
@@ -1578,6 +1590,7 @@ impl SaffireParametersSerdes<SaffireAmplifierParameters> for SaffireAmplifierPro
 
 /// Order of compressor effect against equalizer/amplifier effect.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SaffireChStripCompOrder {
     Pre,
     Post,
@@ -1591,6 +1604,7 @@ impl Default for SaffireChStripCompOrder {
 
 /// General parameters for channel strip effects.
 #[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SaffireChStripParameters {
     pub paired_mode: SaffireMixerMode,
     pub comp_orders: [SaffireChStripCompOrder; 2],
@@ -253,6 +253,7 @@ impl<O: SaffireProioMediaClockSpecification> SaffireParametersSerdes<MediaClockP
 
 /// Signal source of sampling clock in Saffire Pro series.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SaffireProioSamplingClockSource {
     /// Internal source.
     Internal,
@@ -400,6 +401,7 @@ pub trait SaffireProioMeterOperation {
 
 /// The parameters of input monitor in Saffire Pro i/o.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SaffireProioMonitorParameters {
     /// The source level of analog inputs.
     pub analog_inputs: [[i16; 8]; 2],
@@ -585,6 +587,7 @@ impl<O: SaffireProioMonitorSpecification> SaffireProioMonitorProtocol for O {}
 
 /// The parameters of signal multiplexer in Saffire Pro i/o.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SaffireProioMixerParameters {
     /// The level of input sources.
     pub monitor_sources: [i16; 10],
@@ -743,6 +746,7 @@ fn calc_stream_source_pos(i: usize) -> usize {
 
 /// Working mode at standalone mode.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SaffireProioStandaloneMode {
     /// For mixing.
     Mix,
@@ -758,6 +762,7 @@ impl Default for SaffireProioStandaloneMode {
 
 /// Parameters specific to Saffire Pro i/o series.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SaffireProioSpecificParameters {
     /// Whether to increase head room for analog inputs.
     pub head_room: bool,
@@ -86,6 +86,8 @@ impl AvcSelectorOperation for FirexonPhysOutputProtocol {
     const FUNC_BLOCK_ID_LIST: &'static [u8] = &[0x01];
     // NOTE: "mixer-output-1/2", "stream-input-3/4", "stream-input-5/6"
     const INPUT_PLUG_ID_LIST: &'static [u8] = &[0x00, 0x01, 0x02];
+    const SOURCE_NAMES: &'static [&'static str] =
+        &["mixer-output-1/2", "stream-input-3/4", "stream-input-5/6"];
 }
 
 /// The protocol implementation of source to monitor mixer for physical inputs
@@ -23,7 +23,7 @@ use {
     glib::{prelude::IsA, Error, FileError},
     hinawa::{
         prelude::{FwFcpExt, FwFcpExtManual, FwReqExtManual},
-        FwFcp, FwNode, FwReq, FwTcode,
+        FwFcp, FwNode, FwNodeError, FwReq, FwTcode,
     },
     ta1394_avc_audio::{amdtp::*, *},
     ta1394_avc_ccm::*,
@@ -43,10 +43,19 @@ const DM_BCO_BOOTLOADER_INFO_OFFSET: u64 = DM_BCO_OFFSET + 0x00020000;
 /// It seems a unique quirk that the status code in response frame for some AV/C commands is
 /// against AV/C general specification in control operation.
 #[derive(Default, Debug)]
-pub struct BebobAvc(FwFcp);
+pub struct BebobAvc<T = FwFcp>(T);
 
 impl Ta1394Avc<Error> for BebobAvc {
     fn transaction(&self, command_frame: &[u8], timeout_ms: u32) -> Result<Vec<u8>, Error> {
+        // The node can be removed from the bus. The error in domain of FwNodeError is reported so
+        // that caller can detect the case to bind again.
+        if !self.is_bound() {
+            Err(Error::new(
+                FwNodeError::Disconnected,
+                "FCP is not bound to any node",
+            ))?;
+        }
+
         let mut resp = vec![0; Self::FRAME_SIZE];
         self.0
             .avc_transaction(&command_frame, &mut resp, timeout_ms)
@@ -63,9 +72,7 @@ impl Ta1394Avc<Error> for BebobAvc {
             AvcControl::build_operands(op, addr).map_err(|err| Ta1394AvcError::CmdBuild(err))?;
         let command_frame =
             Self::compose_command_frame(AvcCmdType::Control, addr, O::OPCODE, &operands)?;
-        let response_frame = self
-            .transaction(&command_frame, timeout_ms)
-            .map_err(|cause| Ta1394AvcError::CommunicationFailure(cause))?;
+        let response_frame = self.final_transaction(&command_frame, timeout_ms)?;
         Self::detect_response_operands(&response_frame, addr, O::OPCODE)
             .and_then(|(rcode, operands)| {
                 let expected = match O::OPCODE {
@@ -78,7 +85,7 @@ impl Ta1394Avc<Error> for BebobAvc {
                     _ => rcode == AvcRespCode::Accepted,
                 };
                 if !expected {
-                    Err(AvcRespParseError::UnexpectedStatus)
+                    Err(AvcRespParseError::from_unexpected_rcode(rcode))
                 } else {
                     AvcControl::parse_operands(op, addr, &operands)
                 }
@@ -92,6 +99,16 @@ impl BebobAvc {
         self.0.bind(node)
     }
 
+    /// Whether the FCP is bound to node. When the node is removed, it should be bound again.
+    pub fn is_bound(&self) -> bool {
+        self.0.is_bound()
+    }
+}
+
+impl<T> BebobAvc<T>
+where
+    Self: Ta1394Avc<Error>,
+{
     pub fn control<O: AvcOp + AvcControl>(
         &self,
         addr: &AvcAddr,
@@ -109,6 +126,24 @@ impl BebobAvc {
     ) -> Result<(), Error> {
         Ta1394Avc::<Error>::status(self, addr, op, timeout_ms).map_err(|err| from_avc_err(err))
     }
+
+    pub fn specific_inquiry<O: AvcOp + AvcControl>(
+        &self,
+        addr: &AvcAddr,
+        op: &mut O,
+        timeout_ms: u32,
+    ) -> Result<(), Error> {
+        Ta1394Avc::<Error>::specific_inquiry(self, addr, op, timeout_ms).map_err(from_avc_err)
+    }
+
+    pub fn notify<O: AvcOp + AvcNotify>(
+        &self,
+        addr: &AvcAddr,
+        op: &mut O,
+        timeout_ms: u32,
+    ) -> Result<(), Error> {
+        Ta1394Avc::<Error>::notify(self, addr, op, timeout_ms).map_err(from_avc_err)
+    }
 }
 
 fn from_avc_err(err: Ta1394AvcError<Error>) -> Error {
@@ -121,6 +156,7 @@ fn from_avc_err(err: Ta1394AvcError<Error>) -> Error {
 
 /// The parameters of media clock.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MediaClockParameters {
     /// The index for entry in frequency list.
     pub freq_idx: usize,
@@ -131,6 +167,11 @@ pub trait MediaClockFrequencyOperation {
     /// The list of supported frequencies.
     const FREQ_LIST: &'static [u32];
 
+    /// The name of frequency at the index in the list, for display.
+    fn freq_name(idx: usize) -> Option<String> {
+        Self::FREQ_LIST.get(idx).map(|freq| format!("{} Hz", freq))
+    }
+
     /// Cache the state of media clock to the parameters.
     fn cache_freq(
         avc: &BebobAvc,
@@ -203,6 +244,7 @@ pub trait MediaClockFrequencyOperation {
 
 /// The parameters of sampling clock.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SamplingClockParameters {
     /// The index for entry in source list.
     pub src_idx: usize,
@@ -274,6 +316,7 @@ pub trait AvcAudioFeatureSpecification {
 /// The parameters of signal level. The `Default` trait should be implemented to call
 /// `AvcLevelOperation::create_level_parameters()`.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AvcLevelParameters {
     /// The signal levels.
     pub levels: Vec<i16>,
@@ -288,6 +331,29 @@ pub trait AvcLevelOperation: AvcAudioFeatureSpecification {
     /// The step value of signal level.
     const LEVEL_STEP: i16 = 0x100;
 
+    /// Convert the value of signal level into dB. The value for negative infinity is converted
+    /// into `f64::NEG_INFINITY`.
+    fn level_to_db(level: i16) -> f64 {
+        if level == VolumeData::VALUE_NEG_INFINITY {
+            f64::NEG_INFINITY
+        } else {
+            level as f64 / 256.0
+        }
+    }
+
+    /// Convert dB into the value of signal level, saturated between the minimum and maximum
+    /// values.
+    fn level_from_db(db: f64) -> i16 {
+        let level = if db == f64::NEG_INFINITY {
+            VolumeData::VALUE_NEG_INFINITY as f64
+        } else {
+            (db * 256.0).round()
+        };
+        level
+            .max(Self::LEVEL_MIN as f64)
+            .min(Self::LEVEL_MAX as f64) as i16
+    }
+
     /// Instantiate parameters.
     fn create_level_parameters() -> AvcLevelParameters {
         AvcLevelParameters {
@@ -324,13 +390,14 @@ pub trait AvcLevelOperation: AvcAudioFeatureSpecification {
             })
     }
 
-    /// Update the hardware when detecting any changes in the parameters.
-    fn update_levels(
-        avc: &BebobAvc,
+    /// Update the hardware when detecting any changes in the parameters. The indices of updated
+    /// entries are returned.
+    fn update_levels<T: Ta1394Avc<Error>>(
+        avc: &T,
         params: &AvcLevelParameters,
         old: &mut AvcLevelParameters,
         timeout_ms: u32,
-    ) -> Result<(), Error> {
+    ) -> Result<Vec<usize>, Error> {
         assert_eq!(params.levels.len(), Self::ENTRIES.len());
         assert_eq!(old.levels.len(), Self::ENTRIES.len());
 
@@ -338,8 +405,9 @@ pub trait AvcLevelOperation: AvcAudioFeatureSpecification {
             .iter_mut()
             .zip(params.levels.iter())
             .zip(Self::ENTRIES)
-            .filter(|((old, new), _)| !new.eq(old))
-            .try_for_each(|((old, new), entry)| {
+            .enumerate()
+            .filter(|(_, ((old, new), _))| !new.eq(old))
+            .try_fold(Vec::new(), |mut updated, (i, ((old, new), entry))| {
                 let &(func_block_id, audio_ch) = entry;
                 let mut op = AudioFeature::new(
                     func_block_id,
@@ -348,14 +416,47 @@ pub trait AvcLevelOperation: AvcAudioFeatureSpecification {
                     FeatureCtl::Volume(VolumeData(vec![*new])),
                 );
                 avc.control(&AUDIO_SUBUNIT_0_ADDR, &mut op, timeout_ms)
-                    .map(|_| *old = *new)
+                    .map_err(from_avc_err)?;
+                *old = *new;
+                updated.push(i);
+                Ok(updated)
             })
     }
+
+    /// Update the hardware for the entry at the index when detecting change, then the parameters
+    /// at success.
+    fn update_level_single<T: Ta1394Avc<Error>>(
+        avc: &T,
+        idx: usize,
+        level: i16,
+        params: &mut AvcLevelParameters,
+        timeout_ms: u32,
+    ) -> Result<(), Error> {
+        assert_eq!(params.levels.len(), Self::ENTRIES.len());
+
+        let &(func_block_id, audio_ch) = Self::ENTRIES.get(idx).ok_or_else(|| {
+            let msg = format!("Invalid index of entry: {}", idx);
+            Error::new(FileError::Inval, &msg)
+        })?;
+        if params.levels[idx] != level {
+            let mut op = AudioFeature::new(
+                func_block_id,
+                CtlAttr::Current,
+                audio_ch,
+                FeatureCtl::Volume(VolumeData(vec![level])),
+            );
+            avc.control(&AUDIO_SUBUNIT_0_ADDR, &mut op, timeout_ms)
+                .map(|_| params.levels[idx] = level)
+                .map_err(from_avc_err)?;
+        }
+        Ok(())
+    }
 }
 
 /// The parameters of L/R balance. The `Default` trait should be implemented to call
 /// `AvcLrBalanceOperation::create_lr_balance_parameters()`.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AvcLrBalanceParameters {
     /// The L/R balances.
     pub balances: Vec<i16>,
@@ -407,8 +508,8 @@ pub trait AvcLrBalanceOperation: AvcAudioFeatureSpecification {
     }
 
     /// Update the hardware when detecting any changes in the parameters.
-    fn update_lr_balances(
-        avc: &BebobAvc,
+    fn update_lr_balances<T: Ta1394Avc<Error>>(
+        avc: &T,
         params: &AvcLrBalanceParameters,
         old: &mut AvcLrBalanceParameters,
         timeout_ms: u32,
@@ -431,13 +532,56 @@ pub trait AvcLrBalanceOperation: AvcAudioFeatureSpecification {
                 );
                 avc.control(&AUDIO_SUBUNIT_0_ADDR, &mut op, timeout_ms)
                     .map(|_| *old = new)
+                    .map_err(from_avc_err)
             })
     }
+
+    /// Update the hardware for the entry at the index when detecting change, then the parameters
+    /// at success.
+    fn update_balance_single<T: Ta1394Avc<Error>>(
+        avc: &T,
+        idx: usize,
+        balance: i16,
+        params: &mut AvcLrBalanceParameters,
+        timeout_ms: u32,
+    ) -> Result<(), Error> {
+        assert_eq!(params.balances.len(), Self::ENTRIES.len());
+
+        let &(func_block_id, audio_ch) = Self::ENTRIES.get(idx).ok_or_else(|| {
+            let msg = format!("Invalid index of entry: {}", idx);
+            Error::new(FileError::Inval, &msg)
+        })?;
+        if params.balances[idx] != balance {
+            let mut op = AudioFeature::new(
+                func_block_id,
+                CtlAttr::Current,
+                audio_ch,
+                FeatureCtl::LrBalance(LrBalanceData(balance)),
+            );
+            avc.control(&AUDIO_SUBUNIT_0_ADDR, &mut op, timeout_ms)
+                .map(|_| params.balances[idx] = balance)
+                .map_err(from_avc_err)?;
+        }
+        Ok(())
+    }
+
+    /// Center all of entries. The hardware is updated just for entries not centered yet.
+    fn center_balances<T: Ta1394Avc<Error>>(
+        avc: &T,
+        params: &mut AvcLrBalanceParameters,
+        timeout_ms: u32,
+    ) -> Result<(), Error> {
+        let updates = AvcLrBalanceParameters {
+            balances: vec![LrBalanceData::VALUE_LEFT_ZERO; params.balances.len()],
+        };
+        Self::update_lr_balances(avc, &updates, params, timeout_ms)
+    }
 }
 
 /// The parameters of mute. The `Default` trait should be implemented to call
 /// `AvcMuteOperation::create_mute_parameters()`.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AvcMuteParameters {
     /// Muted or not.
     pub mutes: Vec<bool>,
@@ -483,8 +627,8 @@ pub trait AvcMuteOperation: AvcAudioFeatureSpecification {
     }
 
     /// Update the hardware when detecting any changes in the parameters.
-    fn update_mutes(
-        avc: &BebobAvc,
+    fn update_mutes<T: Ta1394Avc<Error>>(
+        avc: &T,
         params: &AvcMuteParameters,
         old: &mut AvcMuteParameters,
         timeout_ms: u32,
@@ -508,6 +652,220 @@ pub trait AvcMuteOperation: AvcAudioFeatureSpecification {
                 );
                 avc.control(&AUDIO_SUBUNIT_0_ADDR, &mut op, timeout_ms)
                     .map(|_| *old = new)
+                    .map_err(from_avc_err)
+            })
+    }
+
+    /// Update the hardware for the entry at the index when detecting change, then the parameters
+    /// at success.
+    fn update_mute_single<T: Ta1394Avc<Error>>(
+        avc: &T,
+        idx: usize,
+        mute: bool,
+        params: &mut AvcMuteParameters,
+        timeout_ms: u32,
+    ) -> Result<(), Error> {
+        assert_eq!(params.mutes.len(), Self::ENTRIES.len());
+
+        let &(func_block_id, audio_ch) = Self::ENTRIES.get(idx).ok_or_else(|| {
+            let msg = format!("Invalid index of entry: {}", idx);
+            Error::new(FileError::Inval, &msg)
+        })?;
+        if params.mutes[idx] != mute {
+            let mut op = AudioFeature::new(
+                func_block_id,
+                CtlAttr::Current,
+                audio_ch,
+                FeatureCtl::Mute(vec![mute]),
+            );
+            avc.control(&AUDIO_SUBUNIT_0_ADDR, &mut op, timeout_ms)
+                .map(|_| params.mutes[idx] = mute)
+                .map_err(from_avc_err)?;
+        }
+        Ok(())
+    }
+
+    /// Mute all of entries. The hardware is updated just for entries not muted yet.
+    fn mute_all<T: Ta1394Avc<Error>>(
+        avc: &T,
+        params: &mut AvcMuteParameters,
+        timeout_ms: u32,
+    ) -> Result<(), Error> {
+        let updates = AvcMuteParameters {
+            mutes: vec![true; params.mutes.len()],
+        };
+        Self::update_mutes(avc, &updates, params, timeout_ms)
+    }
+
+    /// Unmute all of entries. The hardware is updated just for entries muted yet.
+    fn unmute_all<T: Ta1394Avc<Error>>(
+        avc: &T,
+        params: &mut AvcMuteParameters,
+        timeout_ms: u32,
+    ) -> Result<(), Error> {
+        let updates = AvcMuteParameters {
+            mutes: vec![false; params.mutes.len()],
+        };
+        Self::update_mutes(avc, &updates, params, timeout_ms)
+    }
+}
+
+/// The parameters of automatic gain. The `Default` trait should be implemented to call
+/// `AvcAutoGainOperation::create_auto_gain_parameters()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AvcAutoGainParameters {
+    /// Automatic gain control is enabled or not.
+    pub auto_gains: Vec<bool>,
+}
+
+/// The trait of automatic gain operation for audio function blocks.
+pub trait AvcAutoGainOperation: AvcAudioFeatureSpecification {
+    /// Instantiate parameters.
+    fn create_auto_gain_parameters() -> AvcAutoGainParameters {
+        AvcAutoGainParameters {
+            auto_gains: vec![Default::default(); Self::ENTRIES.len()],
+        }
+    }
+
+    /// Cache state of hardware to the parameters.
+    fn cache_auto_gains<T: Ta1394Avc<Error>>(
+        avc: &T,
+        params: &mut AvcAutoGainParameters,
+        timeout_ms: u32,
+    ) -> Result<(), Error> {
+        assert_eq!(params.auto_gains.len(), Self::ENTRIES.len());
+
+        params
+            .auto_gains
+            .iter_mut()
+            .zip(Self::ENTRIES)
+            .try_for_each(|(state, entry)| {
+                let &(func_block_id, audio_ch) = entry;
+
+                let mut op = AudioFeature::new(
+                    func_block_id,
+                    CtlAttr::Current,
+                    audio_ch,
+                    FeatureCtl::AutomaticGain(vec![false]),
+                );
+                avc.status(&AUDIO_SUBUNIT_0_ADDR, &mut op, timeout_ms)
+                    .map(|_| {
+                        if let FeatureCtl::AutomaticGain(data) = op.ctl {
+                            *state = data[0];
+                        }
+                    })
+                    .map_err(from_avc_err)
+            })
+    }
+
+    /// Update the hardware when detecting any changes in the parameters.
+    fn update_auto_gains<T: Ta1394Avc<Error>>(
+        avc: &T,
+        params: &AvcAutoGainParameters,
+        old: &mut AvcAutoGainParameters,
+        timeout_ms: u32,
+    ) -> Result<(), Error> {
+        assert_eq!(params.auto_gains.len(), Self::ENTRIES.len());
+        assert_eq!(old.auto_gains.len(), Self::ENTRIES.len());
+
+        old.auto_gains
+            .iter_mut()
+            .zip(params.auto_gains.iter())
+            .zip(Self::ENTRIES)
+            .filter(|((o, n), _)| !n.eq(o))
+            .try_for_each(|((old, &new), entry)| {
+                let &(func_block_id, audio_ch) = entry;
+
+                let mut op = AudioFeature::new(
+                    func_block_id,
+                    CtlAttr::Current,
+                    audio_ch,
+                    FeatureCtl::AutomaticGain(vec![new]),
+                );
+                avc.control(&AUDIO_SUBUNIT_0_ADDR, &mut op, timeout_ms)
+                    .map(|_| *old = new)
+                    .map_err(from_avc_err)
+            })
+    }
+}
+
+/// The parameters of loudness. The `Default` trait should be implemented to call
+/// `AvcLoudnessOperation::create_loudness_parameters()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AvcLoudnessParameters {
+    /// Loudness control is enabled or not.
+    pub loudnesses: Vec<bool>,
+}
+
+/// The trait of loudness operation for audio function blocks.
+pub trait AvcLoudnessOperation: AvcAudioFeatureSpecification {
+    /// Instantiate parameters.
+    fn create_loudness_parameters() -> AvcLoudnessParameters {
+        AvcLoudnessParameters {
+            loudnesses: vec![Default::default(); Self::ENTRIES.len()],
+        }
+    }
+
+    /// Cache state of hardware to the parameters.
+    fn cache_loudnesses<T: Ta1394Avc<Error>>(
+        avc: &T,
+        params: &mut AvcLoudnessParameters,
+        timeout_ms: u32,
+    ) -> Result<(), Error> {
+        assert_eq!(params.loudnesses.len(), Self::ENTRIES.len());
+
+        params
+            .loudnesses
+            .iter_mut()
+            .zip(Self::ENTRIES)
+            .try_for_each(|(state, entry)| {
+                let &(func_block_id, audio_ch) = entry;
+
+                let mut op = AudioFeature::new(
+                    func_block_id,
+                    CtlAttr::Current,
+                    audio_ch,
+                    FeatureCtl::Loudness(vec![false]),
+                );
+                avc.status(&AUDIO_SUBUNIT_0_ADDR, &mut op, timeout_ms)
+                    .map(|_| {
+                        if let FeatureCtl::Loudness(data) = op.ctl {
+                            *state = data[0];
+                        }
+                    })
+                    .map_err(from_avc_err)
+            })
+    }
+
+    /// Update the hardware when detecting any changes in the parameters.
+    fn update_loudnesses<T: Ta1394Avc<Error>>(
+        avc: &T,
+        params: &AvcLoudnessParameters,
+        old: &mut AvcLoudnessParameters,
+        timeout_ms: u32,
+    ) -> Result<(), Error> {
+        assert_eq!(params.loudnesses.len(), Self::ENTRIES.len());
+        assert_eq!(old.loudnesses.len(), Self::ENTRIES.len());
+
+        old.loudnesses
+            .iter_mut()
+            .zip(params.loudnesses.iter())
+            .zip(Self::ENTRIES)
+            .filter(|((o, n), _)| !n.eq(o))
+            .try_for_each(|((old, &new), entry)| {
+                let &(func_block_id, audio_ch) = entry;
+
+                let mut op = AudioFeature::new(
+                    func_block_id,
+                    CtlAttr::Current,
+                    audio_ch,
+                    FeatureCtl::Loudness(vec![new]),
+                );
+                avc.control(&AUDIO_SUBUNIT_0_ADDR, &mut op, timeout_ms)
+                    .map(|_| *old = new)
+                    .map_err(from_avc_err)
             })
     }
 }
@@ -515,6 +873,7 @@ pub trait AvcMuteOperation: AvcAudioFeatureSpecification {
 /// The parameter of selectors. The `Default` trait should be implemented to call
 /// `AvcSelectorOperation::create_selector_parameters()`.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AvcSelectorParameters {
     /// The index for entry in the list of function block.
     pub selectors: Vec<usize>,
@@ -526,9 +885,24 @@ pub trait AvcSelectorOperation {
     const FUNC_BLOCK_ID_LIST: &'static [u8];
     /// The list of plug identifier.
     const INPUT_PLUG_ID_LIST: &'static [u8];
+    /// The list of name for source, in the same order as `INPUT_PLUG_ID_LIST`.
+    const SOURCE_NAMES: &'static [&'static str];
+
+    #[doc(hidden)]
+    const SOURCE_NAMES_ASSERT: () = assert!(
+        Self::SOURCE_NAMES.len() == Self::INPUT_PLUG_ID_LIST.len(),
+        "Programming error for count of source names"
+    );
+
+    /// The name of source at the index in the list of plug identifier, for display.
+    fn source_name(idx: usize) -> Option<&'static str> {
+        let () = Self::SOURCE_NAMES_ASSERT;
+        Self::SOURCE_NAMES.get(idx).copied()
+    }
 
     /// Instantiate parameters.
     fn create_selector_parameters() -> AvcSelectorParameters {
+        let () = Self::SOURCE_NAMES_ASSERT;
         AvcSelectorParameters {
             selectors: vec![Default::default(); Self::FUNC_BLOCK_ID_LIST.len()],
         }
@@ -585,4 +959,615 @@ pub trait AvcSelectorOperation {
                     .map(|_| *old = new)
             })
     }
+
+    /// Update the hardware for the entry at the index when detecting change, then the parameters
+    /// at success.
+    fn update_selector_single<T: Ta1394Avc<Error>>(
+        avc: &T,
+        idx: usize,
+        selector: usize,
+        params: &mut AvcSelectorParameters,
+        timeout_ms: u32,
+    ) -> Result<(), Error> {
+        assert_eq!(params.selectors.len(), Self::FUNC_BLOCK_ID_LIST.len());
+
+        let &func_block_id = Self::FUNC_BLOCK_ID_LIST.get(idx).ok_or_else(|| {
+            let msg = format!("Invalid index of entry: {}", idx);
+            Error::new(FileError::Inval, &msg)
+        })?;
+        if params.selectors[idx] != selector {
+            let mut op = AudioSelector::new(func_block_id, CtlAttr::Current, selector as u8);
+            avc.control(&AUDIO_SUBUNIT_0_ADDR, &mut op, timeout_ms)
+                .map(|_| params.selectors[idx] = selector)
+                .map_err(from_avc_err)?;
+        }
+        Ok(())
+    }
+}
+
+/// Enable or disable the list of processing function blocks. The operation aborts at the first
+/// failure, and the identifier of function block is included in the error message.
+pub fn set_processing_enabled<T: Ta1394Avc<Error>>(
+    avc: &T,
+    blocks: &[(u8, bool)],
+    timeout_ms: u32,
+) -> Result<(), Error> {
+    blocks.iter().try_for_each(|&(func_block_id, enabled)| {
+        let mut op = AudioProcessing::new(
+            func_block_id,
+            CtlAttr::Current,
+            0xff,
+            AudioCh::All,
+            AudioCh::All,
+            ProcessingCtl::Enable(enabled),
+        );
+        avc.control(&AUDIO_SUBUNIT_0_ADDR, &mut op, timeout_ms)
+            .map_err(|err| {
+                let cause = from_avc_err(err);
+                let msg = format!(
+                    "Fail to operate processing function block 0x{:02x}: {}",
+                    func_block_id, cause
+                );
+                Error::new(cause.kind::<FileError>().unwrap_or(FileError::Io), &msg)
+            })
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, std::cell::RefCell};
+
+    #[derive(Default)]
+    struct TestProcessingAvc {
+        frames: RefCell<Vec<Vec<u8>>>,
+        rejected_func_block_id: Option<u8>,
+    }
+
+    impl Ta1394Avc<Error> for TestProcessingAvc {
+        fn transaction(&self, command_frame: &[u8], _: u32) -> Result<Vec<u8>, Error> {
+            self.frames.borrow_mut().push(command_frame.to_vec());
+            // The identifier of function block follows to the type of function block.
+            let rcode = if Some(command_frame[4]) == self.rejected_func_block_id {
+                AvcRespCode::Rejected
+            } else {
+                AvcRespCode::Accepted
+            };
+            let mut frame = command_frame.to_vec();
+            frame[0] = rcode.into();
+            Ok(frame)
+        }
+    }
+
+    #[test]
+    fn processing_enabled_in_batch() {
+        let avc = TestProcessingAvc::default();
+        let blocks = [(0x01, true), (0x02, false), (0x05, true)];
+        set_processing_enabled(&avc, &blocks, 100).unwrap();
+
+        let frames = avc.frames.borrow();
+        assert_eq!(frames.len(), blocks.len());
+        frames
+            .iter()
+            .zip(&blocks)
+            .for_each(|(frame, &(func_block_id, enabled))| {
+                assert_eq!(frame[4], func_block_id);
+                assert_eq!(frame[frame.len() - 1], if enabled { 0x70 } else { 0x60 });
+            });
+    }
+
+    #[test]
+    fn processing_enabled_abort_at_failure() {
+        let avc = TestProcessingAvc {
+            rejected_func_block_id: Some(0x02),
+            ..Default::default()
+        };
+        let blocks = [(0x01, true), (0x02, false), (0x05, true)];
+        let err = set_processing_enabled(&avc, &blocks, 100).unwrap_err();
+        assert!(err.to_string().contains("0x02"));
+        assert_eq!(avc.frames.borrow().len(), 2);
+    }
+
+    struct TestClkProtocol;
+
+    impl MediaClockFrequencyOperation for TestClkProtocol {
+        const FREQ_LIST: &'static [u32] =
+            &[32000, 44100, 48000, 88200, 96000, 176400, 192000, 22050];
+    }
+
+    #[test]
+    fn media_clock_freq_name() {
+        let names: Vec<String> = (0..TestClkProtocol::FREQ_LIST.len())
+            .map(|i| TestClkProtocol::freq_name(i).unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "32000 Hz",
+                "44100 Hz",
+                "48000 Hz",
+                "88200 Hz",
+                "96000 Hz",
+                "176400 Hz",
+                "192000 Hz",
+                "22050 Hz"
+            ]
+        );
+        assert_eq!(
+            TestClkProtocol::freq_name(TestClkProtocol::FREQ_LIST.len()),
+            None
+        );
+    }
+
+    #[test]
+    fn avc_error_conversion() {
+        let err = from_avc_err(Ta1394AvcError::CmdBuild(AvcCmdBuildError::InvalidOperands));
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Inval));
+
+        let cause = Error::new(FileError::Nxio, "no response");
+        let err = from_avc_err(Ta1394AvcError::CommunicationFailure(cause));
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Nxio));
+
+        let err = from_avc_err(Ta1394AvcError::RespParse(
+            AvcRespParseError::UnexpectedStatus,
+        ));
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Io));
+    }
+
+    #[derive(Default)]
+    struct TestAvc {
+        response_frame: RefCell<Vec<u8>>,
+        command_frame: RefCell<Vec<u8>>,
+    }
+
+    impl Ta1394Avc<Error> for BebobAvc<TestAvc> {
+        fn transaction(&self, command_frame: &[u8], _: u32) -> Result<Vec<u8>, Error> {
+            *self.0.command_frame.borrow_mut() = command_frame.to_vec();
+            let frame = self.0.response_frame.borrow().clone();
+            if frame.is_empty() {
+                Err(Error::new(FileError::Nxio, "no response"))
+            } else {
+                Ok(frame)
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct TestOp(u8);
+
+    impl AvcOp for TestOp {
+        const OPCODE: u8 = 0x10;
+    }
+
+    impl AvcControl for TestOp {
+        fn build_operands(&mut self, _: &AvcAddr) -> Result<Vec<u8>, AvcCmdBuildError> {
+            Ok(vec![self.0])
+        }
+
+        fn parse_operands(
+            &mut self,
+            _: &AvcAddr,
+            operands: &[u8],
+        ) -> Result<(), AvcRespParseError> {
+            self.0 = operands[0];
+            Ok(())
+        }
+    }
+
+    impl AvcNotify for TestOp {
+        fn build_operands(&mut self, _: &AvcAddr) -> Result<Vec<u8>, AvcCmdBuildError> {
+            Ok(vec![self.0])
+        }
+
+        fn parse_operands(
+            &mut self,
+            _: &AvcAddr,
+            operands: &[u8],
+        ) -> Result<(), AvcRespParseError> {
+            self.0 = operands[0];
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn notify_with_stub() {
+        let avc = BebobAvc(TestAvc::default());
+        let mut op = TestOp(0xff);
+
+        // CHANGED response.
+        *avc.0.response_frame.borrow_mut() = vec![0x0d, 0xff, 0x10, 0x5a];
+        avc.notify(&AvcAddr::Unit, &mut op, 100).unwrap();
+        assert_eq!(&*avc.0.command_frame.borrow(), &[0x03, 0xff, 0x10, 0xff]);
+        assert_eq!(op.0, 0x5a);
+
+        // REJECTED response.
+        *avc.0.response_frame.borrow_mut() = vec![0x0a, 0xff, 0x10, 0x5a];
+        let err = avc.notify(&AvcAddr::Unit, &mut op, 100).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Io));
+
+        avc.0.response_frame.borrow_mut().clear();
+        let err = avc.notify(&AvcAddr::Unit, &mut op, 100).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Nxio));
+    }
+
+    #[test]
+    fn specific_inquiry_with_stub() {
+        let avc = BebobAvc(TestAvc::default());
+        let mut op = TestOp(0x01);
+
+        // IMPLEMENTED/STABLE response.
+        *avc.0.response_frame.borrow_mut() = vec![0x0c, 0xff, 0x10, 0x01];
+        avc.specific_inquiry(&AvcAddr::Unit, &mut op, 100).unwrap();
+        assert_eq!(&*avc.0.command_frame.borrow(), &[0x02, 0xff, 0x10, 0x01]);
+
+        // NOT IMPLEMENTED response.
+        *avc.0.response_frame.borrow_mut() = vec![0x08, 0xff, 0x10, 0x01];
+        let err = avc
+            .specific_inquiry(&AvcAddr::Unit, &mut op, 100)
+            .unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Io));
+    }
+
+    #[test]
+    fn avc_error_at_unbound() {
+        let avc = BebobAvc::default();
+        assert!(!avc.is_bound());
+
+        let mut op = AudioFeature::new(
+            0x01,
+            CtlAttr::Current,
+            AudioCh::Master,
+            FeatureCtl::Mute(vec![false]),
+        );
+        match Ta1394Avc::<Error>::control(&avc, &AUDIO_SUBUNIT_0_ADDR, &mut op, 100) {
+            Err(Ta1394AvcError::CommunicationFailure(cause)) => {
+                assert_eq!(cause.kind::<FwNodeError>(), Some(FwNodeError::Disconnected))
+            }
+            _ => unreachable!(),
+        }
+
+        let err = avc.status(&AUDIO_SUBUNIT_0_ADDR, &mut op, 100).unwrap_err();
+        assert_eq!(err.kind::<FwNodeError>(), Some(FwNodeError::Disconnected));
+    }
+
+    struct TestLevelProtocol;
+
+    impl AvcAudioFeatureSpecification for TestLevelProtocol {
+        const ENTRIES: &'static [(u8, AudioCh)] = &[(0x01, AudioCh::Master)];
+    }
+
+    impl AvcLevelOperation for TestLevelProtocol {}
+
+    impl AvcLrBalanceOperation for TestLevelProtocol {}
+
+    struct TestMultiLevelProtocol;
+
+    impl AvcAudioFeatureSpecification for TestMultiLevelProtocol {
+        const ENTRIES: &'static [(u8, AudioCh)] = &[
+            (0x01, AudioCh::Each(0)),
+            (0x01, AudioCh::Each(1)),
+            (0x02, AudioCh::Master),
+        ];
+    }
+
+    impl AvcLevelOperation for TestMultiLevelProtocol {}
+
+    #[test]
+    fn update_levels_indices() {
+        let avc = TestProcessingAvc::default();
+        let mut old = TestMultiLevelProtocol::create_level_parameters();
+
+        let mut params = old.clone();
+        params.levels[0] = -0x100;
+        params.levels[2] = 0x100;
+        let updated = TestMultiLevelProtocol::update_levels(&avc, &params, &mut old, 100).unwrap();
+        assert_eq!(updated, vec![0, 2]);
+        assert_eq!(old, params);
+        let frames: Vec<u8> = avc.frames.borrow().iter().map(|frame| frame[4]).collect();
+        assert_eq!(frames, vec![0x01, 0x02]);
+
+        let updated = TestMultiLevelProtocol::update_levels(&avc, &params, &mut old, 100).unwrap();
+        assert_eq!(updated, Vec::<usize>::new());
+        assert_eq!(avc.frames.borrow().len(), 2);
+
+        // The update aborts at the first failure.
+        let avc = TestProcessingAvc {
+            rejected_func_block_id: Some(0x02),
+            ..Default::default()
+        };
+        params.levels[1] = 0x200;
+        params.levels[2] = 0x200;
+        assert!(TestMultiLevelProtocol::update_levels(&avc, &params, &mut old, 100).is_err());
+        assert_eq!(old.levels, vec![-0x100, 0x200, 0x100]);
+    }
+
+    struct TestToneProtocol;
+
+    impl AvcAudioFeatureSpecification for TestToneProtocol {
+        const ENTRIES: &'static [(u8, AudioCh)] = &[
+            (0x03, AudioCh::Each(0)),
+            (0x03, AudioCh::Each(1)),
+            (0x04, AudioCh::Master),
+        ];
+    }
+
+    impl AvcAutoGainOperation for TestToneProtocol {}
+
+    impl AvcLoudnessOperation for TestToneProtocol {}
+
+    #[test]
+    fn update_auto_gains_and_loudnesses() {
+        let avc = TestProcessingAvc::default();
+
+        let mut old = TestToneProtocol::create_auto_gain_parameters();
+        let mut params = old.clone();
+        params.auto_gains[1] = true;
+        TestToneProtocol::update_auto_gains(&avc, &params, &mut old, 100).unwrap();
+        assert_eq!(old, params);
+        {
+            let frames = avc.frames.borrow();
+            assert_eq!(frames.len(), 1);
+            // The identifier of function block, the channel, and the selector of control.
+            assert_eq!(&frames[0][4..], &[0x03, 0x10, 0x02, 0x02, 0x09, 0x01, 0x70]);
+        }
+
+        // No transaction for unchanged parameters.
+        TestToneProtocol::update_auto_gains(&avc, &params, &mut old, 100).unwrap();
+        assert_eq!(avc.frames.borrow().len(), 1);
+
+        let mut old = TestToneProtocol::create_loudness_parameters();
+        let mut params = old.clone();
+        params.loudnesses[0] = true;
+        params.loudnesses[2] = true;
+        TestToneProtocol::update_loudnesses(&avc, &params, &mut old, 100).unwrap();
+        assert_eq!(old, params);
+        {
+            let frames = avc.frames.borrow();
+            assert_eq!(frames.len(), 3);
+            assert_eq!(&frames[1][4..], &[0x03, 0x10, 0x02, 0x01, 0x0c, 0x01, 0x70]);
+            assert_eq!(&frames[2][4..], &[0x04, 0x10, 0x02, 0x00, 0x0c, 0x01, 0x70]);
+        }
+
+        // The update aborts at the first failure.
+        let avc = TestProcessingAvc {
+            rejected_func_block_id: Some(0x03),
+            ..Default::default()
+        };
+        params.loudnesses = vec![false; 3];
+        let err = TestToneProtocol::update_loudnesses(&avc, &params, &mut old, 100);
+        assert!(err.is_err());
+        assert_eq!(old.loudnesses, vec![true, false, true]);
+        assert_eq!(avc.frames.borrow().len(), 1);
+    }
+
+    struct TestSelectorProtocol;
+
+    impl AvcSelectorOperation for TestSelectorProtocol {
+        const FUNC_BLOCK_ID_LIST: &'static [u8] = &[0x01, 0x02];
+        const INPUT_PLUG_ID_LIST: &'static [u8] = &[0x00, 0x01];
+        const SOURCE_NAMES: &'static [&'static str] = &["source-1", "source-2"];
+    }
+
+    #[test]
+    fn selector_source_name() {
+        assert_eq!(TestSelectorProtocol::source_name(0), Some("source-1"));
+        assert_eq!(TestSelectorProtocol::source_name(1), Some("source-2"));
+        assert_eq!(TestSelectorProtocol::source_name(2), None);
+    }
+
+    #[test]
+    fn level_db_conversion() {
+        assert_eq!(TestLevelProtocol::level_to_db(0), 0.0);
+        assert_eq!(TestLevelProtocol::level_from_db(0.0), 0);
+
+        assert_eq!(TestLevelProtocol::level_to_db(-0x100), -1.0);
+        assert_eq!(TestLevelProtocol::level_from_db(-1.0), -0x100);
+
+        assert_eq!(
+            TestLevelProtocol::level_from_db(-127.9961),
+            VolumeData::VALUE_MIN
+        );
+        assert!((TestLevelProtocol::level_to_db(VolumeData::VALUE_MIN) + 127.9961).abs() < 0.0001);
+
+        assert_eq!(
+            TestLevelProtocol::level_from_db(f64::NEG_INFINITY),
+            VolumeData::VALUE_NEG_INFINITY
+        );
+        assert_eq!(
+            TestLevelProtocol::level_to_db(VolumeData::VALUE_NEG_INFINITY),
+            f64::NEG_INFINITY
+        );
+
+        // Saturated.
+        assert_eq!(
+            TestLevelProtocol::level_from_db(6.0),
+            TestLevelProtocol::LEVEL_MAX
+        );
+        assert_eq!(
+            TestLevelProtocol::level_from_db(-200.0),
+            TestLevelProtocol::LEVEL_MIN
+        );
+    }
+
+    struct TestMuteProtocol;
+
+    impl AvcAudioFeatureSpecification for TestMuteProtocol {
+        const ENTRIES: &'static [(u8, AudioCh)] = &[
+            (0x01, AudioCh::Each(0)),
+            (0x01, AudioCh::Each(1)),
+            (0x02, AudioCh::Master),
+        ];
+    }
+
+    impl AvcMuteOperation for TestMuteProtocol {}
+
+    #[test]
+    fn mute_all_without_redundant_operation() {
+        let avc = TestProcessingAvc::default();
+
+        let mut params = TestMuteProtocol::create_mute_parameters();
+        params.mutes[1] = true;
+        TestMuteProtocol::mute_all(&avc, &mut params, 100).unwrap();
+        assert_eq!(params.mutes, vec![true; TestMuteProtocol::ENTRIES.len()]);
+        {
+            // The entries not muted yet are operated.
+            let frames = avc.frames.borrow();
+            assert_eq!(frames.len(), 2);
+            assert_eq!(&frames[0][4..8], &[0x01, 0x10, 0x02, 0x01]);
+            assert_eq!(&frames[1][4..8], &[0x02, 0x10, 0x02, 0x00]);
+        }
+
+        TestMuteProtocol::mute_all(&avc, &mut params, 100).unwrap();
+        assert_eq!(avc.frames.borrow().len(), 2);
+
+        TestMuteProtocol::unmute_all(&avc, &mut params, 100).unwrap();
+        assert_eq!(params.mutes, vec![false; TestMuteProtocol::ENTRIES.len()]);
+        assert_eq!(avc.frames.borrow().len(), 5);
+
+        TestMuteProtocol::unmute_all(&avc, &mut params, 100).unwrap();
+        assert_eq!(avc.frames.borrow().len(), 5);
+    }
+
+    #[test]
+    fn mute_all_with_failure() {
+        let avc = TestProcessingAvc {
+            rejected_func_block_id: Some(0x01),
+            ..Default::default()
+        };
+
+        // The error of the first operation is delivered, and the parameters are left as is.
+        let mut params = TestMuteProtocol::create_mute_parameters();
+        assert!(TestMuteProtocol::mute_all(&avc, &mut params, 100).is_err());
+        assert_eq!(params.mutes, vec![false; TestMuteProtocol::ENTRIES.len()]);
+        assert_eq!(avc.frames.borrow().len(), 1);
+
+        // The operation aborts at the entry for rejected function block.
+        let avc = TestProcessingAvc {
+            rejected_func_block_id: Some(0x02),
+            ..Default::default()
+        };
+        let mut params = TestMuteProtocol::create_mute_parameters();
+        let err = TestMuteProtocol::mute_all(&avc, &mut params, 100).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Io));
+        assert_eq!(params.mutes, vec![true, true, false]);
+        assert_eq!(avc.frames.borrow().len(), 3);
+    }
+
+    struct TestBalanceProtocol;
+
+    impl AvcAudioFeatureSpecification for TestBalanceProtocol {
+        const ENTRIES: &'static [(u8, AudioCh)] = &[
+            (0x03, AudioCh::Each(0)),
+            (0x03, AudioCh::Each(1)),
+            (0x04, AudioCh::Master),
+        ];
+    }
+
+    impl AvcLrBalanceOperation for TestBalanceProtocol {}
+
+    #[test]
+    fn center_balances() {
+        let avc = TestProcessingAvc::default();
+
+        let mut params = TestBalanceProtocol::create_lr_balance_parameters();
+        params.balances[1] = 0x100;
+        TestBalanceProtocol::center_balances(&avc, &mut params, 100).unwrap();
+        assert_eq!(
+            params.balances,
+            vec![LrBalanceData::VALUE_LEFT_ZERO; TestBalanceProtocol::ENTRIES.len()]
+        );
+        {
+            // Just for the entry not centered yet.
+            let frames = avc.frames.borrow();
+            assert_eq!(frames.len(), 1);
+            assert_eq!(&frames[0][4..8], &[0x03, 0x10, 0x02, 0x02]);
+        }
+
+        TestBalanceProtocol::center_balances(&avc, &mut params, 100).unwrap();
+        assert_eq!(avc.frames.borrow().len(), 1);
+
+        // The error of the first operation is delivered, and the parameters are left as is.
+        let avc = TestProcessingAvc {
+            rejected_func_block_id: Some(0x03),
+            ..Default::default()
+        };
+        params.balances[1] = 0x100;
+        params.balances[2] = 0x100;
+        assert!(TestBalanceProtocol::center_balances(&avc, &mut params, 100).is_err());
+        assert_eq!(params.balances, vec![0, 0x100, 0x100]);
+        assert_eq!(avc.frames.borrow().len(), 1);
+
+        // The update of single entry does not touch the others.
+        TestBalanceProtocol::update_balance_single(&avc, 2, 0, &mut params, 100).unwrap();
+        assert_eq!(params.balances, vec![0, 0x100, 0]);
+        let err =
+            TestBalanceProtocol::update_balance_single(&avc, 3, 0, &mut params, 100).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Inval));
+        assert_eq!(params.balances, vec![0, 0x100, 0]);
+        assert_eq!(avc.frames.borrow().len(), 2);
+    }
+
+    #[test]
+    fn update_single_entry() {
+        let avc = TestProcessingAvc::default();
+
+        let mut params = TestMuteProtocol::create_mute_parameters();
+        TestMuteProtocol::update_mute_single(&avc, 1, true, &mut params, 100).unwrap();
+        assert_eq!(params.mutes, vec![false, true, false]);
+        TestMuteProtocol::update_mute_single(&avc, 1, true, &mut params, 100).unwrap();
+        assert_eq!(avc.frames.borrow().len(), 1);
+        assert_eq!(&avc.frames.borrow()[0][4..8], &[0x01, 0x10, 0x02, 0x02]);
+        let err =
+            TestMuteProtocol::update_mute_single(&avc, 3, true, &mut params, 100).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Inval));
+        assert_eq!(params.mutes, vec![false, true, false]);
+        assert_eq!(avc.frames.borrow().len(), 1);
+
+        let mut params = TestLevelProtocol::create_level_parameters();
+        TestLevelProtocol::update_level_single(&avc, 0, -0x100, &mut params, 100).unwrap();
+        assert_eq!(params.levels, vec![-0x100]);
+        assert_eq!(avc.frames.borrow().len(), 2);
+        let err = TestLevelProtocol::update_level_single(&avc, 1, 0, &mut params, 100).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Inval));
+        assert_eq!(params.levels, vec![-0x100]);
+
+        let mut params = TestLevelProtocol::create_lr_balance_parameters();
+        TestLevelProtocol::update_balance_single(&avc, 0, 0x80, &mut params, 100).unwrap();
+        assert_eq!(params.balances, vec![0x80]);
+        assert_eq!(avc.frames.borrow().len(), 3);
+
+        let mut params = TestSelectorProtocol::create_selector_parameters();
+        TestSelectorProtocol::update_selector_single(&avc, 1, 1, &mut params, 100).unwrap();
+        assert_eq!(params.selectors, vec![0, 1]);
+        assert_eq!(avc.frames.borrow()[3][4], 0x02);
+        let err =
+            TestSelectorProtocol::update_selector_single(&avc, 2, 1, &mut params, 100).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Inval));
+        assert_eq!(params.selectors, vec![0, 1]);
+        assert_eq!(avc.frames.borrow().len(), 4);
+
+        // The parameters are left as is at failure.
+        let avc = TestProcessingAvc {
+            rejected_func_block_id: Some(0x01),
+            ..Default::default()
+        };
+
+        let mut params = TestMuteProtocol::create_mute_parameters();
+        assert!(TestMuteProtocol::update_mute_single(&avc, 0, true, &mut params, 100).is_err());
+        assert_eq!(params.mutes, vec![false, false, false]);
+
+        let mut params = TestLevelProtocol::create_level_parameters();
+        assert!(TestLevelProtocol::update_level_single(&avc, 0, -0x100, &mut params, 100).is_err());
+        assert_eq!(params.levels, vec![0]);
+
+        let mut params = TestLevelProtocol::create_lr_balance_parameters();
+        assert!(TestLevelProtocol::update_balance_single(&avc, 0, 0x80, &mut params, 100).is_err());
+        assert_eq!(params.balances, vec![0]);
+
+        let mut params = TestSelectorProtocol::create_selector_parameters();
+        assert!(
+            TestSelectorProtocol::update_selector_single(&avc, 0, 1, &mut params, 100).is_err()
+        );
+        assert_eq!(params.selectors, vec![0, 0]);
+        assert_eq!(avc.frames.borrow().len(), 4);
+    }
 }
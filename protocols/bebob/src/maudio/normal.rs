@@ -268,6 +268,7 @@ impl AvcSelectorOperation for Fw410PhysOutputProtocol {
     const FUNC_BLOCK_ID_LIST: &'static [u8] = &[0x02, 0x03, 0x04, 0x05, 0x06];
     // NOTE: "mixer-output", "aux-output-1/2"
     const INPUT_PLUG_ID_LIST: &'static [u8] = &[0x00, 0x01];
+    const SOURCE_NAMES: &'static [&'static str] = &["mixer-output", "aux-output-1/2"];
 }
 
 /// The protocol implementation for source of aux mixer in FireWire 410.
@@ -325,6 +326,7 @@ impl AvcSelectorOperation for Fw410HeadphoneProtocol {
     const FUNC_BLOCK_ID_LIST: &'static [u8] = &[0x07];
     // NOTE: "mixer", "aux-1/2".
     const INPUT_PLUG_ID_LIST: &'static [u8] = &[0x00, 0x01];
+    const SOURCE_NAMES: &'static [&'static str] = &["mixer-output", "aux-output-1/2"];
 }
 
 /// The protocol implementation for source of S/PDIF output in FireWire 410.
@@ -335,6 +337,7 @@ impl AvcSelectorOperation for Fw410SpdifOutputProtocol {
     const FUNC_BLOCK_ID_LIST: &'static [u8] = &[0x01];
     // NOTE: "Coaxial", "Optical".
     const INPUT_PLUG_ID_LIST: &'static [u8] = &[0x00, 0x01];
+    const SOURCE_NAMES: &'static [&'static str] = &["coaxial-input-1/2", "optical-input-1/2"];
 }
 
 /// The protocol implementation for mixer in FireWire 410.
@@ -451,6 +454,7 @@ impl AvcSelectorOperation for SoloSpdifOutputProtocol {
     const FUNC_BLOCK_ID_LIST: &'static [u8] = &[0x01];
     // NOTE: "stream-3/4", "mixer-3/4".
     const INPUT_PLUG_ID_LIST: &'static [u8] = &[0x00, 0x01];
+    const SOURCE_NAMES: &'static [&'static str] = &["stream-input-3/4", "mixer-output-3/4"];
 }
 
 /// The protocol implementation for mixer in FireWire Solo.
@@ -548,6 +552,7 @@ impl AvcSelectorOperation for AudiophilePhysOutputProtocol {
     const FUNC_BLOCK_ID_LIST: &'static [u8] = &[0x01, 0x02, 0x03];
     // NOTE: "mixer-output", "aux-output-1/2"
     const INPUT_PLUG_ID_LIST: &'static [u8] = &[0x00, 0x01];
+    const SOURCE_NAMES: &'static [&'static str] = &["mixer-output", "aux-output-1/2"];
 }
 
 /// The protocol implementation for source of aux mixer in FireWire Audiophile.
@@ -601,6 +606,12 @@ impl AvcSelectorOperation for AudiophileHeadphoneProtocol {
     const FUNC_BLOCK_ID_LIST: &'static [u8] = &[0x04];
     // NOTE: "mixer-1/2", "mixer-3/4", "mixer-5/6", "aux-1/2".
     const INPUT_PLUG_ID_LIST: &'static [u8] = &[0x00, 0x01, 0x02, 0x03];
+    const SOURCE_NAMES: &'static [&'static str] = &[
+        "mixer-output-1/2",
+        "mixer-output-3/4",
+        "mixer-output-5/6",
+        "aux-output-1/2",
+    ];
 }
 
 /// The protocol implementation for mixer in FireWire Solo.
@@ -694,6 +705,7 @@ impl AvcLevelOperation for OzonicStreamInputProtocol {}
 
 /// The state of switch with LED specific to FireWire Audiophile.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AudiophileSwitchState {
     Off,
     A,
@@ -862,22 +874,32 @@ pub trait MaudioNormalMeterProtocol {
         meter: &mut MaudioNormalMeter,
         timeout_ms: u32,
     ) -> Result<(), Error> {
-        let frame = &mut meter.cache;
-
-        // For rotaries, switch, and sync_status if available.
-        let mut bitmap = [0; 4];
-        let pos = frame.len() - 4;
-        bitmap.copy_from_slice(&frame[pos..]);
+        let mut frame = meter.cache.to_vec();
 
         req.transaction(
             node,
             FwTcode::ReadBlockRequest,
             DM_APPL_METER_OFFSET,
             frame.len(),
-            frame,
+            &mut frame,
             timeout_ms,
         )?;
 
+        Self::parse_meter(meter, &frame);
+
+        Ok(())
+    }
+
+    fn parse_meter(meter: &mut MaudioNormalMeter, frame: &[u8]) {
+        assert_eq!(frame.len(), meter.cache.len());
+
+        // For rotaries, switch, and sync_status if available.
+        let mut bitmap = [0; 4];
+        let pos = meter.cache.len() - 4;
+        bitmap.copy_from_slice(&meter.cache[pos..]);
+
+        meter.cache.copy_from_slice(frame);
+
         let mut quadlet = [0; 4];
 
         meter.phys_inputs.iter_mut().enumerate().for_each(|(i, m)| {
@@ -953,8 +975,6 @@ pub trait MaudioNormalMeterProtocol {
         if let Some(sync_status) = &mut meter.sync_status {
             *sync_status = frame[frame.len() - 1] > 0;
         }
-
-        Ok(())
     }
 }
 
@@ -978,6 +998,7 @@ impl MaudioNormalMixerOperation for OzonicMixerProtocol {
 /// The parameter of mixer. The `Default` trait should be implemented to call
 /// `MaudioNormalMixerOperation::create_mixer_parameters()`.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MaudioNormalMixerParameters(pub Vec<Vec<bool>>);
 
 /// The trait for mixer operation.
@@ -1068,3 +1089,47 @@ pub trait MaudioNormalMixerOperation {
             )
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct TestMeterProtocol;
+
+    impl MaudioNormalMeterProtocol for TestMeterProtocol {
+        const PHYS_INPUT_COUNT: usize = 2;
+        const STREAM_INPUT_COUNT: usize = 2;
+        const PHYS_OUTPUT_COUNT: usize = 4;
+        const ROTARY_COUNT: usize = 0;
+        const HAS_SWITCH: bool = false;
+        const HAS_SYNC_STATUS: bool = true;
+    }
+
+    #[test]
+    fn normal_meter_parse() {
+        let mut meter = TestMeterProtocol::create_meter();
+
+        let frame = [
+            0x00, 0x00, 0x00, 0x01, // phys-input-1
+            0x7f, 0xff, 0xff, 0xff, // phys-input-2
+            0x00, 0x00, 0x01, 0x00, // stream-input-1
+            0xff, 0xff, 0xff, 0xff, // stream-input-2
+            0x00, 0x01, 0x00, 0x00, // phys-output-1
+            0x01, 0x00, 0x00, 0x00, // phys-output-2
+            0x80, 0x00, 0x00, 0x00, // phys-output-3
+            0x00, 0x00, 0x00, 0x00, // phys-output-4
+            0x00, 0x00, 0x00, 0x01, // bitmap
+        ];
+        TestMeterProtocol::parse_meter(&mut meter, &frame);
+
+        assert_eq!(meter.phys_inputs, vec![0x00000001, 0x7fffffff]);
+        assert_eq!(meter.stream_inputs, Some(vec![0x00000100, -1]));
+        assert_eq!(
+            meter.phys_outputs,
+            vec![0x00010000, 0x01000000, i32::MIN, 0x00000000]
+        );
+        assert_eq!(meter.headphone, None);
+        assert_eq!(meter.aux_output, None);
+        assert_eq!(meter.sync_status, Some(true));
+    }
+}
@@ -121,6 +121,7 @@ pub struct PflInputParametersProtocol;
 
 /// Nominal frequency detected for any external input.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PflDetectedInputFreq {
     Unavailable,
     R44100,
@@ -218,6 +219,7 @@ const CACHE_SIZE: usize = 24;
 
 /// Parameters of input configuration.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PflInputParameters {
     /// Whether to mute ADAT inputs.
     pub adat_mute: [bool; 4],
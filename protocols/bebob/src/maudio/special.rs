@@ -410,6 +410,7 @@ impl Default for MaudioSpecialStateCache {
 
 /// Parameters of input.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MaudioSpecialInputParameters {
     /// The gains of stream inputs.
     pub stream_gains: [i16; 4],
@@ -539,6 +540,7 @@ impl SpecialParametersSerdes<MaudioSpecialInputParameters> for MaudioSpecialInpu
 
 /// Source of analog output.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OutputSource {
     /// The corresponding pair of mixer outputs.
     MixerOutputPair,
@@ -554,6 +556,7 @@ impl Default for OutputSource {
 
 /// Source of headphone.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HeadphoneSource {
     /// The 1st pair of mixer outputs.
     MixerOutputPair0,
@@ -571,6 +574,7 @@ impl Default for HeadphoneSource {
 
 /// Parameters of output.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MaudioSpecialOutputParameters {
     /// The volume of analog outputs.
     pub analog_volumes: [i16; 4],
@@ -735,6 +739,7 @@ impl SpecialParametersSerdes<MaudioSpecialOutputParameters> for MaudioSpecialOut
 
 /// Parameters of aux signal multiplexer.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MaudioSpecialAuxParameters {
     /// The volume of outputs.
     pub output_volumes: [i16; 2],
@@ -838,6 +843,7 @@ impl SpecialParametersSerdes<MaudioSpecialAuxParameters> for MaudioSpecialAuxPro
 
 /// Parameters of signal multiplexer.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MaudioSpecialMixerParameters {
     /// Enable/Disable the pairs of analog inputs.
     pub analog_pairs: [[bool; 4]; 2],
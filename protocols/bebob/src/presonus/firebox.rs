@@ -83,6 +83,7 @@ impl AvcSelectorOperation for FireboxPhysOutputProtocol {
     const FUNC_BLOCK_ID_LIST: &'static [u8] = &[0x01, 0x02, 0x03, 0x05];
     // NOTE: "stream-input", "mixer-output-1/2"
     const INPUT_PLUG_ID_LIST: &'static [u8] = &[0x00, 0x01];
+    const SOURCE_NAMES: &'static [&'static str] = &["stream-input", "mixer-output-1/2"];
 }
 
 /// The protocol implementation of headphone.
@@ -103,6 +104,13 @@ impl AvcSelectorOperation for FireboxHeadphoneProtocol {
     // NOTE: "stream-input-1/2", "stream-input-3/4", "stream-input-5/6", "stream-input-7/8",
     //       "mixer-output-1/2"
     const INPUT_PLUG_ID_LIST: &'static [u8] = &[0x00, 0x01, 0x02, 0x03, 0x04];
+    const SOURCE_NAMES: &'static [&'static str] = &[
+        "stream-input-1/2",
+        "stream-input-3/4",
+        "stream-input-5/6",
+        "stream-input-7/8",
+        "mixer-output-1/2",
+    ];
 }
 
 /// The protocol implementation of physical source for mixer.
@@ -143,6 +151,12 @@ impl AvcSelectorOperation for FireboxMixerStreamSourceProtocol {
     const FUNC_BLOCK_ID_LIST: &'static [u8] = &[0x06];
     // NOTE: "stream-input-1/2", "stream-input-3/4", "stream-input-5/6", "stream-input-7/8",
     const INPUT_PLUG_ID_LIST: &'static [u8] = &[0x00, 0x01, 0x02, 0x03];
+    const SOURCE_NAMES: &'static [&'static str] = &[
+        "stream-input-1/2",
+        "stream-input-3/4",
+        "stream-input-5/6",
+        "stream-input-7/8",
+    ];
 }
 /// The protocol implementation of mixer output.
 #[derive(Default, Debug)]
@@ -160,6 +174,7 @@ impl AvcMuteOperation for FireboxMixerOutputProtocol {}
 
 /// The parameters of analog inputs.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FireboxAnalogInputParameters {
     /// Boost signal for analog input 1-4.
     pub boosts: [bool; FireboxAnalogInputProtocol::CH_COUNT],
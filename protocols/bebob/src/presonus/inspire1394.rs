@@ -104,6 +104,7 @@ impl AvcSelectorOperation for Inspire1394PhysOutputProtocol {
     const FUNC_BLOCK_ID_LIST: &'static [u8] = &[0x01];
     // NOTE: "mixer-output-1/2", "stream-input-1/2"
     const INPUT_PLUG_ID_LIST: &'static [u8] = &[0x00, 0x01];
+    const SOURCE_NAMES: &'static [&'static str] = &["mixer-output-1/2", "stream-input-1/2"];
 }
 
 /// The protocol implementation of headphone.
@@ -208,6 +209,7 @@ impl Inspire1394MeterProtocol {
 
 /// The parameters of input switches.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Inspire1394SwitchParameters {
     /// Phono mode in 2nd input pair (lines).
     pub pair1_phono: bool,
@@ -285,6 +287,7 @@ impl Inspire1394SwitchProtocol {
 
 /// The switch related to inputs.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InputSwitch {
     /// Phono mode for 2nd pair of inputs (line).
     Analog34Phono(bool),
@@ -94,6 +94,12 @@ impl AvcSelectorOperation for AureonMonitorSourceProtocol {
     const FUNC_BLOCK_ID_LIST: &'static [u8] = &[0x01];
     // NOTE: "analog-input-1/2", "analog-input-3/4", "analog-input-5/6", "digital-input-1/2"
     const INPUT_PLUG_ID_LIST: &'static [u8] = &[0x00, 0x01, 0x02, 0x03];
+    const SOURCE_NAMES: &'static [&'static str] = &[
+        "analog-input-1/2",
+        "analog-input-3/4",
+        "analog-input-5/6",
+        "digital-input-1/2",
+    ];
 }
 
 /// The protocol implementation of monitor source.
@@ -118,4 +124,5 @@ impl AvcSelectorOperation for AureonSpdifOutputProtocol {
     const FUNC_BLOCK_ID_LIST: &'static [u8] = &[0x02];
     // NOTE: "mixer-output-1/2", "stream-input-9/10"
     const INPUT_PLUG_ID_LIST: &'static [u8] = &[0x00, 0x01];
+    const SOURCE_NAMES: &'static [&'static str] = &["mixer-output-1/2", "stream-input-9/10"];
 }
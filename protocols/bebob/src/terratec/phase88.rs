@@ -129,6 +129,7 @@ impl AvcSelectorOperation for Phase88PhysInputProtocol {
         0x00, // line
         0x01, // mic
     ];
+    const SOURCE_NAMES: &'static [&'static str] = &["line", "mic"];
 }
 
 /// The protocol implementation of source of physical input to mixer.
@@ -178,6 +179,13 @@ impl AvcSelectorOperation for Phase88MixerStreamSourceProtocol {
         0x04, // stream-input-7/8
         0x00, // stream-input-9/10
     ];
+    const SOURCE_NAMES: &'static [&'static str] = &[
+        "stream-input-1/2",
+        "stream-input-3/4",
+        "stream-input-5/6",
+        "stream-input-7/8",
+        "stream-input-9/10",
+    ];
 }
 
 /// The protocol implementation of mixer output.
@@ -219,6 +227,14 @@ const PHYS_OUTPUT_SELECTOR_ID_LIST: [u8; 2] = [0x00, 0x01];
 impl AvcSelectorOperation for Phase88MixerOutputProtocol {
     const FUNC_BLOCK_ID_LIST: &'static [u8] = &MIXER_OUT_SELECTOR_FB_ID_LIST;
     const INPUT_PLUG_ID_LIST: &'static [u8] = &MIXER_OUT_SELECTOR_ID_LIST;
+    const SOURCE_NAMES: &'static [&'static str] = &[
+        "analog-output-1/2",
+        "analog-output-3/4",
+        "analog-output-5/6",
+        "analog-output-7/8",
+        "digital-output-1/2",
+        "unused",
+    ];
 
     fn cache_selectors(
         avc: &BebobAvc,
@@ -172,6 +172,7 @@ impl AvcSelectorOperation for GoPhase24CoaxPhysInputProtocol {
     // Unused.
     const FUNC_BLOCK_ID_LIST: &'static [u8] = &[0x00];
     const INPUT_PLUG_ID_LIST: &'static [u8] = &[0x00, 0x01, 0x02];
+    const SOURCE_NAMES: &'static [&'static str] = &["low", "middle", "high"];
 
     fn cache_selectors(
         avc: &BebobAvc,
@@ -257,6 +258,14 @@ impl AvcSelectorOperation for GoPhase24CoaxPhysOutputProtocol {
         0x04, // mixer-output-1/2
         0x05, // stream-input-5/6
     ];
+    const SOURCE_NAMES: &'static [&'static str] = &[
+        "stream-input-1/2",
+        "stream-input-3/4",
+        "analog-input-1/2",
+        "digital-input-1/2",
+        "mixer-output-1/2",
+        "stream-input-5/6",
+    ];
 }
 /// The protocol implementation of physical output for optical models.
 #[derive(Default, Debug)]
@@ -289,6 +298,14 @@ impl AvcSelectorOperation for GoPhase24OptPhysOutputProtocol {
         0x04, // mixer-output-1/2
         0x05, // stream-input-5/6
     ];
+    const SOURCE_NAMES: &'static [&'static str] = &[
+        "stream-input-1/2",
+        "stream-input-3/4",
+        "analog-input-1/2",
+        "digital-input-1/2",
+        "mixer-output-1/2",
+        "stream-input-5/6",
+    ];
 }
 
 /// The protocol implementation of mixer source gain for coaxial model.
@@ -304,6 +321,14 @@ impl AvcSelectorOperation for GoPhase24CoaxHeadphoneProtocol {
         0x04, // mixer-output-1/2
         0x05, // stream-input-5/6
     ];
+    const SOURCE_NAMES: &'static [&'static str] = &[
+        "stream-input-1/2",
+        "stream-input-3/4",
+        "analog-input-1/2",
+        "digital-input-1/2",
+        "mixer-output-1/2",
+        "stream-input-5/6",
+    ];
 }
 
 /// The protocol implementation of mixer source gain.
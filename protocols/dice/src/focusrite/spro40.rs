@@ -208,3 +208,31 @@ impl SaffireproIoParamsSpecification for SPro40Protocol {
     const AESEBU_IS_SUPPORTED: bool = false;
     const MIC_PREAMP_TRANSFORMER_IS_SUPPORTED: bool = false;
 }
+
+#[cfg(test)]
+mod test {
+    use {super::*, crate::tcat::extension::cmd_section::RateMode};
+
+    #[test]
+    fn analog_input_blocks() {
+        [RateMode::Low, RateMode::Middle, RateMode::High]
+            .iter()
+            .for_each(|&rate_mode| {
+                let (srcs, _) = SPro40Protocol::compute_avail_real_blk_pair(rate_mode);
+                let analog_inputs: Vec<u8> = srcs
+                    .iter()
+                    .filter(|src| src.id == SrcBlkId::Ins1)
+                    .map(|src| src.ch)
+                    .collect();
+                assert_eq!(analog_inputs, (0..8).collect::<Vec<u8>>());
+
+                let pos = srcs
+                    .iter()
+                    .position(|src| src.id == SrcBlkId::Ins1)
+                    .unwrap();
+                assert!(srcs[pos..(pos + 8)]
+                    .iter()
+                    .all(|src| src.id == SrcBlkId::Ins1));
+            });
+    }
+}
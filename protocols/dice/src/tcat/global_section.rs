@@ -405,6 +405,38 @@ const EXTERNAL_CLOCK_SOURCE_TABLE: [ClockSource; 11] = [
     ClockSource::WordClock,
 ];
 
+const CLOCK_SOURCE_CANONICAL_LABEL_TABLE: [(ClockSource, &str); 13] = [
+    (ClockSource::Aes1, "AES1"),
+    (ClockSource::Aes2, "AES2"),
+    (ClockSource::Aes3, "AES3"),
+    (ClockSource::Aes4, "AES4"),
+    (ClockSource::AesAny, "AES-ANY"),
+    (ClockSource::Adat, "ADAT"),
+    (ClockSource::Tdif, "TDIF"),
+    (ClockSource::WordClock, "Word-Clock"),
+    (ClockSource::Arx1, "Stream-1"),
+    (ClockSource::Arx2, "Stream-2"),
+    (ClockSource::Arx3, "Stream-3"),
+    (ClockSource::Arx4, "Stream-4"),
+    (ClockSource::Internal, "Internal"),
+];
+
+/// Generate canonical names for the available sources of sampling clock, in the order of
+/// `GlobalParameters::avail_sources`. The list is derived from the clock capabilities, thus the
+/// source unavailable in the device is omitted instead of labelled.
+pub fn clock_source_labels(params: &GlobalParameters) -> Vec<String> {
+    params
+        .avail_sources
+        .iter()
+        .filter_map(|src| {
+            CLOCK_SOURCE_CANONICAL_LABEL_TABLE
+                .iter()
+                .find(|(s, _)| src.eq(s))
+                .map(|(_, label)| label.to_string())
+        })
+        .collect()
+}
+
 impl<O: TcatOperation + TcatGlobalSectionSpecification> TcatSectionSerdes<GlobalParameters> for O {
     const MIN_SIZE: usize = 96;
 
@@ -762,6 +794,7 @@ mod test {
                 (ClockSource::Internal, "INTERNAL".to_string()),
             ]
         );
+        assert_eq!(clock_source_labels(&params), vec!["Internal".to_string()]);
 
         let mut r = vec![0u8; raw.len()];
         Protocol::serialize(&params, &mut r).unwrap();
@@ -830,4 +863,30 @@ mod test {
 
         assert_eq!(r, raw);
     }
+
+    #[test]
+    fn clock_source_labels_from_caps() {
+        let mut params = GlobalParameters {
+            avail_sources: vec![
+                ClockSource::Aes1,
+                ClockSource::Adat,
+                ClockSource::WordClock,
+                ClockSource::Reserved(0x0d),
+                ClockSource::Internal,
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            clock_source_labels(&params),
+            vec![
+                "AES1".to_string(),
+                "ADAT".to_string(),
+                "Word-Clock".to_string(),
+                "Internal".to_string(),
+            ]
+        );
+
+        params.avail_sources.clear();
+        assert_eq!(clock_source_labels(&params), Vec::<String>::new());
+    }
 }
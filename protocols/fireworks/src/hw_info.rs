@@ -211,8 +211,8 @@ where
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct HwMeter {
     pub detected_clk_srcs: Vec<(ClkSrc, bool)>,
-    pub detected_midi_inputs: [bool; 2],
-    pub detected_midi_outputs: [bool; 2],
+    pub detected_midi_inputs: Vec<bool>,
+    pub detected_midi_outputs: Vec<bool>,
     pub guitar_charging: bool,
     pub guitar_stereo_connect: bool,
     pub guitar_hex_signal: bool,
@@ -224,8 +224,8 @@ impl Default for HwMeter {
     fn default() -> Self {
         Self {
             detected_clk_srcs: Vec::new(),
-            detected_midi_inputs: [false; 2],
-            detected_midi_outputs: [false; 2],
+            detected_midi_inputs: Vec::new(),
+            detected_midi_outputs: Vec::new(),
             guitar_charging: false,
             guitar_stereo_connect: false,
             guitar_hex_signal: false,
@@ -237,10 +237,18 @@ impl Default for HwMeter {
 
 impl HwMeter {
     /// The constructor for structure expressing hardware meter.
-    pub fn new(clk_srcs: &[ClkSrc], phys_inputs: usize, phys_outputs: usize) -> Self {
+    pub fn new(
+        clk_srcs: &[ClkSrc],
+        midi_inputs: usize,
+        midi_outputs: usize,
+        phys_inputs: usize,
+        phys_outputs: usize,
+    ) -> Self {
         let mut meter = Self::default();
 
         meter.detected_clk_srcs = clk_srcs.iter().map(|&src| (src, false)).collect();
+        meter.detected_midi_inputs = vec![false; midi_inputs];
+        meter.detected_midi_outputs = vec![false; midi_outputs];
         meter.phys_output_meters = vec![0; phys_outputs];
         meter.phys_input_meters = vec![0; phys_inputs];
 
@@ -359,3 +367,21 @@ where
             .map(|_| states.data.copy_from_slice(&params[2..]))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn hw_meter_midi_ports() {
+        let mut meter = HwMeter::new(&[ClkSrc::Internal], 2, 2, 0, 0);
+
+        let mut quads = vec![0u32; METER_QUADS];
+        quads[0] = 1 << 9;
+        meter.parse(&quads);
+
+        assert_eq!(meter.detected_midi_inputs, vec![false, true]);
+        assert_eq!(meter.detected_midi_outputs, vec![false, true]);
+        assert_eq!(meter.detected_clk_srcs, vec![(ClkSrc::Internal, false)]);
+    }
+}
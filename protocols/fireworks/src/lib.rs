@@ -12,6 +12,7 @@ pub mod phys_output;
 pub mod playback;
 pub mod port_conf;
 pub mod robot_guitar;
+pub mod snapshot;
 pub mod transaction;
 pub mod transport;
 
@@ -20,6 +21,9 @@ pub mod onyx_f;
 
 pub mod rip;
 
+#[cfg(test)]
+mod mock;
+
 use {
     glib::{Error, FileError},
     hitaki::{prelude::EfwProtocolExtManual, EfwProtocolError},
@@ -68,14 +72,47 @@ pub trait EfwHardwareSpecification {
             .fold(0, |count, entry| count + entry.1)
     }
 
+    /// Whether the sampling transfer frequency is supported.
+    fn supports_rate(freq: u32) -> bool {
+        Self::SUPPORTED_SAMPLING_RATES.contains(&freq)
+    }
+
+    /// Whether the source of sampling clock is supported.
+    fn supports_clock(src: ClkSrc) -> bool {
+        Self::SUPPORTED_SAMPLING_CLOCKS.contains(&src)
+    }
+
+    /// The index of rate mode for the sampling transfer frequency, to refer to the number of
+    /// audio channels in isochronous stream.
+    fn rate_mode_for_freq(freq: u32) -> Option<usize> {
+        match freq {
+            32000 | 44100 | 48000 => Some(0),
+            88200 | 96000 => Some(1),
+            176400 | 192000 => Some(2),
+            _ => None,
+        }
+    }
+
+    /// The number of audio channels in received isochronous stream at the sampling transfer
+    /// frequency.
+    fn rx_channel_count_for_rate(freq: u32) -> Option<usize> {
+        Self::rate_mode_for_freq(freq).map(|mode| Self::RX_CHANNEL_COUNTS[mode])
+    }
+
+    /// The number of audio channels in transmitted isochronous stream at the sampling transfer
+    /// frequency.
+    fn tx_channel_count_for_rate(freq: u32) -> Option<usize> {
+        Self::rate_mode_for_freq(freq).map(|mode| Self::TX_CHANNEL_COUNTS[mode])
+    }
+
     fn create_hardware_meter() -> HwMeter {
         HwMeter {
             detected_clk_srcs: Self::SUPPORTED_SAMPLING_CLOCKS
                 .iter()
                 .map(|&src| (src, Default::default()))
                 .collect(),
-            detected_midi_inputs: Default::default(),
-            detected_midi_outputs: Default::default(),
+            detected_midi_inputs: vec![Default::default(); Self::MIDI_INPUT_COUNT],
+            detected_midi_outputs: vec![Default::default(); Self::MIDI_OUTPUT_COUNT],
             guitar_charging: Default::default(),
             guitar_stereo_connect: Default::default(),
             guitar_hex_signal: Default::default(),
@@ -158,6 +195,27 @@ impl Default for ClkSrc {
     }
 }
 
+impl ClkSrc {
+    /// Whether the source of sampling clock is external signal.
+    pub fn is_external(&self) -> bool {
+        !matches!(self, Self::Internal | Self::Continuous)
+    }
+}
+
+impl std::fmt::Display for ClkSrc {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Internal => write!(f, "Internal"),
+            Self::WordClock => write!(f, "Word Clock"),
+            Self::Spdif => write!(f, "S/PDIF"),
+            Self::Adat => write!(f, "ADAT"),
+            Self::Adat2 => write!(f, "ADAT-2"),
+            Self::Continuous => write!(f, "Continuous"),
+            Self::Reserved(val) => write!(f, "Reserved({})", val),
+        }
+    }
+}
+
 fn serialize_clock_source(src: &ClkSrc) -> u32 {
     match src {
         ClkSrc::Internal => 0,
@@ -219,6 +277,9 @@ pub enum HwCap {
     RobotGuitar,
     /// Support chaging for guitar.
     GuitarCharging,
+    /// Solo of playback is not functional. This is not reported by hardware information, just
+    /// for fixup.
+    PlaybackSoloUnsupported,
     Reserved(usize),
 }
 
@@ -228,6 +289,34 @@ impl Default for HwCap {
     }
 }
 
+impl std::fmt::Display for HwCap {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::ChangeableRespAddr => write!(f, "ChangeableRespAddr"),
+            Self::ControlRoom => write!(f, "ControlRoom"),
+            Self::OptionalSpdifCoax => write!(f, "OptionalSpdifCoax"),
+            Self::OptionalAesebuXlr => write!(f, "OptionalAesebuXlr"),
+            Self::Dsp => write!(f, "Dsp"),
+            Self::Fpga => write!(f, "Fpga"),
+            Self::PhantomPowering => write!(f, "PhantomPowering"),
+            Self::OutputMapping => write!(f, "OutputMapping"),
+            Self::InputGain => write!(f, "InputGain"),
+            Self::OptionalSpdifOpt => write!(f, "OptionalSpdifOpt"),
+            Self::OptionalAdatOpt => write!(f, "OptionalAdatOpt"),
+            Self::NominalInput => write!(f, "NominalInput"),
+            Self::NominalOutput => write!(f, "NominalOutput"),
+            Self::SoftClip => write!(f, "SoftClip"),
+            Self::RobotGuitar => write!(f, "RobotGuitar"),
+            Self::GuitarCharging => write!(f, "GuitarCharging"),
+            Self::PlaybackSoloUnsupported => write!(f, "PlaybackSoloUnsupported"),
+            Self::Reserved(pos) => write!(f, "Reserved({})", pos),
+        }
+    }
+}
+
+// No flag in hardware information, thus out of the range of bit position.
+const PLAYBACK_SOLO_UNSUPPORTED_POS: usize = usize::MAX - 1;
+
 #[cfg(test)]
 fn serialize_hw_cap(cap: &HwCap) -> usize {
     match cap {
@@ -247,6 +336,7 @@ fn serialize_hw_cap(cap: &HwCap) -> usize {
         HwCap::SoftClip => 13,
         HwCap::RobotGuitar => 14,
         HwCap::GuitarCharging => 15,
+        HwCap::PlaybackSoloUnsupported => PLAYBACK_SOLO_UNSUPPORTED_POS,
         HwCap::Reserved(pos) => *pos,
     }
 }
@@ -269,6 +359,8 @@ fn deserialize_hw_cap(cap: &mut HwCap, pos: usize) {
         13 => HwCap::SoftClip,
         14 => HwCap::RobotGuitar,
         15 => HwCap::GuitarCharging,
+        // Never reported by hardware.
+        PLAYBACK_SOLO_UNSUPPORTED_POS => HwCap::PlaybackSoloUnsupported,
         _ => HwCap::Reserved(pos),
     };
 }
@@ -289,6 +381,34 @@ impl Default for NominalSignalLevel {
     }
 }
 
+impl std::fmt::Display for NominalSignalLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Professional => write!(f, "+4 dBu"),
+            Self::Medium => write!(f, "0 dBV"),
+            Self::Consumer => write!(f, "-10 dBV"),
+        }
+    }
+}
+
+impl std::convert::TryFrom<&str> for NominalSignalLevel {
+    type Error = ();
+
+    fn try_from(literal: &str) -> Result<Self, Self::Error> {
+        let literal: String = literal
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase();
+        match literal.as_str() {
+            "+4dbu" | "+4" | "pro" | "professional" => Ok(Self::Professional),
+            "0dbv" | "0" | "medium" => Ok(Self::Medium),
+            "-10dbv" | "-10" | "consumer" => Ok(Self::Consumer),
+            _ => Err(()),
+        }
+    }
+}
+
 fn serialize_nominal_signal_level(level: &NominalSignalLevel) -> u32 {
     match level {
         NominalSignalLevel::Consumer => 2,
@@ -360,6 +480,24 @@ impl Default for PhysGroupType {
     }
 }
 
+impl std::fmt::Display for PhysGroupType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Analog => write!(f, "Analog"),
+            Self::Spdif => write!(f, "S/PDIF"),
+            Self::Adat => write!(f, "ADAT"),
+            Self::SpdifOrAdat => write!(f, "S/PDIF or ADAT"),
+            Self::AnalogMirror => write!(f, "Analog Mirror"),
+            Self::Headphones => write!(f, "Headphones"),
+            Self::I2s => write!(f, "I2S"),
+            Self::Guitar => write!(f, "Guitar"),
+            Self::PiezoGuitar => write!(f, "Piezo Guitar"),
+            Self::GuitarString => write!(f, "Guitar String"),
+            Self::Unknown(val) => write!(f, "Unknown({})", val),
+        }
+    }
+}
+
 /// Entry of physical group.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
 pub struct PhysGroupEntry {
@@ -367,9 +505,150 @@ pub struct PhysGroupEntry {
     pub group_count: usize,
 }
 
+/// The range of channels for the group at the index, in the flat list of channels across all of
+/// the groups.
+pub fn channel_range(
+    groups: &[(PhysGroupType, usize)],
+    group_idx: usize,
+) -> Option<std::ops::Range<usize>> {
+    groups.get(group_idx).map(|&(_, count)| {
+        let start = groups[..group_idx]
+            .iter()
+            .fold(0, |start, entry| start + entry.1);
+        start..(start + count)
+    })
+}
+
 #[cfg(test)]
 mod test {
-    use super::*;
+    use {super::*, onyx_f::Onyx1200fProtocol};
+
+    #[test]
+    fn nominal_signal_level_literal() {
+        use std::convert::TryFrom;
+
+        [
+            NominalSignalLevel::Professional,
+            NominalSignalLevel::Medium,
+            NominalSignalLevel::Consumer,
+        ]
+        .iter()
+        .for_each(|&level| {
+            let literal = level.to_string();
+            assert_eq!(NominalSignalLevel::try_from(literal.as_str()), Ok(level));
+        });
+
+        assert_eq!(
+            NominalSignalLevel::try_from("+4 dBu").unwrap().to_string(),
+            "+4 dBu"
+        );
+        assert_eq!(
+            NominalSignalLevel::try_from("0 dBV").unwrap().to_string(),
+            "0 dBV"
+        );
+        assert_eq!(
+            NominalSignalLevel::try_from("-10 dBV").unwrap().to_string(),
+            "-10 dBV"
+        );
+
+        assert_eq!(
+            NominalSignalLevel::try_from("+4dBu"),
+            Ok(NominalSignalLevel::Professional)
+        );
+        assert_eq!(
+            NominalSignalLevel::try_from("PRO"),
+            Ok(NominalSignalLevel::Professional)
+        );
+        assert_eq!(
+            NominalSignalLevel::try_from("Consumer"),
+            Ok(NominalSignalLevel::Consumer)
+        );
+        assert_eq!(
+            NominalSignalLevel::try_from("-10"),
+            Ok(NominalSignalLevel::Consumer)
+        );
+
+        assert_eq!(NominalSignalLevel::try_from("+8 dBu"), Err(()));
+        assert_eq!(NominalSignalLevel::try_from(""), Err(()));
+    }
+
+    #[test]
+    fn phys_group_channel_range() {
+        let groups = Onyx1200fProtocol::PHYS_OUTPUT_GROUPS;
+        assert_eq!(channel_range(groups, 0), Some(0..groups[0].1));
+
+        let ranges: Vec<_> = (0..groups.len())
+            .map(|i| channel_range(groups, i).unwrap())
+            .collect();
+        ranges
+            .windows(2)
+            .for_each(|pair| assert_eq!(pair[0].end, pair[1].start));
+        assert_eq!(
+            ranges.last().map(|range| range.end),
+            Some(Onyx1200fProtocol::phys_output_count())
+        );
+
+        assert_eq!(channel_range(groups, groups.len()), None);
+        assert_eq!(channel_range(&[], 0), None);
+    }
+
+    #[test]
+    fn rate_mode_for_freq() {
+        assert_eq!(Onyx1200fProtocol::rate_mode_for_freq(32000), Some(0));
+        assert_eq!(Onyx1200fProtocol::rate_mode_for_freq(44100), Some(0));
+        assert_eq!(Onyx1200fProtocol::rate_mode_for_freq(48000), Some(0));
+        assert_eq!(Onyx1200fProtocol::rate_mode_for_freq(88200), Some(1));
+        assert_eq!(Onyx1200fProtocol::rate_mode_for_freq(96000), Some(1));
+        assert_eq!(Onyx1200fProtocol::rate_mode_for_freq(176400), Some(2));
+        assert_eq!(Onyx1200fProtocol::rate_mode_for_freq(192000), Some(2));
+        assert_eq!(Onyx1200fProtocol::rate_mode_for_freq(22050), None);
+
+        assert_eq!(
+            Onyx1200fProtocol::tx_channel_count_for_rate(48000),
+            Some(30)
+        );
+        assert_eq!(
+            Onyx1200fProtocol::rx_channel_count_for_rate(48000),
+            Some(34)
+        );
+        assert_eq!(
+            Onyx1200fProtocol::tx_channel_count_for_rate(96000),
+            Some(16)
+        );
+        assert_eq!(
+            Onyx1200fProtocol::tx_channel_count_for_rate(192000),
+            Some(8)
+        );
+        assert_eq!(Onyx1200fProtocol::tx_channel_count_for_rate(0), None);
+    }
+
+    #[test]
+    fn supported_rate_and_clock() {
+        assert!(Onyx1200fProtocol::supports_rate(44100));
+        assert!(Onyx1200fProtocol::supports_rate(192000));
+        assert!(!Onyx1200fProtocol::supports_rate(32000));
+        assert!(!Onyx1200fProtocol::supports_rate(0));
+
+        assert!(Onyx1200fProtocol::supports_clock(ClkSrc::Internal));
+        assert!(Onyx1200fProtocol::supports_clock(ClkSrc::Adat2));
+        assert!(!Onyx1200fProtocol::supports_clock(ClkSrc::Continuous));
+        assert!(!Onyx1200fProtocol::supports_clock(ClkSrc::Reserved(1)));
+    }
+
+    #[test]
+    fn clock_source_is_external() {
+        [
+            (ClkSrc::Internal, false),
+            (ClkSrc::WordClock, true),
+            (ClkSrc::Spdif, true),
+            (ClkSrc::Adat, true),
+            (ClkSrc::Adat2, true),
+            (ClkSrc::Continuous, false),
+            (ClkSrc::Reserved(1), true),
+        ]
+        .iter()
+        .for_each(|(src, expected)| assert_eq!(src.is_external(), *expected, "{}", src));
+    }
 
     #[test]
     fn clock_source_serdes() {
@@ -450,6 +729,7 @@ mod test {
             HwCap::SoftClip,
             HwCap::RobotGuitar,
             HwCap::GuitarCharging,
+            HwCap::PlaybackSoloUnsupported,
             HwCap::default(),
         ]
         .iter()
@@ -460,4 +740,63 @@ mod test {
             assert_eq!(*cap, c);
         });
     }
+
+    #[test]
+    fn clock_source_display() {
+        [
+            (ClkSrc::Internal, "Internal"),
+            (ClkSrc::WordClock, "Word Clock"),
+            (ClkSrc::Spdif, "S/PDIF"),
+            (ClkSrc::Adat, "ADAT"),
+            (ClkSrc::Adat2, "ADAT-2"),
+            (ClkSrc::Continuous, "Continuous"),
+            (ClkSrc::Reserved(10), "Reserved(10)"),
+        ]
+        .iter()
+        .for_each(|(src, label)| assert_eq!(&src.to_string(), label));
+    }
+
+    #[test]
+    fn hw_cap_display() {
+        [
+            (HwCap::ChangeableRespAddr, "ChangeableRespAddr"),
+            (HwCap::ControlRoom, "ControlRoom"),
+            (HwCap::OptionalSpdifCoax, "OptionalSpdifCoax"),
+            (HwCap::OptionalAesebuXlr, "OptionalAesebuXlr"),
+            (HwCap::Dsp, "Dsp"),
+            (HwCap::Fpga, "Fpga"),
+            (HwCap::PhantomPowering, "PhantomPowering"),
+            (HwCap::OutputMapping, "OutputMapping"),
+            (HwCap::InputGain, "InputGain"),
+            (HwCap::OptionalSpdifOpt, "OptionalSpdifOpt"),
+            (HwCap::OptionalAdatOpt, "OptionalAdatOpt"),
+            (HwCap::NominalInput, "NominalInput"),
+            (HwCap::NominalOutput, "NominalOutput"),
+            (HwCap::SoftClip, "SoftClip"),
+            (HwCap::RobotGuitar, "RobotGuitar"),
+            (HwCap::GuitarCharging, "GuitarCharging"),
+            (HwCap::Reserved(20), "Reserved(20)"),
+        ]
+        .iter()
+        .for_each(|(cap, label)| assert_eq!(&cap.to_string(), label));
+    }
+
+    #[test]
+    fn phys_group_type_display() {
+        [
+            (PhysGroupType::Analog, "Analog"),
+            (PhysGroupType::Spdif, "S/PDIF"),
+            (PhysGroupType::Adat, "ADAT"),
+            (PhysGroupType::SpdifOrAdat, "S/PDIF or ADAT"),
+            (PhysGroupType::AnalogMirror, "Analog Mirror"),
+            (PhysGroupType::Headphones, "Headphones"),
+            (PhysGroupType::I2s, "I2S"),
+            (PhysGroupType::Guitar, "Guitar"),
+            (PhysGroupType::PiezoGuitar, "Piezo Guitar"),
+            (PhysGroupType::GuitarString, "Guitar String"),
+            (PhysGroupType::Unknown(12), "Unknown(12)"),
+        ]
+        .iter()
+        .for_each(|(group_type, label)| assert_eq!(&group_type.to_string(), label));
+    }
 }
@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: LGPL-3.0-or-later
+// Copyright (c) 2021 Takashi Sakamoto
+
+//! Mock of Efw protocol for unit testing.
+//!
+//! The module provides the implementation of `hitaki::prelude::EfwProtocolExtManual` without
+//! actual hardware. Every transaction is recorded, then delegated to the handler given by each
+//! test to operate state in memory, if any.
+
+use {
+    glib::{translate::FromGlib, Error, SignalHandlerId},
+    hitaki::{prelude::EfwProtocolExtManual, EfwProtocolError},
+    std::cell::RefCell,
+};
+
+/// The transaction recorded by the mock, with category, command, and arguments.
+pub type MockTransaction = (u32, u32, Vec<u32>);
+
+type MockHandler<'a> = dyn Fn(u32, u32, &[u32], &mut Vec<u32>) -> Result<(), Error> + 'a;
+
+/// The mock of Efw protocol.
+#[derive(Default)]
+pub struct MockProtocol<'a> {
+    transactions: RefCell<Vec<MockTransaction>>,
+    handler: Option<Box<MockHandler<'a>>>,
+}
+
+impl<'a> MockProtocol<'a> {
+    /// Instantiate with the handler of transaction, which takes category, command, arguments,
+    /// and parameters to fill.
+    pub fn with_handler<F>(handler: F) -> Self
+    where
+        F: Fn(u32, u32, &[u32], &mut Vec<u32>) -> Result<(), Error> + 'a,
+    {
+        Self {
+            transactions: Default::default(),
+            handler: Some(Box::new(handler)),
+        }
+    }
+
+    /// Take the recorded transactions.
+    pub fn take_transactions(&self) -> Vec<MockTransaction> {
+        self.transactions.take()
+    }
+
+    /// Take the command and arguments of recorded transactions, with assertion of category.
+    pub fn take_commands(&self, category: u32) -> Vec<(u32, Vec<u32>)> {
+        self.take_transactions()
+            .into_iter()
+            .map(|(cat, command, args)| {
+                assert_eq!(cat, category);
+                (command, args)
+            })
+            .collect()
+    }
+}
+
+impl<'a> EfwProtocolExtManual for MockProtocol<'a> {
+    fn transaction(
+        &self,
+        category: u32,
+        command: u32,
+        args: &[u32],
+        params: &mut Vec<u32>,
+        _: u32,
+    ) -> Result<(), Error> {
+        self.transactions
+            .borrow_mut()
+            .push((category, command, args.to_vec()));
+        self.handler
+            .as_ref()
+            .map_or(Ok(()), |handler| handler(category, command, args, params))
+    }
+
+    fn emit_responded(&self, _: u32, _: u32, _: u32, _: u32, _: EfwProtocolError, _: &[u32]) {
+        // Omitted.
+    }
+
+    fn connect_responded<F>(&self, _f: F) -> SignalHandlerId
+    where
+        F: Fn(&Self, u32, u32, u32, u32, EfwProtocolError, &[u32]) + 'static,
+    {
+        // Dummy.
+        unsafe { SignalHandlerId::from_glib(0) }
+    }
+}
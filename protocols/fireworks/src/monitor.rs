@@ -34,9 +34,47 @@ pub struct EfwMonitorSourceParameters {
 }
 
 /// The parameters of input monitor.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct EfwMonitorParameters(pub Vec<EfwMonitorSourceParameters>);
 
+impl EfwMonitorParameters {
+    /// Check the number of destinations and sources according to the specification of hardware.
+    pub fn validate<O: EfwHardwareSpecification>(&self) -> Result<(), Error> {
+        if self.0.len() != O::MONITOR_DESTINATION_COUNT {
+            let msg = format!(
+                "Invalid number of monitor destinations: {} but {} expected",
+                self.0.len(),
+                O::MONITOR_DESTINATION_COUNT
+            );
+            Err(Error::new(FileError::Inval, &msg))?;
+        }
+
+        self.0.iter().enumerate().try_for_each(|(dst_ch, sources)| {
+            [
+                ("gains", sources.gains.len()),
+                ("mutes", sources.mutes.len()),
+                ("solos", sources.solos.len()),
+                ("pans", sources.pans.len()),
+            ]
+            .iter()
+            .try_for_each(|&(label, len)| {
+                if len != O::MONITOR_SOURCE_COUNT {
+                    let msg = format!(
+                        "Invalid number of monitor {} for destination {}: {} but {} expected",
+                        label,
+                        dst_ch,
+                        len,
+                        O::MONITOR_SOURCE_COUNT
+                    );
+                    Err(Error::new(FileError::Inval, &msg))
+                } else {
+                    Ok(())
+                }
+            })
+        })
+    }
+}
+
 impl<O, P> EfwWhollyCachableParamsOperation<P, EfwMonitorParameters> for O
 where
     O: EfwHardwareSpecification,
@@ -47,18 +85,13 @@ where
         states: &mut EfwMonitorParameters,
         timeout_ms: u32,
     ) -> Result<(), Error> {
-        assert_eq!(states.0.len(), Self::MONITOR_DESTINATION_COUNT);
+        states.validate::<Self>()?;
 
         states
             .0
             .iter_mut()
             .enumerate()
             .try_for_each(|(dst_ch, sources)| {
-                assert_eq!(sources.gains.len(), Self::MONITOR_SOURCE_COUNT);
-                assert_eq!(sources.mutes.len(), Self::MONITOR_SOURCE_COUNT);
-                assert_eq!(sources.solos.len(), Self::MONITOR_SOURCE_COUNT);
-                assert_eq!(sources.pans.len(), Self::MONITOR_SOURCE_COUNT);
-
                 sources
                     .gains
                     .iter_mut()
@@ -145,7 +178,8 @@ where
         updates: EfwMonitorParameters,
         timeout_ms: u32,
     ) -> Result<(), Error> {
-        assert_eq!(states.0.len(), Self::MONITOR_DESTINATION_COUNT);
+        states.validate::<Self>()?;
+        updates.validate::<Self>()?;
 
         states
             .0
@@ -153,15 +187,6 @@ where
             .zip(updates.0.iter())
             .enumerate()
             .try_for_each(|(dst_ch, (input, update))| {
-                assert_eq!(input.gains.len(), Self::MONITOR_SOURCE_COUNT);
-                assert_eq!(input.mutes.len(), Self::MONITOR_SOURCE_COUNT);
-                assert_eq!(input.solos.len(), Self::MONITOR_SOURCE_COUNT);
-                assert_eq!(input.pans.len(), Self::MONITOR_SOURCE_COUNT);
-                assert_eq!(update.gains.len(), Self::MONITOR_SOURCE_COUNT);
-                assert_eq!(update.mutes.len(), Self::MONITOR_SOURCE_COUNT);
-                assert_eq!(update.solos.len(), Self::MONITOR_SOURCE_COUNT);
-                assert_eq!(update.pans.len(), Self::MONITOR_SOURCE_COUNT);
-
                 input
                     .gains
                     .iter_mut()
@@ -241,3 +266,132 @@ where
             })
     }
 }
+
+/// Mute all of sources for all of destinations in input monitor.
+pub fn mute_all<O, P>(
+    proto: &mut P,
+    states: &mut EfwMonitorParameters,
+    timeout_ms: u32,
+) -> Result<(), Error>
+where
+    O: EfwHardwareSpecification,
+    P: EfwProtocolExtManual,
+{
+    let mut updates = states.clone();
+    updates
+        .0
+        .iter_mut()
+        .for_each(|sources| sources.mutes.iter_mut().for_each(|mute| *mute = true));
+    <O as EfwPartiallyUpdatableParamsOperation<P, EfwMonitorParameters>>::update_partially(
+        proto, states, updates, timeout_ms,
+    )
+}
+
+/// Enable solo of the source for the destination in input monitor, and disable solo of the other
+/// sources for the destination.
+pub fn solo_exclusive<O, P>(
+    proto: &mut P,
+    states: &mut EfwMonitorParameters,
+    dst: usize,
+    src: usize,
+    timeout_ms: u32,
+) -> Result<(), Error>
+where
+    O: EfwHardwareSpecification,
+    P: EfwProtocolExtManual,
+{
+    if dst >= O::MONITOR_DESTINATION_COUNT || src >= O::MONITOR_SOURCE_COUNT {
+        let msg = format!(
+            "Invalid pair of monitor destination and source: {} and {}",
+            dst, src
+        );
+        Err(Error::new(FileError::Inval, &msg))?;
+    }
+
+    let mut updates = states.clone();
+    if let Some(sources) = updates.0.get_mut(dst) {
+        sources
+            .solos
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, solo)| *solo = i == src);
+    }
+    <O as EfwPartiallyUpdatableParamsOperation<P, EfwMonitorParameters>>::update_partially(
+        proto, states, updates, timeout_ms,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{audiofire::Audiofire2Protocol, mock::MockProtocol},
+    };
+
+    #[test]
+    fn monitor_params_validate() {
+        let mut params = Audiofire2Protocol::create_monitor_parameters();
+        assert!(params.validate::<Audiofire2Protocol>().is_ok());
+
+        params.0[3].solos.pop();
+        let err = params.validate::<Audiofire2Protocol>().unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Inval));
+
+        params.0.pop();
+        let err = params.validate::<Audiofire2Protocol>().unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Inval));
+
+        let params = EfwMonitorParameters(Vec::new());
+        assert!(params.validate::<Audiofire2Protocol>().is_err());
+    }
+
+    #[test]
+    fn monitor_mute_all() {
+        let mut proto = MockProtocol::default();
+        let mut params = Audiofire2Protocol::create_monitor_parameters();
+        params.0[1].mutes[2] = true;
+
+        mute_all::<Audiofire2Protocol, _>(&mut proto, &mut params, 100).unwrap();
+        assert!(params
+            .0
+            .iter()
+            .all(|sources| sources.mutes.iter().all(|&mute| mute)));
+
+        let count = Audiofire2Protocol::MONITOR_DESTINATION_COUNT
+            * Audiofire2Protocol::MONITOR_SOURCE_COUNT;
+        let trxs = proto.take_commands(CATEGORY_MONITOR);
+        assert_eq!(trxs.len(), count - 1);
+        assert!(trxs
+            .iter()
+            .all(|(cmd, args)| *cmd == CMD_SET_MUTE && args[2] == 1));
+        assert!(!trxs.iter().any(|(_, args)| args[..2] == [2, 1]));
+    }
+
+    #[test]
+    fn monitor_solo_exclusive() {
+        let mut proto = MockProtocol::default();
+        let mut params = Audiofire2Protocol::create_monitor_parameters();
+        params.0[0].solos[1] = true;
+        params.0[0].solos[3] = true;
+        params.0[2].solos[3] = true;
+
+        solo_exclusive::<Audiofire2Protocol, _>(&mut proto, &mut params, 0, 2, 100).unwrap();
+        params.0[0].solos.iter().enumerate().for_each(|(i, &solo)| {
+            assert_eq!(solo, i == 2);
+        });
+        assert!(params.0[2].solos[3]);
+        assert_eq!(
+            proto.take_commands(CATEGORY_MONITOR),
+            vec![
+                (CMD_SET_SOLO, vec![1, 0, 0]),
+                (CMD_SET_SOLO, vec![2, 0, 1]),
+                (CMD_SET_SOLO, vec![3, 0, 0]),
+            ]
+        );
+
+        let dst = Audiofire2Protocol::MONITOR_DESTINATION_COUNT;
+        let err = solo_exclusive::<Audiofire2Protocol, _>(&mut proto, &mut params, dst, 0, 100)
+            .unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Inval));
+    }
+}
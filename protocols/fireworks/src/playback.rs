@@ -18,7 +18,7 @@ const CMD_SET_SOLO: u32 = 4;
 const CMD_GET_SOLO: u32 = 5;
 
 /// The parameters of playback.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct EfwPlaybackParameters {
     /// The volume of playback. The value is unsigned fixed-point number of 8.24 format; i.e. Q24.
     /// It is between 0x00000000..0x02000000 for -144.0..+6.0 dB.
@@ -201,6 +201,13 @@ where
         updates: EfwPlaybackSoloParameters,
         timeout_ms: u32,
     ) -> Result<(), Error> {
+        if O::CAPABILITIES.contains(&HwCap::PlaybackSoloUnsupported) {
+            Err(Error::new(
+                FileError::Perm,
+                "Solo of playback is not supported",
+            ))?;
+        }
+
         assert_eq!(states.solos.len(), Self::RX_CHANNEL_COUNTS[0]);
         assert_eq!(updates.solos.len(), Self::RX_CHANNEL_COUNTS[0]);
 
@@ -227,3 +234,65 @@ where
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use {super::*, crate::mock::MockProtocol};
+
+    #[derive(Default)]
+    struct TestProtocol<const UNSUPPORTED: bool>;
+
+    impl<const UNSUPPORTED: bool> EfwHardwareSpecification for TestProtocol<UNSUPPORTED> {
+        const SUPPORTED_SAMPLING_RATES: &'static [u32] = &[];
+        const SUPPORTED_SAMPLING_CLOCKS: &'static [ClkSrc] = &[];
+        const CAPABILITIES: &'static [HwCap] = if UNSUPPORTED {
+            &[HwCap::PlaybackSoloUnsupported]
+        } else {
+            &[]
+        };
+        const RX_CHANNEL_COUNTS: [usize; 3] = [4; 3];
+        const TX_CHANNEL_COUNTS: [usize; 3] = [0; 3];
+        const MONITOR_SOURCE_COUNT: usize = 0;
+        const MONITOR_DESTINATION_COUNT: usize = 0;
+        const MIDI_INPUT_COUNT: usize = 0;
+        const MIDI_OUTPUT_COUNT: usize = 0;
+        const PHYS_INPUT_GROUPS: &'static [(PhysGroupType, usize)] = &[];
+        const PHYS_OUTPUT_GROUPS: &'static [(PhysGroupType, usize)] = &[];
+    }
+
+    impl<const UNSUPPORTED: bool> EfwPlaybackSoloSpecification for TestProtocol<UNSUPPORTED> {}
+
+    #[test]
+    fn playback_solo_supported() {
+        let mut proto = MockProtocol::default();
+        let mut params = TestProtocol::<false>::create_playback_solo_parameters();
+        let mut updates = params.clone();
+        updates.solos[2] = true;
+
+        TestProtocol::<false>::update_partially(&mut proto, &mut params, updates.clone(), 100)
+            .unwrap();
+        assert_eq!(params, updates);
+        assert_eq!(
+            proto.take_commands(CATEGORY_PLAYBACK),
+            vec![(CMD_SET_SOLO, vec![2, 1])]
+        );
+    }
+
+    #[test]
+    fn playback_solo_unsupported() {
+        let mut proto = MockProtocol::default();
+        let mut params = TestProtocol::<true>::create_playback_solo_parameters();
+        let mut updates = params.clone();
+        updates.solos[2] = true;
+
+        let err =
+            TestProtocol::<true>::update_partially(&mut proto, &mut params, updates.clone(), 100)
+                .unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Perm));
+        assert_eq!(
+            params,
+            TestProtocol::<true>::create_playback_solo_parameters()
+        );
+        assert_eq!(proto.take_commands(CATEGORY_PLAYBACK), vec![]);
+    }
+}
@@ -6,7 +6,7 @@
 //! The module includes protocol about robot guitar defined by Echo Audio Digital Corporation for
 //! Fireworks board module.
 
-use super::*;
+use super::{hw_info::HwMeter, *};
 
 const CATEGORY_ROBOT_GUITAR: u32 = 10;
 
@@ -76,3 +76,147 @@ where
         )
     }
 }
+
+/// Status of charging for Robot Guitar, detected by hardware meter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EfwGuitarChargeStatus {
+    /// Neither charging nor charged yet.
+    Idle,
+    /// Under charging.
+    Charging,
+    /// Charging finished.
+    Charged,
+}
+
+impl Default for EfwGuitarChargeStatus {
+    fn default() -> Self {
+        Self::Idle
+    }
+}
+
+/// State of charging for Robot Guitar, with debounce against the flag in hardware meter.
+///
+/// The status is changed only when the flag is kept for `DEBOUNCE_COUNT` times of cache operation,
+/// thus transient change of the flag is ignored. The status becomes `Charged` when the flag goes
+/// down after charging, and stays until next charging.
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+pub struct EfwGuitarChargeState {
+    pub status: EfwGuitarChargeStatus,
+    charging: bool,
+    count: usize,
+}
+
+impl EfwGuitarChargeState {
+    /// The number of consecutive cache operations to detect the change of flag.
+    pub const DEBOUNCE_COUNT: usize = 2;
+
+    fn parse(&mut self, charging: bool) {
+        if charging != self.charging {
+            self.charging = charging;
+            self.count = 1;
+        } else if self.count < Self::DEBOUNCE_COUNT {
+            self.count += 1;
+        }
+
+        if self.count < Self::DEBOUNCE_COUNT {
+            return;
+        }
+
+        self.status = match (self.status, charging) {
+            (_, true) => EfwGuitarChargeStatus::Charging,
+            (EfwGuitarChargeStatus::Idle, false) => EfwGuitarChargeStatus::Idle,
+            (_, false) => EfwGuitarChargeStatus::Charged,
+        };
+    }
+}
+
+impl<O, P> EfwWhollyCachableParamsOperation<P, EfwGuitarChargeState> for O
+where
+    O: EfwRobotGuitarSpecification + EfwHardwareSpecification,
+    P: EfwProtocolExtManual,
+{
+    fn cache_wholly(
+        proto: &mut P,
+        states: &mut EfwGuitarChargeState,
+        timeout_ms: u32,
+    ) -> Result<(), Error> {
+        if !O::CAPABILITIES.contains(&HwCap::GuitarCharging) {
+            Err(Error::new(
+                FileError::Nxio,
+                "Charging for guitar is not supported",
+            ))?;
+        }
+
+        let mut meter = O::create_hardware_meter();
+        <O as EfwWhollyCachableParamsOperation<P, HwMeter>>::cache_wholly(
+            proto, &mut meter, timeout_ms,
+        )
+        .map(|_| states.parse(meter.guitar_charging))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{audiofire::Audiofire4Protocol, mock::MockProtocol, rip::RipProtocol},
+        std::cell::Cell,
+    };
+
+    fn charging_flags(charging: bool) -> u32 {
+        (charging as u32) << 29
+    }
+
+    fn fill_flags(flags: &Cell<u32>, params: &mut [u32]) -> Result<(), glib::Error> {
+        params.iter_mut().for_each(|quad| *quad = 0);
+        params[0] = flags.get();
+        Ok(())
+    }
+
+    impl EfwRobotGuitarSpecification for Audiofire4Protocol {}
+
+    #[test]
+    fn guitar_charge_state_transition() {
+        let flags = Cell::new(0);
+        let mut proto = MockProtocol::with_handler(|_, _, _, params| fill_flags(&flags, params));
+        let mut state = EfwGuitarChargeState::default();
+
+        [
+            (false, EfwGuitarChargeStatus::Idle),
+            (false, EfwGuitarChargeStatus::Idle),
+            // Transient flag is ignored.
+            (true, EfwGuitarChargeStatus::Idle),
+            (false, EfwGuitarChargeStatus::Idle),
+            (true, EfwGuitarChargeStatus::Idle),
+            (true, EfwGuitarChargeStatus::Charging),
+            (true, EfwGuitarChargeStatus::Charging),
+            // Transient flag is ignored.
+            (false, EfwGuitarChargeStatus::Charging),
+            (true, EfwGuitarChargeStatus::Charging),
+            (false, EfwGuitarChargeStatus::Charging),
+            (false, EfwGuitarChargeStatus::Charged),
+            (false, EfwGuitarChargeStatus::Charged),
+            (true, EfwGuitarChargeStatus::Charged),
+            (true, EfwGuitarChargeStatus::Charging),
+        ]
+        .iter()
+        .enumerate()
+        .for_each(|(i, &(charging, status))| {
+            flags.set(charging_flags(charging));
+            RipProtocol::cache_wholly(&mut proto, &mut state, 100).unwrap();
+            assert_eq!(state.status, status, "{}", i);
+        });
+    }
+
+    #[test]
+    fn guitar_charge_state_unsupported() {
+        let flags = Cell::new(0);
+        let mut proto = MockProtocol::with_handler(|_, _, _, params| fill_flags(&flags, params));
+        let mut state = EfwGuitarChargeState::default();
+
+        flags.set(charging_flags(true));
+        let err = Audiofire4Protocol::cache_wholly(&mut proto, &mut state, 100).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Nxio));
+        assert_eq!(state, EfwGuitarChargeState::default());
+    }
+}
@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: LGPL-3.0-or-later
+// Copyright (c) 2021 Takashi Sakamoto
+
+//! Snapshot of parameters.
+//!
+//! The module includes the structure to save and restore parameters at once. It is useful when
+//! changing sampling transfer frequency, since firmware resets some parameters at the change.
+
+use super::*;
+
+/// The snapshot of parameters for input monitor, physical output, and playback.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct EfwParameterSnapshot {
+    pub monitor: EfwMonitorParameters,
+    pub output: EfwOutputParameters,
+    pub playback: EfwPlaybackParameters,
+}
+
+/// Save current parameters into the snapshot.
+pub fn snapshot<O, P>(proto: &mut P, timeout_ms: u32) -> Result<EfwParameterSnapshot, Error>
+where
+    O: EfwHardwareSpecification,
+    P: EfwProtocolExtManual,
+{
+    let mut snapshot = EfwParameterSnapshot {
+        monitor: O::create_monitor_parameters(),
+        output: O::create_output_parameters(),
+        playback: O::create_playback_parameters(),
+    };
+    O::cache_wholly(proto, &mut snapshot.monitor, timeout_ms)?;
+    O::cache_wholly(proto, &mut snapshot.output, timeout_ms)?;
+    O::cache_wholly(proto, &mut snapshot.playback, timeout_ms)?;
+    Ok(snapshot)
+}
+
+/// Restore parameters from the snapshot. Current parameters are cached at first, then the
+/// parameters different from the snapshot are updated.
+pub fn restore<O, P>(
+    proto: &mut P,
+    snapshot: &EfwParameterSnapshot,
+    timeout_ms: u32,
+) -> Result<(), Error>
+where
+    O: EfwHardwareSpecification,
+    P: EfwProtocolExtManual,
+{
+    let mut curr = self::snapshot::<O, P>(proto, timeout_ms)?;
+    O::update_partially(
+        proto,
+        &mut curr.monitor,
+        snapshot.monitor.clone(),
+        timeout_ms,
+    )?;
+    O::update_partially(proto, &mut curr.output, snapshot.output.clone(), timeout_ms)?;
+    O::update_partially(
+        proto,
+        &mut curr.playback,
+        snapshot.playback.clone(),
+        timeout_ms,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use {
+        super::*,
+        crate::{audiofire::Audiofire4Protocol, mock::MockProtocol},
+        std::{cell::RefCell, collections::HashMap},
+    };
+
+    // The registers are addressed by category, command to get, and arguments except for the last.
+    // The command to set is even number, and the command to get is the next odd number.
+    type RegisterKey = (u32, u32, Vec<u32>);
+
+    fn operate_registers(
+        registers: &RefCell<HashMap<RegisterKey, u32>>,
+        category: u32,
+        command: u32,
+        args: &[u32],
+        params: &mut [u32],
+    ) -> Result<(), glib::Error> {
+        let (&val, keys) = args.split_last().unwrap();
+        if command & 0x01 > 0 {
+            let key = (category, command, keys.to_vec());
+            let val = registers.borrow().get(&key).copied().unwrap_or_default();
+            params[..keys.len()].copy_from_slice(keys);
+            params[keys.len()] = val;
+        } else {
+            let key = (category, command + 1, keys.to_vec());
+            registers.borrow_mut().insert(key, val);
+        }
+        Ok(())
+    }
+
+    // The number of transactions to set.
+    fn set_count(proto: &MockProtocol) -> usize {
+        proto
+            .take_transactions()
+            .iter()
+            .filter(|(_, command, _)| command & 0x01 == 0)
+            .count()
+    }
+
+    #[test]
+    fn snapshot_round_trip() {
+        let registers = RefCell::new(HashMap::new());
+        let mut proto = MockProtocol::with_handler(|category, command, args, params| {
+            operate_registers(&registers, category, command, args, params)
+        });
+
+        let initial = snapshot::<Audiofire4Protocol, _>(&mut proto, 100).unwrap();
+        assert_eq!(
+            initial.monitor,
+            Audiofire4Protocol::create_monitor_parameters()
+        );
+        assert_eq!(
+            initial.output,
+            Audiofire4Protocol::create_output_parameters()
+        );
+        assert_eq!(
+            initial.playback,
+            Audiofire4Protocol::create_playback_parameters()
+        );
+
+        let mut expected = initial.clone();
+        expected
+            .monitor
+            .0
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, srcs)| {
+                let pos = i % srcs.gains.len();
+                srcs.gains[pos] = 0x01000000;
+                srcs.mutes[0] = true;
+                srcs.solos[1] = true;
+                srcs.pans[2] = 0x80;
+            });
+        expected.output.volumes[1] = 0x00800000;
+        expected.output.mutes[2] = true;
+        expected.playback.volumes[3] = 0x02000000;
+        expected.playback.mutes[0] = true;
+
+        restore::<Audiofire4Protocol, _>(&mut proto, &expected, 100).unwrap();
+        let count = set_count(&proto);
+        assert!(count > 0);
+        let cached = snapshot::<Audiofire4Protocol, _>(&mut proto, 100).unwrap();
+        assert_eq!(cached, expected);
+
+        // No update is required.
+        restore::<Audiofire4Protocol, _>(&mut proto, &expected, 100).unwrap();
+        assert_eq!(set_count(&proto), 0);
+
+        // Firmware resets parameters.
+        registers.borrow_mut().clear();
+        let cached = snapshot::<Audiofire4Protocol, _>(&mut proto, 100).unwrap();
+        assert_eq!(cached, initial);
+
+        restore::<Audiofire4Protocol, _>(&mut proto, &expected, 100).unwrap();
+        assert_eq!(set_count(&proto), count);
+        let cached = snapshot::<Audiofire4Protocol, _>(&mut proto, 100).unwrap();
+        assert_eq!(cached, expected);
+    }
+}
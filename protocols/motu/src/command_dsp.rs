@@ -38,6 +38,7 @@ const MSG_DST_OFFSET_END: u64 = MSG_DST_OFFSET_BEGIN + 0x10000000;
 
 /// The mode of stereo-paired channels.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InputStereoPairMode {
     /// Adjustable left/right balance.
     LeftRight,
@@ -74,6 +75,7 @@ impl From<InputStereoPairMode> for u8 {
 
 /// The level to decline audio signal.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RollOffLevel {
     /// 6 dB per octave.
     L6,
@@ -126,6 +128,7 @@ impl From<RollOffLevel> for u8 {
 
 /// The type of filter for equalizer (5 options).
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FilterType5 {
     T1,
     T2,
@@ -169,6 +172,7 @@ impl From<FilterType5> for u8 {
 
 /// The type of filter for equalizer (5 options).
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FilterType4 {
     T1,
     T2,
@@ -209,6 +213,7 @@ impl From<FilterType4> for u8 {
 
 /// The way to decide loudness level of input signal.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LevelDetectMode {
     /// According to the peak of signal.
     Peak,
@@ -245,6 +250,7 @@ impl From<LevelDetectMode> for u8 {
 
 /// The mode of leveler.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LevelerMode {
     Compress,
     Limit,
@@ -279,6 +285,7 @@ impl From<LevelerMode> for u8 {
 
 /// The DSP command specific to equalizer effects.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EqualizerParameter {
     Enable(bool),
     HpfEnable(bool),
@@ -316,6 +323,7 @@ pub enum EqualizerParameter {
 
 /// The DSP command specific to dynamics effects.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DynamicsParameter {
     Enable(bool),
     CompEnable(bool),
@@ -374,6 +382,7 @@ fn append_data(raw: &mut Vec<u8>, identifier: &[u8], vals: &[u8]) {
 
 /// Target of focus.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FocusTarget {
     Output(usize),
     Input(usize),
@@ -826,6 +835,7 @@ impl InputCmd {
 
 /// The mode of stereo pair for source of mixer.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SourceStereoPairMode {
     Width,
     LrBalance,
@@ -1284,6 +1294,7 @@ impl OutputCmd {
 
 /// The mode of early reflection.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RoomShape {
     A,
     B,
@@ -1327,6 +1338,7 @@ impl From<RoomShape> for u8 {
 
 /// The point of split.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SplitPoint {
     Output,
     Mixer,
@@ -1739,7 +1751,8 @@ fn send_message(
             frame.push(0x00);
         }
 
-        req.transaction(
+        transaction(
+            req,
             node,
             FwTcode::WriteBlockRequest,
             DSP_CMD_OFFSET,
@@ -1976,6 +1989,10 @@ pub trait MotuCommandDspParametersOperation<T> {
     fn build_commands(params: &T) -> Vec<DspCmd>;
     /// Parse DSP command for parameters.
     fn parse_command(params: &mut T, command: &DspCmd) -> bool;
+    /// Check whether the parameters are available before building DSP commands.
+    fn validate_params(_: &T) -> Result<(), Error> {
+        Ok(())
+    }
 }
 
 /// The trait for DSP image operations.
@@ -2009,6 +2026,7 @@ where
         updates: T,
         timeout_ms: u32,
     ) -> Result<(), Error> {
+        O::validate_params(&updates)?;
         let mut new_cmds = O::build_commands(&updates);
         let old_cmds = O::build_commands(params);
         new_cmds.retain(|cmd| old_cmds.iter().find(|c| c.eq(&cmd)).is_none());
@@ -2018,7 +2036,8 @@ where
 }
 
 /// State of reverb function.
-#[derive(Default, Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommandDspReverbState {
     /// Whether to enable reverb effect.
     pub enable: bool,
@@ -2046,6 +2065,26 @@ pub struct CommandDspReverbState {
     pub reflection_level: f32,
 }
 
+impl Default for CommandDspReverbState {
+    // The values should be within the ranges in the specification of reverb.
+    fn default() -> Self {
+        Self {
+            enable: Default::default(),
+            split_point: Default::default(),
+            pre_delay: 0,
+            shelf_filter_freq: 20000,
+            shelf_filter_attenuation: 0,
+            decay_time: 100,
+            freq_time: [100; 3],
+            freq_crossover: [100, 20000],
+            width: 0.0,
+            reflection_mode: Default::default(),
+            reflection_size: 50,
+            reflection_level: 0.0,
+        }
+    }
+}
+
 /// The specification of reverb.
 pub trait MotuCommandDspReverbSpecification {
     /// The minimum value of decay time.
@@ -2110,6 +2149,80 @@ pub trait MotuCommandDspReverbSpecification {
     const REFLECTION_LEVEL_MIN: f32 = 0.0;
     /// The maximum value of reflection level.
     const REFLECTION_LEVEL_MAX: f32 = 1.0;
+
+    /// Check whether the fields of parameters are within the available ranges.
+    fn validate_reverb_state(params: &CommandDspReverbState) -> Result<(), Error> {
+        fn check<T: PartialOrd + std::fmt::Display>(
+            label: &str,
+            val: T,
+            min: T,
+            max: T,
+        ) -> Result<(), Error> {
+            if val >= min && val <= max {
+                Ok(())
+            } else {
+                let msg = format!(
+                    "Invalid value for {}: {}, should be between {} and {}",
+                    label, val, min, max
+                );
+                Err(Error::new(FileError::Inval, &msg))
+            }
+        }
+
+        check(
+            "pre delay",
+            params.pre_delay,
+            Self::PRE_DELAY_MIN,
+            Self::PRE_DELAY_MAX,
+        )?;
+        check(
+            "shelf filter frequency",
+            params.shelf_filter_freq,
+            Self::SHELF_FILTER_FREQ_MIN,
+            Self::SHELF_FILTER_FREQ_MAX,
+        )?;
+        check(
+            "shelf filter attenuation",
+            params.shelf_filter_attenuation,
+            Self::SHELF_FILTER_ATTR_MIN,
+            Self::SHELF_FILTER_ATTR_MAX,
+        )?;
+        check(
+            "decay time",
+            params.decay_time,
+            Self::DECAY_TIME_MIN,
+            Self::DECAY_TIME_MAX,
+        )?;
+        params.freq_time.iter().try_for_each(|&time| {
+            check(
+                "frequency time",
+                time,
+                Self::FREQ_TIME_MIN,
+                Self::FREQ_TIME_MAX,
+            )
+        })?;
+        params.freq_crossover.iter().try_for_each(|&freq| {
+            check(
+                "frequency crossover",
+                freq,
+                Self::FREQ_CROSSOVER_MIN,
+                Self::FREQ_CROSSOVER_MAX,
+            )
+        })?;
+        check("width", params.width, Self::WIDTH_MIN, Self::WIDTH_MAX)?;
+        check(
+            "reflection size",
+            params.reflection_size,
+            Self::REFLECTION_SIZE_MIN,
+            Self::REFLECTION_SIZE_MAX,
+        )?;
+        check(
+            "reflection level",
+            params.reflection_level,
+            Self::REFLECTION_LEVEL_MIN,
+            Self::REFLECTION_LEVEL_MAX,
+        )
+    }
 }
 
 impl<O> MotuCommandDspParametersOperation<CommandDspReverbState> for O
@@ -2163,10 +2276,15 @@ where
             false
         }
     }
+
+    fn validate_params(params: &CommandDspReverbState) -> Result<(), Error> {
+        Self::validate_reverb_state(params)
+    }
 }
 
 /// State of monitor function.
 #[derive(Default, Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommandDspMonitorState {
     /// The volume adjusted by main (master) knob. -inf (mute), -80.0 dB to 0.0 dB.
     pub main_volume: f32,
@@ -2243,6 +2361,7 @@ where
 
 /// State of entry of mixer function.
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommandDspMixerSourceState {
     /// Whether to mute the source of mixer.
     pub mute: Vec<bool>,
@@ -2264,6 +2383,7 @@ const MIXER_COUNT: usize = 8;
 
 /// State of mixer function.
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommandDspMixerState {
     /// The destination of mixer outputs.
     pub output_assign: [TargetPort; MIXER_COUNT],
@@ -2422,6 +2542,7 @@ where
 
 /// State of equalizer.
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommandDspEqualizerState {
     /// Whether to enable whole equalizer.
     pub enable: Vec<bool>,
@@ -2505,6 +2626,17 @@ pub trait MotuCommandDspEqualizerSpecification {
     /// The step value of frequency.
     const EQUALIZER_FREQ_STEP: u32 = 1;
 
+    /// The initial value of frequency at low frequency.
+    const EQUALIZER_LF_FREQ: u32 = 100;
+    /// The initial value of frequency at low-middle frequency.
+    const EQUALIZER_LMF_FREQ: u32 = 300;
+    /// The initial value of frequency at middle frequency.
+    const EQUALIZER_MF_FREQ: u32 = 1000;
+    /// The initial value of frequency at high-middle frequency.
+    const EQUALIZER_HMF_FREQ: u32 = 3000;
+    /// The initial value of frequency at high frequency.
+    const EQUALIZER_HF_FREQ: u32 = 10000;
+
     /// The minimum value of gain.
     const EQUALIZER_GAIN_MIN: f32 = -20.0;
     /// The maximum value of gain.
@@ -2515,6 +2647,79 @@ pub trait MotuCommandDspEqualizerSpecification {
     /// The maximum value of width.
     const EQUALIZER_WIDTH_MAX: f32 = 3.0;
 
+    /// Instantiate equalizer parameters for the number of channels. The pass filters are at the
+    /// edges of available range of frequency.
+    fn create_equalizer_state(count: usize) -> CommandDspEqualizerState {
+        CommandDspEqualizerState {
+            enable: vec![Default::default(); count],
+
+            hpf_enable: vec![Default::default(); count],
+            hpf_slope: vec![Default::default(); count],
+            hpf_freq: vec![Self::EQUALIZER_FREQ_MIN; count],
+
+            lpf_enable: vec![Default::default(); count],
+            lpf_slope: vec![Default::default(); count],
+            lpf_freq: vec![Self::EQUALIZER_FREQ_MAX; count],
+
+            lf_enable: vec![Default::default(); count],
+            lf_type: vec![Default::default(); count],
+            lf_freq: vec![Self::EQUALIZER_LF_FREQ; count],
+            lf_gain: vec![Default::default(); count],
+            lf_width: vec![Default::default(); count],
+
+            lmf_enable: vec![Default::default(); count],
+            lmf_type: vec![Default::default(); count],
+            lmf_freq: vec![Self::EQUALIZER_LMF_FREQ; count],
+            lmf_gain: vec![Default::default(); count],
+            lmf_width: vec![Default::default(); count],
+
+            mf_enable: vec![Default::default(); count],
+            mf_type: vec![Default::default(); count],
+            mf_freq: vec![Self::EQUALIZER_MF_FREQ; count],
+            mf_gain: vec![Default::default(); count],
+            mf_width: vec![Default::default(); count],
+
+            hmf_enable: vec![Default::default(); count],
+            hmf_type: vec![Default::default(); count],
+            hmf_freq: vec![Self::EQUALIZER_HMF_FREQ; count],
+            hmf_gain: vec![Default::default(); count],
+            hmf_width: vec![Default::default(); count],
+
+            hf_enable: vec![Default::default(); count],
+            hf_type: vec![Default::default(); count],
+            hf_freq: vec![Self::EQUALIZER_HF_FREQ; count],
+            hf_gain: vec![Default::default(); count],
+            hf_width: vec![Default::default(); count],
+        }
+    }
+
+    /// Check whether the frequencies in the state are within the available range.
+    fn validate_equalizer_state(state: &CommandDspEqualizerState) -> Result<(), Error> {
+        [
+            ("high pass filter", &state.hpf_freq),
+            ("low pass filter", &state.lpf_freq),
+            ("low frequency", &state.lf_freq),
+            ("low-middle frequency", &state.lmf_freq),
+            ("middle frequency", &state.mf_freq),
+            ("high-middle frequency", &state.hmf_freq),
+            ("high frequency", &state.hf_freq),
+        ]
+        .iter()
+        .try_for_each(|(label, freqs)| {
+            freqs.iter().enumerate().try_for_each(|(ch, &freq)| {
+                if freq >= Self::EQUALIZER_FREQ_MIN && freq <= Self::EQUALIZER_FREQ_MAX {
+                    Ok(())
+                } else {
+                    let msg = format!(
+                        "Invalid frequency of equalizer at {} for channel {}: {}",
+                        label, ch, freq
+                    );
+                    Err(Error::new(FileError::Inval, &msg))
+                }
+            })
+        })
+    }
+
     fn create_equalizer_parameters(
         state: &CommandDspEqualizerState,
         ch: usize,
@@ -2615,6 +2820,7 @@ pub trait MotuCommandDspEqualizerSpecification {
 
 /// State of dynamics.
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommandDspDynamicsState {
     /// whether to enable dynamics effects.
     pub enable: Vec<bool>,
@@ -2736,6 +2942,7 @@ pub trait MotuCommandDspDynamicsSpecification {
 
 /// State of input function.
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommandDspInputState {
     /// Whether to invert phase of input signals.
     pub phase: Vec<bool>,
@@ -2820,49 +3027,12 @@ pub trait MotuCommandDspInputSpecification {
         }
     }
 
-    /// Instantiate input equalizer parameters.
-    fn create_input_equalizer_state() -> CommandDspInputEqualizerState {
-        CommandDspInputEqualizerState(CommandDspEqualizerState {
-            enable: vec![Default::default(); Self::INPUT_PORTS.len()],
-
-            hpf_enable: vec![Default::default(); Self::INPUT_PORTS.len()],
-            hpf_slope: vec![Default::default(); Self::INPUT_PORTS.len()],
-            hpf_freq: vec![Default::default(); Self::INPUT_PORTS.len()],
-
-            lpf_enable: vec![Default::default(); Self::INPUT_PORTS.len()],
-            lpf_slope: vec![Default::default(); Self::INPUT_PORTS.len()],
-            lpf_freq: vec![Default::default(); Self::INPUT_PORTS.len()],
-
-            lf_enable: vec![Default::default(); Self::INPUT_PORTS.len()],
-            lf_type: vec![Default::default(); Self::INPUT_PORTS.len()],
-            lf_freq: vec![Default::default(); Self::INPUT_PORTS.len()],
-            lf_gain: vec![Default::default(); Self::INPUT_PORTS.len()],
-            lf_width: vec![Default::default(); Self::INPUT_PORTS.len()],
-
-            lmf_enable: vec![Default::default(); Self::INPUT_PORTS.len()],
-            lmf_type: vec![Default::default(); Self::INPUT_PORTS.len()],
-            lmf_freq: vec![Default::default(); Self::INPUT_PORTS.len()],
-            lmf_gain: vec![Default::default(); Self::INPUT_PORTS.len()],
-            lmf_width: vec![Default::default(); Self::INPUT_PORTS.len()],
-
-            mf_enable: vec![Default::default(); Self::INPUT_PORTS.len()],
-            mf_type: vec![Default::default(); Self::INPUT_PORTS.len()],
-            mf_freq: vec![Default::default(); Self::INPUT_PORTS.len()],
-            mf_gain: vec![Default::default(); Self::INPUT_PORTS.len()],
-            mf_width: vec![Default::default(); Self::INPUT_PORTS.len()],
-
-            hmf_enable: vec![Default::default(); Self::INPUT_PORTS.len()],
-            hmf_type: vec![Default::default(); Self::INPUT_PORTS.len()],
-            hmf_freq: vec![Default::default(); Self::INPUT_PORTS.len()],
-            hmf_gain: vec![Default::default(); Self::INPUT_PORTS.len()],
-            hmf_width: vec![Default::default(); Self::INPUT_PORTS.len()],
-
-            hf_enable: vec![Default::default(); Self::INPUT_PORTS.len()],
-            hf_type: vec![Default::default(); Self::INPUT_PORTS.len()],
-            hf_freq: vec![Default::default(); Self::INPUT_PORTS.len()],
-            hf_gain: vec![Default::default(); Self::INPUT_PORTS.len()],
-            hf_width: vec![Default::default(); Self::INPUT_PORTS.len()],
-        })
+    /// Instantiate input equalizer parameters. The frequencies are within the available range.
+    fn create_input_equalizer_state() -> CommandDspInputEqualizerState
+    where
+        Self: MotuCommandDspEqualizerSpecification,
+    {
+        CommandDspInputEqualizerState(Self::create_equalizer_state(Self::INPUT_PORTS.len()))
     }
 
     /// Instantiate input dynamics parameters.
@@ -2987,6 +3157,7 @@ where
 
 /// State of input equalizers.
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommandDspInputEqualizerState(pub CommandDspEqualizerState);
 
 impl AsRef<CommandDspEqualizerState> for CommandDspInputEqualizerState {
@@ -3028,10 +3199,15 @@ where
             false
         }
     }
+
+    fn validate_params(params: &CommandDspInputEqualizerState) -> Result<(), Error> {
+        Self::validate_equalizer_state(&params.0)
+    }
 }
 
 /// State of input dynamics.
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommandDspInputDynamicsState(pub CommandDspDynamicsState);
 
 impl AsRef<CommandDspDynamicsState> for CommandDspInputDynamicsState {
@@ -3077,6 +3253,7 @@ where
 
 /// State of input function.
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommandDspOutputState {
     /// The gain to send to reverb effect.
     pub reverb_send: Vec<f32>,
@@ -3116,48 +3293,12 @@ pub trait MotuCommandDspOutputSpecification {
         }
     }
 
-    fn create_output_equalizer_state() -> CommandDspOutputEqualizerState {
-        CommandDspOutputEqualizerState(CommandDspEqualizerState {
-            enable: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-
-            hpf_enable: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            hpf_slope: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            hpf_freq: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-
-            lpf_enable: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            lpf_slope: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            lpf_freq: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-
-            lf_enable: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            lf_type: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            lf_freq: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            lf_gain: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            lf_width: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-
-            lmf_enable: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            lmf_type: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            lmf_freq: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            lmf_gain: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            lmf_width: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-
-            mf_enable: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            mf_type: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            mf_freq: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            mf_gain: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            mf_width: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-
-            hmf_enable: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            hmf_type: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            hmf_freq: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            hmf_gain: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            hmf_width: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-
-            hf_enable: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            hf_type: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            hf_freq: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            hf_gain: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-            hf_width: vec![Default::default(); Self::OUTPUT_PORTS.len()],
-        })
+    /// Instantiate output equalizer parameters. The frequencies are within the available range.
+    fn create_output_equalizer_state() -> CommandDspOutputEqualizerState
+    where
+        Self: MotuCommandDspEqualizerSpecification,
+    {
+        CommandDspOutputEqualizerState(Self::create_equalizer_state(Self::OUTPUT_PORTS.len()))
     }
 
     fn create_output_dynamics_state() -> CommandDspOutputDynamicsState {
@@ -3247,6 +3388,7 @@ where
 
 /// State of output equalizers.
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommandDspOutputEqualizerState(pub CommandDspEqualizerState);
 
 impl AsRef<CommandDspEqualizerState> for CommandDspOutputEqualizerState {
@@ -3288,10 +3430,15 @@ where
             false
         }
     }
+
+    fn validate_params(params: &CommandDspOutputEqualizerState) -> Result<(), Error> {
+        Self::validate_equalizer_state(&params.0)
+    }
 }
 
 /// State of output dynamics.
 #[derive(Default, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CommandDspOutputDynamicsState(pub CommandDspDynamicsState);
 
 impl AsRef<CommandDspDynamicsState> for CommandDspOutputDynamicsState {
@@ -3383,7 +3530,10 @@ where
 
 #[cfg(test)]
 mod test {
-    use super::*;
+    use {
+        super::*,
+        crate::{mock::*, version_3::F828mk3HybridProtocol},
+    };
 
     #[test]
     fn test_u8_cmds() {
@@ -3879,4 +4029,224 @@ mod test {
         assert_eq!(cmds[13], DspCmd::Input(InputCmd::Width(1, 0.0)));
         assert_eq!(cmds.len(), 14);
     }
+
+    #[test]
+    fn equalizer_state_validation() {
+        let state = F828mk3HybridProtocol::create_input_equalizer_state();
+        assert!(F828mk3HybridProtocol::validate_equalizer_state(&state.0).is_ok());
+
+        let state = F828mk3HybridProtocol::create_output_equalizer_state();
+        assert!(F828mk3HybridProtocol::validate_equalizer_state(&state.0).is_ok());
+
+        let mut state = F828mk3HybridProtocol::create_input_equalizer_state();
+        state.0.mf_freq[3] = F828mk3HybridProtocol::EQUALIZER_FREQ_MAX + 1;
+        let err = F828mk3HybridProtocol::validate_equalizer_state(&state.0).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Inval));
+
+        state.0.mf_freq[3] = F828mk3HybridProtocol::EQUALIZER_FREQ_MIN - 1;
+        let err = F828mk3HybridProtocol::validate_equalizer_state(&state.0).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Inval));
+    }
+
+    #[test]
+    fn equalizer_update_single_band() {
+        let mut req = FwReq::new();
+        let mut node = FwNode::new();
+        let mut sequence_number = 0;
+
+        let mut params = F828mk3HybridProtocol::create_input_equalizer_state();
+        let mut updates = params.clone();
+        updates.0.mf_freq[2] = 2000;
+        updates.0.mf_gain[2] = 6.0;
+
+        F828mk3HybridProtocol::update_partially(
+            &mut req,
+            &mut node,
+            &mut sequence_number,
+            &mut params,
+            updates.clone(),
+            100,
+        )
+        .unwrap();
+        assert_eq!(params, updates);
+        assert_eq!(sequence_number, 1);
+
+        // The commands just for the modified band are transmitted.
+        let mut expected = vec![0x02, 0x00];
+        DspCmd::Input(InputCmd::Equalizer(2, EqualizerParameter::MfFreq(2000)))
+            .build(&mut expected);
+        DspCmd::Input(InputCmd::Equalizer(2, EqualizerParameter::MfGain(6.0))).build(&mut expected);
+        while expected.len() % 4 > 0 {
+            expected.push(0x00);
+        }
+        let quads = expected
+            .chunks_exact(4)
+            .map(|raw| u32::from_be_bytes([raw[0], raw[1], raw[2], raw[3]]))
+            .collect();
+        assert_eq!(
+            take_transactions(),
+            vec![MockTransaction::Write(DSP_CMD_OFFSET, quads)]
+        );
+
+        // No transaction for no change.
+        F828mk3HybridProtocol::update_partially(
+            &mut req,
+            &mut node,
+            &mut sequence_number,
+            &mut params,
+            updates.clone(),
+            100,
+        )
+        .unwrap();
+        assert_eq!(take_transactions(), vec![]);
+
+        // No transaction for invalid frequency.
+        updates.0.mf_freq[2] = 0;
+        let err = F828mk3HybridProtocol::update_partially(
+            &mut req,
+            &mut node,
+            &mut sequence_number,
+            &mut params,
+            updates,
+            100,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Inval));
+        assert_eq!(params.0.mf_freq[2], 2000);
+        assert_eq!(take_transactions(), vec![]);
+    }
+
+    struct TestReverbProtocol;
+
+    impl MotuCommandDspReverbSpecification for TestReverbProtocol {}
+
+    #[test]
+    fn reverb_state_validation() {
+        let params = CommandDspReverbState {
+            pre_delay: 50,
+            shelf_filter_freq: 8000,
+            shelf_filter_attenuation: -10,
+            decay_time: 1500,
+            freq_time: [100, 80, 60],
+            freq_crossover: [500, 5000],
+            width: 0.5,
+            reflection_size: 200,
+            reflection_level: 0.25,
+            ..Default::default()
+        };
+        assert!(TestReverbProtocol::validate_reverb_state(&params).is_ok());
+
+        [
+            CommandDspReverbState {
+                pre_delay: 101,
+                ..params
+            },
+            CommandDspReverbState {
+                decay_time: 99,
+                ..params
+            },
+            CommandDspReverbState {
+                decay_time: 60001,
+                ..params
+            },
+            CommandDspReverbState {
+                freq_time: [100, 101, 60],
+                ..params
+            },
+            CommandDspReverbState {
+                width: 1.5,
+                ..params
+            },
+            CommandDspReverbState {
+                reflection_size: 49,
+                ..params
+            },
+            CommandDspReverbState {
+                reflection_size: 401,
+                ..params
+            },
+        ]
+        .iter()
+        .for_each(|p| {
+            let err = TestReverbProtocol::validate_reverb_state(p).unwrap_err();
+            assert_eq!(err.kind::<FileError>(), Some(FileError::Inval), "{:?}", p);
+        });
+    }
+
+    #[test]
+    fn reverb_update_validation() {
+        let mut req = FwReq::new();
+        let mut node = FwNode::new();
+        let mut sequence_number = 0;
+
+        let mut params = CommandDspReverbState::default();
+        assert!(F828mk3HybridProtocol::validate_reverb_state(&params).is_ok());
+
+        // No transaction for invalid parameters.
+        let updates = CommandDspReverbState {
+            decay_time: 60001,
+            ..params
+        };
+        let err = F828mk3HybridProtocol::update_partially(
+            &mut req,
+            &mut node,
+            &mut sequence_number,
+            &mut params,
+            updates,
+            100,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Inval));
+        assert_eq!(params, CommandDspReverbState::default());
+        assert_eq!(take_transactions(), vec![]);
+
+        let updates = CommandDspReverbState {
+            decay_time: 1500,
+            ..params
+        };
+        F828mk3HybridProtocol::update_partially(
+            &mut req,
+            &mut node,
+            &mut sequence_number,
+            &mut params,
+            updates,
+            100,
+        )
+        .unwrap();
+        assert_eq!(params, updates);
+        assert_eq!(take_transactions().len(), 1);
+    }
+
+    #[test]
+    fn reverb_state_payload() {
+        let params = CommandDspReverbState {
+            pre_delay: 50,
+            decay_time: 1500,
+            reflection_size: 200,
+            ..Default::default()
+        };
+        let mut raw = Vec::new();
+        TestReverbProtocol::build_commands(&params)
+            .iter()
+            .filter(|cmd| {
+                matches!(
+                    cmd,
+                    DspCmd::Reverb(ReverbCmd::PreDelay(_))
+                        | DspCmd::Reverb(ReverbCmd::DecayTime(_))
+                        | DspCmd::Reverb(ReverbCmd::ReflectionSize(_))
+                )
+            })
+            .for_each(|cmd| cmd.build(&mut raw));
+        assert_eq!(
+            &raw,
+            &[
+                // Pre delay: 50.0.
+                0x66, 0x00, 0x02, 0x00, 0x04, 0x00, 0x00, 0x48, 0x42,
+                // Decay time: 1500.0.
+                0x66, 0x00, 0x05, 0x00, 0x04, 0x00, 0x80, 0xbb, 0x44,
+                // Reflection size: 200.0.
+                0x66, 0x00, 0x0d, 0x00, 0x04, 0x00, 0x00, 0x48, 0x43,
+            ]
+        );
+    }
 }
@@ -10,15 +10,20 @@ pub mod version_1;
 pub mod version_2;
 pub mod version_3;
 
+#[cfg(test)]
+mod mock;
+
 use {
     glib::{Error, FileError},
-    hinawa::{
-        prelude::{FwNodeExt, FwReqExtManual},
-        FwNode, FwReq, FwTcode,
-    },
+    hinawa::{FwNode, FwNodeError, FwReq, FwReqError, FwTcode},
     std::{thread, time},
 };
 
+#[cfg(not(test))]
+use hinawa::prelude::{FwNodeExt, FwReqExtManual};
+#[cfg(test)]
+use mock::{FwNodeExt, FwReqExtManual};
+
 /// The trait to operate cacheable parameters at once.
 pub trait MotuWhollyCacheableParamsOperation<T> {
     /// Cache whole parameters.
@@ -58,9 +63,44 @@ const OFFSET_CLK: u32 = 0x0b14;
 const OFFSET_PORT: u32 = 0x0c04;
 const OFFSET_CLK_DISPLAY: u32 = 0x0c60;
 
+// When the node disappears from the bus during transaction, the transaction fails with the
+// change of generation. It is reported as FileError::Noent so that runtime can distinguish it
+// from the failure due to slow response of the node. The generation changes by bus reset as well,
+// while the transaction is not cancelled nor left without acknowledge in the case.
+fn transaction(
+    req: &FwReq,
+    node: &mut FwNode,
+    tcode: FwTcode,
+    addr: u64,
+    length: usize,
+    frame: &mut [u8],
+    timeout_ms: u32,
+) -> Result<(), Error> {
+    let generation = node.generation();
+    req.transaction(node, tcode, addr, length, frame, timeout_ms)
+        .map_err(|err| {
+            let disconnected = err.kind::<FwNodeError>() == Some(FwNodeError::Disconnected);
+            let lost = node.generation() != generation
+                && matches!(
+                    err.kind::<FwReqError>(),
+                    Some(FwReqError::Cancelled) | Some(FwReqError::NoAck)
+                );
+            if disconnected || lost {
+                Error::new(FileError::Noent, "The node disappeared from the bus")
+            } else {
+                err
+            }
+        })
+}
+
+fn is_node_disappeared(err: &Error) -> bool {
+    err.kind::<FileError>() == Some(FileError::Noent)
+}
+
 fn read_quad(req: &FwReq, node: &mut FwNode, offset: u32, timeout_ms: u32) -> Result<u32, Error> {
     let mut frame = [0; 4];
-    req.transaction(
+    transaction(
+        req,
         node,
         FwTcode::ReadQuadletRequest,
         BASE_OFFSET + offset as u64,
@@ -73,8 +113,10 @@ fn read_quad(req: &FwReq, node: &mut FwNode, offset: u32, timeout_ms: u32) -> Re
 
 // AudioExpress sometimes transfers response subaction with non-standard rcode. This causes
 // Linux firewire subsystem to report 'unsolicited response' error. In the case, send error
-// is reported to userspace applications. As a workaround, the change of register is ensured
-// by following read transaction in failure of write transaction.
+// is reported to userspace applications. As a workaround, the write transaction is retried with
+// exponential backoff, and the content of register is ensured by read transaction after the
+// successful retry. When all of retries fail, the error of the first transaction is reported.
+// No retry is done when the node disappears.
 fn write_quad(
     req: &FwReq,
     node: &mut FwNode,
@@ -84,33 +126,107 @@ fn write_quad(
 ) -> Result<(), Error> {
     let mut frame = [0; 4];
     frame.copy_from_slice(&quad.to_be_bytes());
-    req.transaction(
+    let err = match transaction(
+        req,
         node,
         FwTcode::WriteQuadletRequest,
         BASE_OFFSET + offset as u64,
         4,
         &mut frame,
         timeout_ms,
-    )
-    .or_else(|err| {
+    ) {
+        Ok(_) => return Ok(()),
+        Err(err) if is_node_disappeared(&err) => return Err(err),
+        Err(err) => err,
+    };
+
+    for i in 0..BUSY_RETRY_COUNT {
         // For prevention of RCODE_BUSY.
-        thread::sleep(time::Duration::from_millis(BUSY_DURATION));
-        req.transaction(
+        thread::sleep(time::Duration::from_millis(BUSY_DURATION << i));
+        frame.copy_from_slice(&quad.to_be_bytes());
+        match transaction(
+            req,
             node,
             FwTcode::WriteQuadletRequest,
             BASE_OFFSET + offset as u64,
             4,
             &mut frame,
             timeout_ms,
-        )
-        .and_then(|_| {
-            if u32::from_be_bytes(frame) == quad {
-                Ok(())
-            } else {
-                Err(err)
-            }
-        })
-    })
+        ) {
+            Ok(_) => match read_quad(req, node, offset, timeout_ms) {
+                Ok(val) if val == quad => return Ok(()),
+                Err(e) if is_node_disappeared(&e) => return Err(e),
+                _ => (),
+            },
+            Err(e) if is_node_disappeared(&e) => return Err(e),
+            Err(_) => (),
+        }
+    }
+
+    Err(err)
+}
+
+fn deserialize_quads(quads: &mut [u32], frame: &[u8]) {
+    let mut quadlet = [0; 4];
+    quads
+        .iter_mut()
+        .zip(frame.chunks_exact(4))
+        .for_each(|(quad, raw)| {
+            quadlet.copy_from_slice(raw);
+            *quad = u32::from_be_bytes(quadlet);
+        });
+}
+
+// Read consecutive registers in single transaction.
+fn read_block(
+    req: &FwReq,
+    node: &mut FwNode,
+    offset: u32,
+    quads: &mut [u32],
+    timeout_ms: u32,
+) -> Result<(), Error> {
+    let mut frame = vec![0; quads.len() * 4];
+    transaction(
+        req,
+        node,
+        FwTcode::ReadBlockRequest,
+        BASE_OFFSET + offset as u64,
+        frame.len(),
+        &mut frame,
+        timeout_ms,
+    )
+    .map(|_| deserialize_quads(quads, &frame))
+}
+
+#[cfg(test)]
+fn serialize_quads(quads: &[u32], frame: &mut [u8]) {
+    quads
+        .iter()
+        .zip(frame.chunks_exact_mut(4))
+        .for_each(|(quad, raw)| raw.copy_from_slice(&quad.to_be_bytes()));
+}
+
+// Write consecutive registers in single transaction. At present, no register is known to be
+// written by block, thus it is just for testing.
+#[cfg(test)]
+fn write_block(
+    req: &FwReq,
+    node: &mut FwNode,
+    offset: u32,
+    quads: &[u32],
+    timeout_ms: u32,
+) -> Result<(), Error> {
+    let mut frame = vec![0; quads.len() * 4];
+    serialize_quads(quads, &mut frame);
+    transaction(
+        req,
+        node,
+        FwTcode::WriteBlockRequest,
+        BASE_OFFSET + offset as u64,
+        frame.len(),
+        &mut frame,
+        timeout_ms,
+    )
 }
 
 fn serialize_flag<T: Copy + Eq>(
@@ -122,6 +238,14 @@ fn serialize_flag<T: Copy + Eq>(
     vals: &[u8],
     label: &str,
 ) -> Result<(), Error> {
+    // The list of values can be longer than the list of flags supported by the model.
+    debug_assert!(
+        flags.len() <= vals.len(),
+        "Lack of values for {}: {} flags, {} values",
+        label,
+        flags.len(),
+        vals.len()
+    );
     flags
         .iter()
         .zip(vals)
@@ -134,6 +258,15 @@ fn serialize_flag<T: Copy + Eq>(
             Error::new(FileError::Io, &label)
         })
         .map(|(_, &val)| {
+            // The value should not corrupt the adjacent fields.
+            debug_assert!(
+                ((val as u32) << shift) & !mask == 0,
+                "The value for {} overflows mask 0x{:08x} with shift {}: 0x{:02x}",
+                label,
+                mask,
+                shift,
+                val
+            );
             *quad &= !mask;
             *quad |= (val as u32) << shift;
         })
@@ -152,7 +285,7 @@ fn deserialize_flag<T: Copy + Eq>(
     flags
         .iter()
         .zip(vals)
-        .find(|(_, v)| val.eq(v))
+        .find(|(_, v)| val.eq(*v))
         .ok_or_else(|| {
             let label = format!(
                 "Invalid value for {}, 0x{:08x}, 0x{:08x}",
@@ -165,6 +298,7 @@ fn deserialize_flag<T: Copy + Eq>(
 
 /// Nominal rate of sampling clock.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ClkRate {
     /// 44.1 kHx.
     R44100,
@@ -186,13 +320,97 @@ impl Default for ClkRate {
     }
 }
 
+impl ClkRate {
+    /// The frequency of sampling clock in Hz.
+    pub fn as_hz(&self) -> u32 {
+        match self {
+            Self::R44100 => 44100,
+            Self::R48000 => 48000,
+            Self::R88200 => 88200,
+            Self::R96000 => 96000,
+            Self::R176400 => 176400,
+            Self::R192000 => 192000,
+        }
+    }
+}
+
+impl std::fmt::Display for ClkRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            Self::R44100 => "44.1 kHz",
+            Self::R48000 => "48 kHz",
+            Self::R88200 => "88.2 kHz",
+            Self::R96000 => "96 kHz",
+            Self::R176400 => "176.4 kHz",
+            Self::R192000 => "192 kHz",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+impl std::convert::TryFrom<u32> for ClkRate {
+    type Error = ();
+
+    fn try_from(freq: u32) -> Result<Self, Self::Error> {
+        match freq {
+            44100 => Ok(Self::R44100),
+            48000 => Ok(Self::R48000),
+            88200 => Ok(Self::R88200),
+            96000 => Ok(Self::R96000),
+            176400 => Ok(Self::R176400),
+            192000 => Ok(Self::R192000),
+            _ => Err(()),
+        }
+    }
+}
+
 const BUSY_DURATION: u64 = 150;
+const BUSY_RETRY_COUNT: usize = 3;
 const DISPLAY_CHARS: usize = 4 * 4;
 
 /// Parameters of clock name in LCD display.
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClockNameDisplayParameters(pub String);
 
+impl ClockNameDisplayParameters {
+    /// Instantiate parameters to display the rate of sampling clock.
+    pub fn from_clock_rate(rate: &ClkRate) -> Self {
+        Self(rate.to_string())
+    }
+
+    fn is_supported_char(c: char) -> bool {
+        c.is_ascii_graphic() || c == ' '
+    }
+
+    /// Check whether the name consists of printable ASCII characters and fits to LCD.
+    pub fn validate(&self) -> Result<(), Error> {
+        if let Some(c) = self.0.chars().find(|&c| !Self::is_supported_char(c)) {
+            let msg = format!("Unsupported character for clock name: {:?}", c);
+            Err(Error::new(FileError::Inval, &msg))
+        } else if self.0.len() > DISPLAY_CHARS {
+            let msg = format!(
+                "Clock name should be up to {} characters: {}",
+                DISPLAY_CHARS, self.0
+            );
+            Err(Error::new(FileError::Inval, &msg))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Replace unsupported characters with space, and truncate to fit to LCD.
+    pub fn sanitize(&self) -> Self {
+        let name = self
+            .0
+            .chars()
+            .map(|c| if Self::is_supported_char(c) { c } else { ' ' })
+            .take(DISPLAY_CHARS)
+            .collect();
+        Self(name)
+    }
+}
+
 /// The trait for specification of LCD to display clock name.
 pub trait MotuClockNameDisplaySpecification {}
 
@@ -206,6 +424,8 @@ where
         params: &ClockNameDisplayParameters,
         timeout_ms: u32,
     ) -> Result<(), Error> {
+        params.validate()?;
+
         let mut chars = [0x20; DISPLAY_CHARS];
         chars
             .iter_mut()
@@ -235,6 +455,7 @@ const PORT_PHONE_SHIFT: usize = 0;
 
 /// The parameters of phone assignments.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PhoneAssignParameters(pub TargetPort);
 
 impl<O> MotuWhollyCacheableParamsOperation<PhoneAssignParameters> for O
@@ -289,6 +510,7 @@ where
 
 /// Mode of speed for output signal of word clock on BNC interface.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WordClkSpeedMode {
     /// The speed is forced to be 44.1/48.0 kHz.
     ForceLowRate,
@@ -366,6 +588,7 @@ impl<O: MotuWordClockOutputSpecification> MotuWhollyUpdatableParamsOperation<Wor
 
 /// Mode of rate convert for AES/EBU input/output signals.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AesebuRateConvertMode {
     /// Not available.
     None,
@@ -452,6 +675,7 @@ where
 
 /// Mode of hold time for clip and peak LEDs.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LevelMetersHoldTimeMode {
     /// off.
     Off,
@@ -477,8 +701,39 @@ impl Default for LevelMetersHoldTimeMode {
     }
 }
 
+impl LevelMetersHoldTimeMode {
+    /// The duration of hold time, if it is finite.
+    pub fn as_duration(&self) -> Option<time::Duration> {
+        let secs = match self {
+            Self::Off | Self::Infinite => None,
+            Self::Sec2 => Some(2),
+            Self::Sec4 => Some(4),
+            Self::Sec10 => Some(10),
+            Self::Sec60 => Some(60),
+            Self::Sec300 => Some(300),
+            Self::Sec480 => Some(480),
+        };
+        secs.map(time::Duration::from_secs)
+    }
+
+    /// The string to display hold time.
+    pub fn as_display_string(&self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Sec2 => "2 s",
+            Self::Sec4 => "4 s",
+            Self::Sec10 => "10 s",
+            Self::Sec60 => "60 s",
+            Self::Sec300 => "300 s",
+            Self::Sec480 => "480 s",
+            Self::Infinite => "\u{221e}",
+        }
+    }
+}
+
 /// Mode of programmable meter display.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LevelMetersProgrammableMode {
     /// For analog outputs.
     AnalogOutput,
@@ -502,6 +757,7 @@ impl Default for LevelMetersProgrammableMode {
 
 /// Mode of AES/EBU meter display.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LevelMetersAesebuMode {
     /// For AES/EBU inputs.
     Input,
@@ -517,6 +773,7 @@ impl Default for LevelMetersAesebuMode {
 
 /// The parameters of level meters.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LevelMetersParameters {
     /// The duration to hold peak.
     pub peak_hold_time: LevelMetersHoldTimeMode,
@@ -685,6 +942,7 @@ where
 
 /// Port to assign.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TargetPort {
     Disabled,
     AnalogPair(usize),
@@ -713,8 +971,124 @@ impl Default for TargetPort {
     }
 }
 
+impl TargetPort {
+    /// The number of channels which the port represents.
+    pub fn channel_count(&self) -> usize {
+        match self {
+            Self::Disabled => 0,
+            Self::Analog6Pairs => 6,
+            Self::Analog8Pairs => 8,
+            _ if self.is_stereo_pair() => 2,
+            _ => 1,
+        }
+    }
+
+    /// Whether the port represents a pair of stereo channels.
+    pub fn is_stereo_pair(&self) -> bool {
+        matches!(
+            self,
+            Self::AnalogPair(_)
+                | Self::AesEbuPair
+                | Self::PhonePair
+                | Self::MainPair
+                | Self::SpdifPair
+                | Self::AdatPair(_)
+                | Self::OpticalAPair(_)
+                | Self::OpticalBPair(_)
+        )
+    }
+}
+
+impl std::fmt::Display for TargetPort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Disabled => write!(f, "Disabled"),
+            Self::AnalogPair(pair) => write!(f, "Analog-{}/{}", pair * 2 + 1, pair * 2 + 2),
+            Self::AesEbuPair => write!(f, "AES/EBU-1/2"),
+            Self::PhonePair => write!(f, "Phone-1/2"),
+            Self::MainPair => write!(f, "Main-1/2"),
+            Self::SpdifPair => write!(f, "S/PDIF-1/2"),
+            Self::AdatPair(pair) => write!(f, "ADAT-{}/{}", pair * 2 + 1, pair * 2 + 2),
+            Self::Analog6Pairs => write!(f, "Analog-1/2/3/4/5/6"),
+            Self::Analog8Pairs => write!(f, "Analog-1/2/3/4/5/6/7/8"),
+            Self::OpticalAPair(pair) => write!(f, "Optical-A-{}/{}", pair * 2 + 1, pair * 2 + 2),
+            Self::OpticalBPair(pair) => write!(f, "Optical-B-{}/{}", pair * 2 + 1, pair * 2 + 2),
+            Self::Analog(ch) => write!(f, "Analog-{}", ch + 1),
+            Self::AesEbu(ch) => write!(f, "AES/EBU-{}", ch + 1),
+            Self::Phone(ch) => write!(f, "Phone-{}", ch + 1),
+            Self::Main(ch) => write!(f, "Main-{}", ch + 1),
+            Self::Spdif(ch) => write!(f, "S/PDIF-{}", ch + 1),
+            Self::Adat(ch) => write!(f, "ADAT-{}", ch + 1),
+            Self::OpticalA(ch) => write!(f, "Optical-A-{}", ch + 1),
+            Self::OpticalB(ch) => write!(f, "Optical-B-{}", ch + 1),
+        }
+    }
+}
+
+/// The error to parse the name of port.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetPortParseError(pub String);
+
+impl std::fmt::Display for TargetPortParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid name of port: {}", self.0)
+    }
+}
+
+impl std::error::Error for TargetPortParseError {}
+
+impl std::str::FromStr for TargetPort {
+    type Err = TargetPortParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || TargetPortParseError(s.to_string());
+
+        if s == "Disabled" {
+            return Ok(Self::Disabled);
+        }
+
+        // The name consists of the label and the sequence of channel numbers, delimited by hyphen.
+        let (label, numbers) = s.rsplit_once('-').ok_or_else(err)?;
+        let chs = numbers
+            .split('/')
+            .map(|n| n.parse::<usize>().ok().filter(|&n| n > 0).map(|n| n - 1))
+            .collect::<Option<Vec<usize>>>()
+            .ok_or_else(err)?;
+
+        let port = match (label, &chs[..]) {
+            ("Analog", [ch]) => Self::Analog(*ch),
+            ("AES/EBU", [ch]) => Self::AesEbu(*ch),
+            ("Phone", [ch]) => Self::Phone(*ch),
+            ("Main", [ch]) => Self::Main(*ch),
+            ("S/PDIF", [ch]) => Self::Spdif(*ch),
+            ("ADAT", [ch]) => Self::Adat(*ch),
+            ("Optical-A", [ch]) => Self::OpticalA(*ch),
+            ("Optical-B", [ch]) => Self::OpticalB(*ch),
+            ("Analog", [ch, _]) => Self::AnalogPair(ch / 2),
+            ("AES/EBU", [_, _]) => Self::AesEbuPair,
+            ("Phone", [_, _]) => Self::PhonePair,
+            ("Main", [_, _]) => Self::MainPair,
+            ("S/PDIF", [_, _]) => Self::SpdifPair,
+            ("ADAT", [ch, _]) => Self::AdatPair(ch / 2),
+            ("Optical-A", [ch, _]) => Self::OpticalAPair(ch / 2),
+            ("Optical-B", [ch, _]) => Self::OpticalBPair(ch / 2),
+            ("Analog", [_, _, _, _, _, _]) => Self::Analog6Pairs,
+            ("Analog", [_, _, _, _, _, _, _, _]) => Self::Analog8Pairs,
+            _ => Err(err())?,
+        };
+
+        // The channel numbers should be in canonical form.
+        if port.to_string() == s {
+            Ok(port)
+        } else {
+            Err(err())
+        }
+    }
+}
+
 /// Nominal level of audio signal.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NominalSignalLevel {
     /// -10 dBV.
     Consumer,
@@ -730,7 +1104,204 @@ impl Default for NominalSignalLevel {
 
 #[cfg(test)]
 mod test {
-    use super::*;
+    use {super::*, std::convert::TryFrom};
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Lack of values for test")]
+    fn serialize_flag_lack_of_values() {
+        let mut quad = 0;
+        let _ = serialize_flag(
+            &TargetPort::MainPair,
+            &mut quad,
+            0x0000000f,
+            0,
+            &[TargetPort::PhonePair, TargetPort::MainPair],
+            &[0x01],
+            "test",
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "The value for test overflows mask")]
+    fn serialize_flag_misaligned_mask() {
+        let mut quad = 0;
+        let _ = serialize_flag(
+            &TargetPort::MainPair,
+            &mut quad,
+            0x000000f0,
+            8,
+            &[TargetPort::PhonePair, TargetPort::MainPair],
+            &[0x01, 0x02],
+            "test",
+        );
+    }
+
+    #[test]
+    fn clk_rate_conversion() {
+        [
+            (ClkRate::R44100, 44100, "44.1 kHz"),
+            (ClkRate::R48000, 48000, "48 kHz"),
+            (ClkRate::R88200, 88200, "88.2 kHz"),
+            (ClkRate::R96000, 96000, "96 kHz"),
+            (ClkRate::R176400, 176400, "176.4 kHz"),
+            (ClkRate::R192000, 192000, "192 kHz"),
+        ]
+        .iter()
+        .for_each(|&(rate, hz, label)| {
+            assert_eq!(rate.as_hz(), hz);
+            assert_eq!(rate.to_string(), label);
+            assert_eq!(ClkRate::try_from(hz), Ok(rate));
+            assert_eq!(
+                ClockNameDisplayParameters::from_clock_rate(&rate),
+                ClockNameDisplayParameters(label.to_string())
+            );
+        });
+
+        assert_eq!(ClkRate::try_from(32000), Err(()));
+    }
+
+    #[test]
+    fn block_transaction_with_mock() {
+        use mock::{take_transactions, MockTransaction};
+
+        let req = FwReq::new();
+        let mut node = FwNode::new();
+        let quads = [0x01234567, 0x89abcdef, 0xfedcba98];
+
+        write_block(&req, &mut node, 0x0b00, &quads, 100).unwrap();
+
+        // The result should be the same as the one of sequential read of quadlet.
+        let sequential: Vec<u32> = (0..quads.len())
+            .map(|i| read_quad(&req, &mut node, 0x0b00 + 4 * i as u32, 100).unwrap())
+            .collect();
+
+        let mut block = [0; 3];
+        read_block(&req, &mut node, 0x0b00, &mut block, 100).unwrap();
+        assert_eq!(&block[..], &sequential[..]);
+        assert_eq!(block, quads);
+
+        let addr = BASE_OFFSET + 0x0b00;
+        assert_eq!(
+            take_transactions(),
+            vec![
+                MockTransaction::Write(addr, quads.to_vec()),
+                MockTransaction::Read(addr, 4),
+                MockTransaction::Read(addr + 4, 4),
+                MockTransaction::Read(addr + 8, 4),
+                MockTransaction::Read(addr, 12),
+            ]
+        );
+    }
+
+    #[test]
+    fn target_port_channel_count() {
+        [
+            (TargetPort::Disabled, 0, false),
+            (TargetPort::AnalogPair(0), 2, true),
+            (TargetPort::AesEbuPair, 2, true),
+            (TargetPort::PhonePair, 2, true),
+            (TargetPort::MainPair, 2, true),
+            (TargetPort::SpdifPair, 2, true),
+            (TargetPort::AdatPair(1), 2, true),
+            (TargetPort::Analog6Pairs, 6, false),
+            (TargetPort::Analog8Pairs, 8, false),
+            (TargetPort::OpticalAPair(2), 2, true),
+            (TargetPort::OpticalBPair(3), 2, true),
+            (TargetPort::Analog(4), 1, false),
+            (TargetPort::AesEbu(0), 1, false),
+            (TargetPort::Phone(1), 1, false),
+            (TargetPort::Main(0), 1, false),
+            (TargetPort::Spdif(1), 1, false),
+            (TargetPort::Adat(5), 1, false),
+            (TargetPort::OpticalA(6), 1, false),
+            (TargetPort::OpticalB(7), 1, false),
+        ]
+        .iter()
+        .for_each(|&(port, count, is_stereo_pair)| {
+            assert_eq!(port.channel_count(), count, "{:?}", port);
+            assert_eq!(port.is_stereo_pair(), is_stereo_pair, "{:?}", port);
+        });
+    }
+
+    #[test]
+    fn target_port_name() {
+        [
+            (TargetPort::Disabled, "Disabled"),
+            (TargetPort::AnalogPair(0), "Analog-1/2"),
+            (TargetPort::AesEbuPair, "AES/EBU-1/2"),
+            (TargetPort::PhonePair, "Phone-1/2"),
+            (TargetPort::MainPair, "Main-1/2"),
+            (TargetPort::SpdifPair, "S/PDIF-1/2"),
+            (TargetPort::AdatPair(1), "ADAT-3/4"),
+            (TargetPort::Analog6Pairs, "Analog-1/2/3/4/5/6"),
+            (TargetPort::Analog8Pairs, "Analog-1/2/3/4/5/6/7/8"),
+            (TargetPort::OpticalAPair(2), "Optical-A-5/6"),
+            (TargetPort::OpticalBPair(3), "Optical-B-7/8"),
+            (TargetPort::Analog(4), "Analog-5"),
+            (TargetPort::AesEbu(0), "AES/EBU-1"),
+            (TargetPort::Phone(1), "Phone-2"),
+            (TargetPort::Main(0), "Main-1"),
+            (TargetPort::Spdif(1), "S/PDIF-2"),
+            (TargetPort::Adat(5), "ADAT-6"),
+            (TargetPort::OpticalA(6), "Optical-A-7"),
+            (TargetPort::OpticalB(7), "Optical-B-8"),
+        ]
+        .iter()
+        .for_each(|&(port, name)| {
+            assert_eq!(port.to_string(), name);
+            assert_eq!(name.parse::<TargetPort>(), Ok(port), "{}", name);
+        });
+    }
+
+    #[test]
+    fn target_port_name_invalid() {
+        [
+            "",
+            "Disabled-1",
+            "Analog",
+            "Analog-",
+            "Analog-0",
+            "Analog-01",
+            "Analog-one",
+            "Analog-2/3",
+            "Analog-1/3",
+            "Analog-2/1",
+            "Analog-1/2/3",
+            "Analog-3/4/5/6/7/8",
+            "Phone-3/4",
+            "SPDIF-1/2",
+            "Optical-C-1",
+            "Unknown-1",
+        ]
+        .iter()
+        .for_each(|name| {
+            assert_eq!(
+                name.parse::<TargetPort>(),
+                Err(TargetPortParseError(name.to_string()))
+            );
+        });
+    }
+
+    #[test]
+    fn level_meters_hold_time_mode_duration() {
+        [
+            (LevelMetersHoldTimeMode::Off, None, "off"),
+            (LevelMetersHoldTimeMode::Sec2, Some(2), "2 s"),
+            (LevelMetersHoldTimeMode::Sec4, Some(4), "4 s"),
+            (LevelMetersHoldTimeMode::Sec10, Some(10), "10 s"),
+            (LevelMetersHoldTimeMode::Sec60, Some(60), "60 s"),
+            (LevelMetersHoldTimeMode::Sec300, Some(300), "300 s"),
+            (LevelMetersHoldTimeMode::Sec480, Some(480), "480 s"),
+            (LevelMetersHoldTimeMode::Infinite, None, "\u{221e}"),
+        ]
+        .iter()
+        .for_each(|&(mode, secs, label)| {
+            assert_eq!(mode.as_duration(), secs.map(time::Duration::from_secs));
+            assert_eq!(mode.as_display_string(), label);
+        });
+    }
 
     #[test]
     fn flag_serdes() {
@@ -812,4 +1383,158 @@ mod test {
         .unwrap();
         assert_eq!(target1, orig1);
     }
+
+    #[test]
+    fn clock_name_display_params() {
+        use {
+            mock::{register, take_transactions},
+            version_2::F828mk2Protocol,
+        };
+
+        let mut req = FwReq::new();
+        let mut node = FwNode::new();
+
+        let params = ClockNameDisplayParameters("48 kHz".to_string());
+        assert!(params.validate().is_ok());
+        assert_eq!(params.sanitize(), params);
+        F828mk2Protocol::update_wholly(&mut req, &mut node, &params, 100).unwrap();
+        assert_eq!(take_transactions().len(), DISPLAY_CHARS / 4);
+        let addr = BASE_OFFSET + OFFSET_CLK_DISPLAY as u64;
+        assert_eq!(register(addr), Some(u32::from_be_bytes(*b"48 k")));
+        assert_eq!(register(addr + 4), Some(u32::from_be_bytes(*b"Hz  ")));
+
+        // Over-long string.
+        let params = ClockNameDisplayParameters("0123456789abcdefg".to_string());
+        let err = F828mk2Protocol::update_wholly(&mut req, &mut node, &params, 100).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Inval));
+        assert_eq!(take_transactions(), vec![]);
+        assert_eq!(
+            params.sanitize(),
+            ClockNameDisplayParameters("0123456789abcdef".to_string())
+        );
+
+        // UTF-8 multibyte characters.
+        let params = ClockNameDisplayParameters("96 \u{33a2}z".to_string());
+        let err = F828mk2Protocol::update_wholly(&mut req, &mut node, &params, 100).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Inval));
+        assert_eq!(take_transactions(), vec![]);
+        let sanitized = params.sanitize();
+        assert_eq!(sanitized, ClockNameDisplayParameters("96  z".to_string()));
+        assert!(sanitized.validate().is_ok());
+    }
+
+    #[test]
+    fn write_quad_retry_at_busy() {
+        use mock::{inject_busy, register, take_transactions, MockTransaction};
+
+        let req = FwReq::new();
+        let mut node = FwNode::new();
+        let addr = BASE_OFFSET + OFFSET_PORT as u64;
+
+        // Fail twice, then succeed.
+        inject_busy(addr, 2);
+        write_quad(&req, &mut node, OFFSET_PORT, 0x01234567, 100).unwrap();
+        let mut trxs = vec![MockTransaction::Write(addr, vec![0x01234567]); 3];
+        trxs.push(MockTransaction::Read(addr, 4));
+        assert_eq!(take_transactions(), trxs);
+        assert_eq!(register(addr), Some(0x01234567));
+
+        // All of retries fail, then the error of first transaction is reported.
+        inject_busy(addr, BUSY_RETRY_COUNT + 1);
+        let err = write_quad(&req, &mut node, OFFSET_PORT, 0x76543210, 100).unwrap_err();
+        assert_eq!(err.kind::<FwReqError>(), Some(FwReqError::Busy));
+        assert_eq!(take_transactions().len(), BUSY_RETRY_COUNT + 1);
+        assert_eq!(register(addr), Some(0x01234567));
+    }
+
+    #[test]
+    fn transaction_abort_at_unplug() {
+        use mock::{
+            inject_bus_reset, inject_unplug, seed_register, take_transactions, MockTransaction,
+        };
+
+        let req = FwReq::new();
+        let mut node = FwNode::new();
+        let addr = BASE_OFFSET + OFFSET_PORT as u64;
+        seed_register(addr, 0x01234567);
+
+        // Bus reset is not regarded as disappearance of the node.
+        let generation = node.generation();
+        inject_bus_reset(addr);
+        let err = read_quad(&req, &mut node, OFFSET_PORT, 100).unwrap_err();
+        assert_eq!(err.kind::<FwReqError>(), Some(FwReqError::Generation));
+        assert_ne!(node.generation(), generation);
+        assert_eq!(read_quad(&req, &mut node, OFFSET_PORT, 100), Ok(0x01234567));
+        assert_eq!(take_transactions().len(), 2);
+
+        // Unplug during write transaction, then no retry.
+        let generation = node.generation();
+        inject_unplug(addr);
+        let err = write_quad(&req, &mut node, OFFSET_PORT, 0x76543210, 100).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Noent));
+        assert_ne!(node.generation(), generation);
+        assert_eq!(
+            take_transactions(),
+            vec![MockTransaction::Write(addr, vec![0x76543210])]
+        );
+
+        // The transaction is not issued for the disconnected node.
+        let err = read_quad(&req, &mut node, OFFSET_PORT, 100).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Noent));
+        assert_eq!(take_transactions(), vec![]);
+    }
+
+    #[test]
+    fn phone_assign_params_with_mock() {
+        use {
+            mock::{register, seed_register, take_transactions, MockTransaction},
+            version_2::F828mk2Protocol,
+        };
+
+        let mut req = FwReq::new();
+        let mut node = FwNode::new();
+        let addr = BASE_OFFSET + OFFSET_PORT as u64;
+
+        // The register is not available yet.
+        let mut params = PhoneAssignParameters::default();
+        let res = F828mk2Protocol::cache_wholly(&mut req, &mut node, &mut params, 100);
+        assert!(res.is_err());
+        assert_eq!(take_transactions(), vec![MockTransaction::Read(addr, 4)]);
+
+        // The other fields in the register should be preserved.
+        seed_register(addr, 0x12345603);
+        let mut params = PhoneAssignParameters::default();
+        F828mk2Protocol::cache_wholly(&mut req, &mut node, &mut params, 100).unwrap();
+        assert_eq!(params, PhoneAssignParameters(TargetPort::AnalogPair(1)));
+        assert_eq!(take_transactions(), vec![MockTransaction::Read(addr, 4)]);
+
+        let params = PhoneAssignParameters(TargetPort::SpdifPair);
+        F828mk2Protocol::update_wholly(&mut req, &mut node, &params, 100).unwrap();
+        assert_eq!(
+            take_transactions(),
+            vec![
+                MockTransaction::Read(addr, 4),
+                MockTransaction::Write(addr, vec![0x12345607]),
+            ]
+        );
+        assert_eq!(register(addr), Some(0x12345607));
+
+        let mut cached = PhoneAssignParameters::default();
+        F828mk2Protocol::cache_wholly(&mut req, &mut node, &mut cached, 100).unwrap();
+        assert_eq!(cached, params);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn level_meters_params_serde() {
+        let params = LevelMetersParameters {
+            peak_hold_time: LevelMetersHoldTimeMode::Sec4,
+            clip_hold_time: LevelMetersHoldTimeMode::Infinite,
+            aesebu_mode: LevelMetersAesebuMode::Output,
+            programmable_mode: LevelMetersProgrammableMode::AdatAOutput,
+        };
+        let json = serde_json::to_string(&params).unwrap();
+        let target: LevelMetersParameters = serde_json::from_str(&json).unwrap();
+        assert_eq!(params, target);
+    }
 }
@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: LGPL-3.0-or-later
+// Copyright (c) 2021 Takashi Sakamoto
+
+//! Mock of asynchronous transaction for unit testing.
+//!
+//! The module provides the trait with the same signature as `hinawa::prelude::FwReqExtManual`.
+//! In test build, it shadows the trait so that the transactions in protocol implementation are
+//! operated against the registers in memory, instead of actual hardware. The registers, the
+//! history of transactions, and the failures to inject are kept per thread, thus independent
+//! between tests.
+//!
+//! The module also provides the trait with the same signature as `hinawa::prelude::FwNodeExt`
+//! for the generation of bus, which changes when bus reset or unplug is injected.
+
+use {
+    glib::Error,
+    hinawa::{FwNode, FwNodeError, FwReq, FwReqError, FwTcode},
+    std::{
+        cell::{Cell, RefCell},
+        collections::HashMap,
+    },
+};
+
+/// The transaction recorded by the mock.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MockTransaction {
+    /// Read transaction with the address and the length of frame.
+    Read(u64, usize),
+    /// Write transaction with the address and the quadlets written.
+    Write(u64, Vec<u32>),
+}
+
+/// The event on the bus to inject.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MockBusEvent {
+    /// The generation changes and the transaction fails with generation rcode.
+    BusReset,
+    /// The generation changes and the transaction is cancelled. The node is disconnected then.
+    Unplug,
+}
+
+thread_local! {
+    static REGISTERS: RefCell<HashMap<u64, u32>> = RefCell::new(HashMap::new());
+    static TRANSACTIONS: RefCell<Vec<MockTransaction>> = const { RefCell::new(Vec::new()) };
+    static FAILURES: RefCell<HashMap<u64, usize>> = RefCell::new(HashMap::new());
+    static BUS_EVENTS: RefCell<HashMap<u64, MockBusEvent>> = RefCell::new(HashMap::new());
+    static GENERATION: Cell<u32> = const { Cell::new(0) };
+    static DISCONNECTED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Seed the value of register at the address.
+pub fn seed_register(addr: u64, quad: u32) {
+    REGISTERS.with(|regs| regs.borrow_mut().insert(addr, quad));
+}
+
+/// Retrieve the value of register at the address, if seeded or written.
+pub fn register(addr: u64) -> Option<u32> {
+    REGISTERS.with(|regs| regs.borrow().get(&addr).copied())
+}
+
+/// Make the given number of subsequent transactions at the address fail with busy rcode.
+pub fn inject_busy(addr: u64, count: usize) {
+    FAILURES.with(|failures| failures.borrow_mut().insert(addr, count));
+}
+
+/// Make the next transaction at the address fail due to bus reset.
+pub fn inject_bus_reset(addr: u64) {
+    BUS_EVENTS.with(|events| events.borrow_mut().insert(addr, MockBusEvent::BusReset));
+}
+
+/// Make the next transaction at the address fail due to unplug of the node.
+pub fn inject_unplug(addr: u64) {
+    BUS_EVENTS.with(|events| events.borrow_mut().insert(addr, MockBusEvent::Unplug));
+}
+
+/// Take the history of transactions since the last call.
+pub fn take_transactions() -> Vec<MockTransaction> {
+    TRANSACTIONS.with(|trxs| trxs.borrow_mut().drain(..).collect())
+}
+
+/// The trait to shadow `hinawa::prelude::FwNodeExt` in test build.
+pub trait FwNodeExt {
+    fn generation(&self) -> u32;
+    fn local_node_id(&self) -> u32;
+}
+
+impl FwNodeExt for FwNode {
+    fn generation(&self) -> u32 {
+        GENERATION.with(|generation| generation.get())
+    }
+
+    fn local_node_id(&self) -> u32 {
+        0xffc0
+    }
+}
+
+fn quads_from_frame(frame: &[u8]) -> Vec<u32> {
+    let mut quadlet = [0; 4];
+    frame
+        .chunks_exact(4)
+        .map(|raw| {
+            quadlet.copy_from_slice(raw);
+            u32::from_be_bytes(quadlet)
+        })
+        .collect()
+}
+
+/// The trait to shadow `hinawa::prelude::FwReqExtManual` in test build.
+pub trait FwReqExtManual {
+    fn transaction(
+        &self,
+        node: &FwNode,
+        tcode: FwTcode,
+        addr: u64,
+        length: usize,
+        frame: &mut [u8],
+        timeout_ms: u32,
+    ) -> Result<(), Error>;
+}
+
+impl FwReqExtManual for FwReq {
+    fn transaction(
+        &self,
+        _: &FwNode,
+        tcode: FwTcode,
+        addr: u64,
+        length: usize,
+        frame: &mut [u8],
+        _: u32,
+    ) -> Result<(), Error> {
+        assert_eq!(
+            length % 4,
+            0,
+            "The length of frame should be multiples of 4"
+        );
+        assert!(frame.len() >= length, "Lack of frame for the length");
+
+        if DISCONNECTED.with(|disconnected| disconnected.get()) {
+            Err(Error::new(FwNodeError::Disconnected, "Disconnected"))?;
+        }
+
+        let event = BUS_EVENTS.with(|events| events.borrow_mut().remove(&addr));
+        if let Some(event) = event {
+            let trx = match tcode {
+                FwTcode::ReadQuadletRequest | FwTcode::ReadBlockRequest => {
+                    MockTransaction::Read(addr, length)
+                }
+                _ => MockTransaction::Write(addr, quads_from_frame(&frame[..length])),
+            };
+            TRANSACTIONS.with(|trxs| trxs.borrow_mut().push(trx));
+            GENERATION.with(|generation| generation.set(generation.get() + 1));
+            match event {
+                MockBusEvent::BusReset => Err(Error::new(FwReqError::Generation, "Bus reset"))?,
+                MockBusEvent::Unplug => {
+                    DISCONNECTED.with(|disconnected| disconnected.set(true));
+                    Err(Error::new(FwReqError::Cancelled, "Cancelled"))?
+                }
+            }
+        }
+
+        let busy = FAILURES.with(|failures| {
+            failures
+                .borrow_mut()
+                .get_mut(&addr)
+                .filter(|count| **count > 0)
+                .map(|count| *count -= 1)
+                .is_some()
+        });
+
+        match tcode {
+            FwTcode::ReadQuadletRequest | FwTcode::ReadBlockRequest => {
+                TRANSACTIONS
+                    .with(|trxs| trxs.borrow_mut().push(MockTransaction::Read(addr, length)));
+                if busy {
+                    Err(Error::new(FwReqError::Busy, "Busy"))?;
+                }
+                REGISTERS.with(|regs| {
+                    let regs = regs.borrow();
+                    frame[..length]
+                        .chunks_exact_mut(4)
+                        .enumerate()
+                        .try_for_each(|(i, raw)| {
+                            let offset = addr + 4 * i as u64;
+                            regs.get(&offset)
+                                .map(|quad| raw.copy_from_slice(&quad.to_be_bytes()))
+                                .ok_or_else(|| {
+                                    let msg = format!("Register not seeded: 0x{:012x}", offset);
+                                    Error::new(FwReqError::AddressError, &msg)
+                                })
+                        })
+                })
+            }
+            FwTcode::WriteQuadletRequest | FwTcode::WriteBlockRequest => {
+                let quads = quads_from_frame(&frame[..length]);
+                if busy {
+                    TRANSACTIONS
+                        .with(|trxs| trxs.borrow_mut().push(MockTransaction::Write(addr, quads)));
+                    return Err(Error::new(FwReqError::Busy, "Busy"));
+                }
+                REGISTERS.with(|regs| {
+                    let mut regs = regs.borrow_mut();
+                    quads.iter().enumerate().for_each(|(i, &quad)| {
+                        regs.insert(addr + 4 * i as u64, quad);
+                    });
+                });
+                TRANSACTIONS
+                    .with(|trxs| trxs.borrow_mut().push(MockTransaction::Write(addr, quads)));
+                Ok(())
+            }
+            _ => Err(Error::new(
+                FwReqError::TypeError,
+                "Unsupported transaction code",
+            )),
+        }
+    }
+}
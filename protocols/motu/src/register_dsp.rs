@@ -97,7 +97,7 @@ where
         let _ = Self::ASSIGN_PORT_TARGETS
             .iter()
             .zip(Self::ASSIGN_PORT_VALS)
-            .find(|(_, v)| val.eq(v))
+            .find(|(_, v)| val.eq(*v))
             .map(|(&p, _)| params.0 = p);
     }
 }
@@ -113,7 +113,7 @@ where
                 let _ = Self::ASSIGN_PORT_TARGETS
                     .iter()
                     .zip(Self::ASSIGN_PORT_VALS)
-                    .find(|(_, v)| val.eq(v))
+                    .find(|(_, v)| val.eq(*v))
                     .map(|(&p, _)| params.0 = p);
                 true
             }
@@ -131,6 +131,7 @@ const MIXER_OUTPUT_VOLUME_MASK: u32 = 0x000000ff;
 
 /// The parameters of mixer return.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RegisterDspMixerReturnParameters(pub bool);
 
 impl<O> MotuWhollyCacheableParamsOperation<RegisterDspMixerReturnParameters> for O
@@ -170,6 +171,7 @@ where
 
 /// State of mixer output.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RegisterDspMixerOutputState {
     pub volume: [u8; MIXER_COUNT],
     pub mute: [bool; MIXER_COUNT],
@@ -329,6 +331,7 @@ const MIXER_RETURN_ENABLE_OFFSET: usize = 0x0c18;
 
 /// State of sources in mixer entiry which can be operated as monaural channel.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RegisterDspMixerMonauralSourceEntry {
     /// The gain of source. The value is between 0x00 and 0x80.
     pub gain: Vec<u8>,
@@ -342,6 +345,7 @@ pub struct RegisterDspMixerMonauralSourceEntry {
 
 /// State of mixer sources which can be operated as monaural channel.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RegisterDspMixerMonauralSourceState(
     pub [RegisterDspMixerMonauralSourceEntry; MIXER_COUNT],
 );
@@ -574,6 +578,7 @@ const MIXER_STEREO_SOURCE_PAIR_COUNT: usize = MIXER_STEREO_SOURCE_COUNT / 2;
 
 /// State of sources in mixer entiry.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RegisterDspMixerStereoSourceEntry {
     /// The gain of mixer sources.
     pub gain: [u8; MIXER_STEREO_SOURCE_COUNT],
@@ -591,6 +596,7 @@ pub struct RegisterDspMixerStereoSourceEntry {
 
 /// State of mixer sources.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RegisterDspMixerStereoSourceState(pub [RegisterDspMixerStereoSourceEntry; MIXER_COUNT]);
 
 const MIXER_SOURCE_PAIRED_WIDTH_FLAG: u32 = 0x00400000;
@@ -888,6 +894,7 @@ const PHONE_VOLUME_OFFSET: usize = 0x0c10;
 
 /// State of output.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RegisterDspOutputState {
     /// The volume of master output.
     pub master_volume: u8,
@@ -983,6 +990,7 @@ where
 
 /// State of inputs in 828mkII and Traveler.
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RegisterDspLineInputState {
     /// The nominal level of input signal.
     pub level: Vec<NominalSignalLevel>,
@@ -1149,6 +1157,7 @@ const MONAURAL_INPUT_COUNT: usize = 10;
 
 /// State of input in Ultralite.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RegisterDspMonauralInputState {
     /// The gain of inputs.
     pub gain: [u8; MONAURAL_INPUT_COUNT],
@@ -1160,6 +1169,7 @@ const STEREO_INPUT_COUNT: usize = 6;
 
 /// State of input in Audio Express, and 4 pre.
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RegisterDspStereoInputState {
     /// The gain of inputs.
     pub gain: [u8; STEREO_INPUT_COUNT],
@@ -1224,10 +1234,13 @@ where
         timeout_ms: u32,
     ) -> Result<(), Error> {
         let mut quads = [0; (MONAURAL_INPUT_COUNT + 3) / 4];
-        quads.iter_mut().enumerate().try_for_each(|(i, quad)| {
-            let offset = INPUT_GAIN_INVERT_OFFSET + i * 4;
-            read_quad(req, node, offset as u32, timeout_ms).map(|val| *quad = val)
-        })?;
+        read_block(
+            req,
+            node,
+            INPUT_GAIN_INVERT_OFFSET as u32,
+            &mut quads,
+            timeout_ms,
+        )?;
 
         (0..MONAURAL_INPUT_COUNT).for_each(|i| {
             let pos = i / 4;
@@ -1652,6 +1665,7 @@ where
 
 /// The target of output metering.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RegisterDspMeterOutputTarget(pub usize);
 
 /// The specification of meter for output target.
@@ -10,6 +10,7 @@ use super::*;
 
 /// Signal source of sampling clock.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum V1ClkSrc {
     /// Internal.
     Internal,
@@ -33,6 +34,7 @@ impl Default for V1ClkSrc {
 
 /// Mode of optical interface.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum V1OptIfaceMode {
     Adat,
     Spdif,
@@ -260,6 +262,7 @@ const CLK_SRC_LABEL: &str = "clock-source-v1";
 
 /// The parameters of media and sampling clock.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Version1ClockParameters {
     /// The rate of media clock.
     pub rate: ClkRate,
@@ -354,6 +357,7 @@ where
 
 /// The parameters of monitor inputs.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Version1MonitorInputParameters(pub TargetPort);
 
 /// The trait for specification of monitor input.
@@ -469,6 +473,7 @@ impl MotuWhollyUpdatableParamsOperation<Version1MonitorInputParameters> for F828
 
 /// The parameter of optical interface for 828.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct F828OpticalIfaceParameters {
     /// The mode of signal in optical input interface.
     pub input_mode: V1OptIfaceMode,
@@ -551,6 +556,7 @@ impl MotuWhollyUpdatableParamsOperation<F828OpticalIfaceParameters> for F828Prot
 
 /// The parameter of stream input for 828.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct F828StreamInputParameters(pub bool);
 
 impl MotuWhollyCacheableParamsOperation<F828StreamInputParameters> for F828Protocol {
@@ -588,6 +594,7 @@ impl MotuWhollyUpdatableParamsOperation<F828StreamInputParameters> for F828Proto
 
 /// The parameter of output for 828.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct F828OutputParameters(pub bool);
 
 impl MotuWhollyCacheableParamsOperation<F828OutputParameters> for F828Protocol {
@@ -10,6 +10,7 @@ use super::{register_dsp::*, *};
 
 /// Signal source of sampling clock.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum V2ClkSrc {
     /// Internal.
     Internal,
@@ -35,6 +36,7 @@ impl Default for V2ClkSrc {
 
 /// The parameters of media and sampling clocks.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Version2ClockParameters {
     /// The rate of media clock.
     pub rate: ClkRate,
@@ -131,6 +133,7 @@ where
 
 /// Mode of optical interface.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum V2OptIfaceMode {
     None,
     Adat,
@@ -145,6 +148,7 @@ impl Default for V2OptIfaceMode {
 
 /// The parameters of optical interfaces.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Version2OpticalIfaceParameters {
     /// The mode of signal in optical input interface.
     pub input_mode: V2OptIfaceMode,
@@ -673,6 +677,7 @@ impl MotuRegisterDspMeterOutputTargetSpecification for TravelerProtocol {}
 
 /// State of inputs in Traveler.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TravelerMicInputState {
     /// The gain of microphone input. The value is between 0x00 and 0x35.
     pub gain: [u8; TravelerProtocol::MIC_INPUT_COUNT],
@@ -841,6 +846,7 @@ impl MotuRegisterDspMeterSpecification for UltraliteProtocol {
 
 /// The parameter of assignment to main output pair in Ultralite.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UltraliteMainAssign(pub TargetPort);
 
 const ULTRALITE_MAIN_ASSIGN_MASK: u32 = 0x000f0000;
@@ -10,6 +10,7 @@ use super::{command_dsp::*, register_dsp::*, *};
 
 /// Signal source of sampling clock.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum V3ClkSrc {
     /// Internal.
     Internal,
@@ -33,6 +34,7 @@ impl Default for V3ClkSrc {
 
 /// The parameters of media and sampling clock.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Version3ClockParameters {
     /// The rate of media clock.
     pub rate: ClkRate,
@@ -131,6 +133,7 @@ where
 
 /// The parameters of port assignment.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct V3PortAssignParameters {
     /// The main assignment.
     pub main: TargetPort,
@@ -218,6 +221,7 @@ where
 
 /// Mode of optical interface.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum V3OptIfaceMode {
     Disabled,
     Adat,
@@ -232,6 +236,7 @@ impl Default for V3OptIfaceMode {
 
 /// The parameters of optical input and output interfaces.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct V3OpticalIfaceParameters {
     /// The mode of input interfaces.
     pub input_modes: Vec<V3OptIfaceMode>,
@@ -1143,13 +1148,13 @@ const F896_MK3_METER_OUTPUT_PORTS: &[(TargetPort, usize)] = &[
     (TargetPort::OpticalB(7), 111),
 ];
 
+// The modes for ADAT B outputs and AES/EBU inputs/outputs are not available, since their values,
+// 0x04 and 0x05, exceed the field of register. The layout of register is not confirmed yet.
 const F896_MK3_LEVEL_METERS_PROGRAMMABLE_MODES: &[LevelMetersProgrammableMode] = &[
     LevelMetersProgrammableMode::AnalogOutput,
     LevelMetersProgrammableMode::AdatAInput,
     LevelMetersProgrammableMode::AdatAOutput,
     LevelMetersProgrammableMode::AdatBInput,
-    LevelMetersProgrammableMode::AdatBOutput,
-    LevelMetersProgrammableMode::AesEbuInputOutput,
 ];
 
 const F896_MK3_OFFSET_AES_EBU_RATE_CONVERTER: u32 = 0x0c90;
@@ -1160,6 +1165,7 @@ const F896_MK3_NOTIFY_FOOTSWITCH_MASK: u32 = 0x01000000;
 
 /// Mode of rate convert for AES/EBU input/output signals.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum F896mk3AesebuRateConvertMode {
     /// Not available.
     None,
@@ -2344,4 +2350,16 @@ mod test {
             H4preProtocol::OUTPUT_PORT_PAIR_POS.len()
         );
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn optical_iface_params_serde() {
+        let params = V3OpticalIfaceParameters {
+            input_modes: vec![V3OptIfaceMode::Adat, V3OptIfaceMode::Disabled],
+            output_modes: vec![V3OptIfaceMode::Spdif, V3OptIfaceMode::Adat],
+        };
+        let json = serde_json::to_string(&params).unwrap();
+        let target: V3OpticalIfaceParameters = serde_json::from_str(&json).unwrap();
+        assert_eq!(params, target);
+    }
 }
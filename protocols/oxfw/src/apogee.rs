@@ -1171,6 +1171,7 @@ impl AvcControl for ApogeeCmd {
     }
 
     fn parse_operands(&mut self, addr: &AvcAddr, operands: &[u8]) -> Result<(), AvcRespParseError> {
+        self.op.check_company_id(operands)?;
         AvcControl::parse_operands(&mut self.op, addr, operands)
     }
 }
@@ -1182,6 +1183,7 @@ impl AvcStatus for ApogeeCmd {
     }
 
     fn parse_operands(&mut self, addr: &AvcAddr, operands: &[u8]) -> Result<(), AvcRespParseError> {
+        self.op.check_company_id(operands)?;
         AvcStatus::parse_operands(&mut self.op, addr, operands)?;
         self.cmd
             .parse_variable(&self.op.data)
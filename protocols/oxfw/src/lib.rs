@@ -23,9 +23,9 @@ use {
     ta1394_avc_stream_format::*,
 };
 
-/// The implementation of AV/C transaction.
+/// The implementation of AV/C transaction. The transport is parameterized just for stub in tests.
 #[derive(Default, Debug)]
-pub struct OxfwAvc(FwFcp);
+pub struct OxfwAvc<T = FwFcp>(T);
 
 impl Ta1394Avc<Error> for OxfwAvc {
     fn transaction(&self, command_frame: &[u8], timeout_ms: u32) -> Result<Vec<u8>, Error> {
@@ -41,7 +41,12 @@ impl OxfwAvc {
     pub fn bind(&self, node: &impl IsA<FwNode>) -> Result<(), Error> {
         self.0.bind(node)
     }
+}
 
+impl<T> OxfwAvc<T>
+where
+    Self: Ta1394Avc<Error>,
+{
     /// Request AV/C control operation and wait for response.
     pub fn control<O: AvcOp + AvcControl>(
         &self,
@@ -61,6 +66,26 @@ impl OxfwAvc {
     ) -> Result<(), Error> {
         Ta1394Avc::<Error>::status(self, addr, op, timeout_ms).map_err(|err| from_avc_err(err))
     }
+
+    /// Request AV/C specific inquiry operation and wait for response.
+    pub fn specific_inquiry<O: AvcOp + AvcControl>(
+        &self,
+        addr: &AvcAddr,
+        op: &mut O,
+        timeout_ms: u32,
+    ) -> Result<(), Error> {
+        Ta1394Avc::<Error>::specific_inquiry(self, addr, op, timeout_ms).map_err(from_avc_err)
+    }
+
+    /// Request AV/C notify operation and wait for response.
+    pub fn notify<O: AvcOp + AvcNotify>(
+        &self,
+        addr: &AvcAddr,
+        op: &mut O,
+        timeout_ms: u32,
+    ) -> Result<(), Error> {
+        Ta1394Avc::<Error>::notify(self, addr, op, timeout_ms).map_err(from_avc_err)
+    }
 }
 
 fn from_avc_err(err: Ta1394AvcError<Error>) -> Error {
@@ -114,6 +139,27 @@ pub trait OxfwAudioFbSpecification {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OxfwOutputVolumeParams(pub Vec<i16>);
 
+impl OxfwOutputVolumeParams {
+    /// Convert the volumes into dB in the preferred order. The negative infinity and invalid
+    /// value are converted into `None`.
+    pub fn to_db_vec(&self) -> Vec<Option<f64>> {
+        self.0
+            .iter()
+            .map(|&vol| match vol {
+                VolumeData::VALUE_NEG_INFINITY | VolumeData::VALUE_INVALID => None,
+                _ => Some(vol as f64 / 256.0),
+            })
+            .collect()
+    }
+
+    /// Set 0 dB to all of volumes.
+    pub fn reset_to_zero_db(&mut self) {
+        self.0
+            .iter_mut()
+            .for_each(|vol| *vol = VolumeData::VALUE_ZERO);
+    }
+}
+
 impl<O, P> OxfwFcpParamsOperation<P, OxfwOutputVolumeParams> for O
 where
     O: OxfwAudioFbSpecification,
@@ -223,6 +269,152 @@ where
     }
 }
 
+/// Specification of audio function block for input gain.
+pub trait OxfwInputGainSpecification {
+    /// The numeric identifier of audio function block for input gain.
+    const INPUT_GAIN_FB_ID: u8;
+
+    /// List to map raw data into preferred data order.
+    const INPUT_CHANNEL_MAP: &'static [usize];
+
+    /// The minimum value of gain.
+    const GAIN_MIN: i16 = VolumeData::VALUE_NEG_INFINITY;
+    /// The maximum value of gain.
+    const GAIN_MAX: i16 = VolumeData::VALUE_ZERO;
+
+    /// Instantiate parameters for input gain.
+    fn create_input_gain_params() -> OxfwInputGainParams {
+        OxfwInputGainParams(vec![Default::default(); Self::INPUT_CHANNEL_MAP.len()])
+    }
+}
+
+/// Parameters of gain for input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OxfwInputGainParams(pub Vec<i16>);
+
+impl<O, P> OxfwFcpParamsOperation<P, OxfwInputGainParams> for O
+where
+    O: OxfwInputGainSpecification,
+    P: Ta1394Avc<Error>,
+{
+    fn cache(avc: &mut P, params: &mut OxfwInputGainParams, timeout_ms: u32) -> Result<(), Error> {
+        assert!(params.0.len() >= Self::INPUT_CHANNEL_MAP.len());
+        let mut op = AudioFeature::new(
+            Self::INPUT_GAIN_FB_ID,
+            CtlAttr::Current,
+            AudioCh::All,
+            FeatureCtl::Volume(VolumeData::new(params.0.len())),
+        );
+        avc.status(&AUDIO_SUBUNIT_0_ADDR, &mut op, timeout_ms)
+            .map_err(from_avc_err)?;
+        if let FeatureCtl::Volume(data) = op.ctl {
+            data.0
+                .iter()
+                .zip(Self::INPUT_CHANNEL_MAP)
+                .for_each(|(&gain, &pos)| {
+                    params.0[pos] = gain;
+                });
+        }
+        Ok(())
+    }
+}
+
+impl<O, P> OxfwFcpMutableParamsOperation<P, OxfwInputGainParams> for O
+where
+    O: OxfwInputGainSpecification,
+    P: Ta1394Avc<Error>,
+{
+    fn update(
+        avc: &mut P,
+        params: &OxfwInputGainParams,
+        prev: &mut OxfwInputGainParams,
+        timeout_ms: u32,
+    ) -> Result<(), Error> {
+        if params != prev {
+            if let Some((i, gain)) = params
+                .0
+                .iter()
+                .enumerate()
+                .find(|(_, gain)| !(Self::GAIN_MIN..=Self::GAIN_MAX).contains(gain))
+            {
+                let msg = format!("Invalid gain for input channel {}: {}", i, gain);
+                Err(Error::new(FileError::Inval, &msg))?;
+            }
+            let gains: Vec<i16> = Self::INPUT_CHANNEL_MAP
+                .iter()
+                .map(|&pos| params.0[pos])
+                .collect();
+            let mut op = AudioFeature::new(
+                Self::INPUT_GAIN_FB_ID,
+                CtlAttr::Current,
+                AudioCh::All,
+                FeatureCtl::Volume(VolumeData(gains)),
+            );
+            avc.control(&AUDIO_SUBUNIT_0_ADDR, &mut op, timeout_ms)
+                .map_err(from_avc_err)?;
+        }
+        prev.0.iter_mut().zip(&params.0).for_each(|(o, n)| *o = *n);
+        Ok(())
+    }
+}
+
+/// Specification of audio function block for input mute.
+pub trait OxfwInputMuteSpecification {
+    /// The numeric identifier of audio function block for input mute.
+    const INPUT_MUTE_FB_ID: u8;
+}
+
+/// Parameters of mute for input.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct OxfwInputMuteParams(pub bool);
+
+impl<O, P> OxfwFcpParamsOperation<P, OxfwInputMuteParams> for O
+where
+    O: OxfwInputMuteSpecification,
+    P: Ta1394Avc<Error>,
+{
+    fn cache(avc: &mut P, params: &mut OxfwInputMuteParams, timeout_ms: u32) -> Result<(), Error> {
+        let mut op = AudioFeature::new(
+            Self::INPUT_MUTE_FB_ID,
+            CtlAttr::Current,
+            AudioCh::Master,
+            FeatureCtl::Mute(vec![Default::default()]),
+        );
+        avc.status(&AUDIO_SUBUNIT_0_ADDR, &mut op, timeout_ms)
+            .map_err(from_avc_err)?;
+        if let FeatureCtl::Mute(data) = op.ctl {
+            params.0 = data[0]
+        }
+        Ok(())
+    }
+}
+
+impl<O, P> OxfwFcpMutableParamsOperation<P, OxfwInputMuteParams> for O
+where
+    O: OxfwInputMuteSpecification,
+    P: Ta1394Avc<Error>,
+{
+    fn update(
+        avc: &mut P,
+        params: &OxfwInputMuteParams,
+        prev: &mut OxfwInputMuteParams,
+        timeout_ms: u32,
+    ) -> Result<(), Error> {
+        if params != prev {
+            let mut op = AudioFeature::new(
+                Self::INPUT_MUTE_FB_ID,
+                CtlAttr::Current,
+                AudioCh::Master,
+                FeatureCtl::Mute(vec![params.0]),
+            );
+            avc.control(&AUDIO_SUBUNIT_0_ADDR, &mut op, timeout_ms)
+                .map_err(from_avc_err)?;
+        }
+        prev.0 = params.0;
+        Ok(())
+    }
+}
+
 /// Parameters for stream formats.
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct OxfwStreamFormatState {
@@ -277,7 +469,12 @@ where
 
                 op.index += 1;
                 if let Err(err) = avc.status(&AvcAddr::Unit, &mut op, timeout_ms) {
-                    if err == Ta1394AvcError::RespParse(AvcRespParseError::UnexpectedStatus) {
+                    if let Ta1394AvcError::RespParse(
+                        AvcRespParseError::Rejected
+                        | AvcRespParseError::NotImplemented
+                        | AvcRespParseError::UnexpectedStatus,
+                    ) = err
+                    {
                         break;
                     } else {
                         Err(from_avc_err(err))?;
@@ -406,3 +603,252 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use {super::*, crate::griffin::FirewaveProtocol, std::cell::RefCell};
+
+    // The stub of transport to return the response frame injected in advance.
+    #[derive(Default)]
+    struct TestAvc {
+        response_frame: RefCell<Vec<u8>>,
+        command_frame: RefCell<Vec<u8>>,
+    }
+
+    impl Ta1394Avc<Error> for TestAvc {
+        fn transaction(&self, command_frame: &[u8], _: u32) -> Result<Vec<u8>, Error> {
+            *self.command_frame.borrow_mut() = command_frame.to_vec();
+            let frame = self.response_frame.borrow().clone();
+            if frame.is_empty() {
+                Err(Error::new(FileError::Nxio, "no response"))
+            } else {
+                Ok(frame)
+            }
+        }
+    }
+
+    #[test]
+    fn output_volume_in_db() {
+        // The volumes in wire order.
+        let vols: [i16; 6] = [
+            0x0000,
+            -0x0100,
+            VolumeData::VALUE_NEG_INFINITY,
+            VolumeData::VALUE_INVALID,
+            -0x0080,
+            -0x7f00,
+        ];
+        let mut frame = vec![0x0c];
+        let mut op = AudioFeature::new(
+            FirewaveProtocol::VOLUME_FB_ID,
+            CtlAttr::Current,
+            AudioCh::All,
+            FeatureCtl::Volume(VolumeData::new(vols.len())),
+        );
+        frame.push(AUDIO_SUBUNIT_0_ADDR.into());
+        frame.push(AudioFeature::OPCODE);
+        let operands = AvcStatus::build_operands(&mut op, &AUDIO_SUBUNIT_0_ADDR).unwrap();
+        frame.extend_from_slice(&operands[..operands.len() - vols.len() * 2]);
+        vols.iter()
+            .for_each(|vol| frame.extend_from_slice(&vol.to_be_bytes()));
+
+        let mut avc = TestAvc::default();
+        *avc.response_frame.borrow_mut() = frame;
+
+        let mut params = FirewaveProtocol::create_output_volume_params();
+        FirewaveProtocol::cache(&mut avc, &mut params, 100).unwrap();
+        assert_eq!(
+            params.to_db_vec(),
+            vec![Some(0.0), Some(-1.0), Some(-0.5), Some(-127.0), None, None,]
+        );
+
+        params.reset_to_zero_db();
+        assert_eq!(params.to_db_vec(), vec![Some(0.0); vols.len()]);
+    }
+
+    #[test]
+    fn output_mute_params() {
+        let mut avc = TestAvc::default();
+
+        // IMPLEMENTED/STABLE response to status command.
+        *avc.response_frame.borrow_mut() = vec![
+            0x0c, 0x08, 0xb8, 0x81, 0x01, 0x10, 0x02, 0x00, 0x01, 0x01, 0x70,
+        ];
+        let mut params = OxfwOutputMuteParams::default();
+        FirewaveProtocol::cache(&mut avc, &mut params, 100).unwrap();
+        assert_eq!(
+            &*avc.command_frame.borrow(),
+            &[0x01, 0x08, 0xb8, 0x81, 0x01, 0x10, 0x02, 0x00, 0x01, 0x01, 0x60]
+        );
+        assert_eq!(params, OxfwOutputMuteParams(true));
+
+        // No control command without change.
+        avc.command_frame.borrow_mut().clear();
+        avc.response_frame.borrow_mut().clear();
+        let mut prev = params.clone();
+        FirewaveProtocol::update(&mut avc, &params, &mut prev, 100).unwrap();
+        assert!(avc.command_frame.borrow().is_empty());
+
+        // ACCEPTED response to control command.
+        *avc.response_frame.borrow_mut() = vec![
+            0x09, 0x08, 0xb8, 0x81, 0x01, 0x10, 0x02, 0x00, 0x01, 0x01, 0x60,
+        ];
+        let params = OxfwOutputMuteParams(false);
+        FirewaveProtocol::update(&mut avc, &params, &mut prev, 100).unwrap();
+        assert_eq!(
+            &*avc.command_frame.borrow(),
+            &[0x00, 0x08, 0xb8, 0x81, 0x01, 0x10, 0x02, 0x00, 0x01, 0x01, 0x60]
+        );
+        assert_eq!(prev, params);
+    }
+
+    struct TestInputProtocol;
+
+    impl OxfwInputGainSpecification for TestInputProtocol {
+        const INPUT_GAIN_FB_ID: u8 = 0x03;
+        const INPUT_CHANNEL_MAP: &'static [usize] = &[1, 0];
+    }
+
+    impl OxfwInputMuteSpecification for TestInputProtocol {
+        const INPUT_MUTE_FB_ID: u8 = 0x04;
+    }
+
+    #[test]
+    fn input_gain_params() {
+        let mut avc = TestAvc::default();
+
+        // IMPLEMENTED/STABLE response to status command.
+        *avc.response_frame.borrow_mut() = vec![
+            0x0c, 0x08, 0xb8, 0x81, 0x03, 0x10, 0x02, 0xff, 0x02, 0x04, 0xff, 0x00, 0xfe, 0x00,
+        ];
+        let mut params = TestInputProtocol::create_input_gain_params();
+        TestInputProtocol::cache(&mut avc, &mut params, 100).unwrap();
+        assert_eq!(
+            &*avc.command_frame.borrow(),
+            &[0x01, 0x08, 0xb8, 0x81, 0x03, 0x10, 0x02, 0xff, 0x02, 0x04, 0x7f, 0xff, 0x7f, 0xff]
+        );
+        assert_eq!(params, OxfwInputGainParams(vec![-0x200, -0x100]));
+
+        // ACCEPTED response to control command.
+        *avc.response_frame.borrow_mut() = vec![
+            0x09, 0x08, 0xb8, 0x81, 0x03, 0x10, 0x02, 0xff, 0x02, 0x04, 0xff, 0x00, 0x00, 0x00,
+        ];
+        let mut prev = params.clone();
+        params.0[0] = 0;
+        TestInputProtocol::update(&mut avc, &params, &mut prev, 100).unwrap();
+        assert_eq!(
+            &*avc.command_frame.borrow(),
+            &[0x00, 0x08, 0xb8, 0x81, 0x03, 0x10, 0x02, 0xff, 0x02, 0x04, 0xff, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(prev, params);
+
+        // The gain beyond the maximum is rejected before any command.
+        avc.command_frame.borrow_mut().clear();
+        let mut params = prev.clone();
+        params.0[1] = TestInputProtocol::GAIN_MAX + 1;
+        let err = TestInputProtocol::update(&mut avc, &params, &mut prev, 100).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Inval));
+        assert!(avc.command_frame.borrow().is_empty());
+        assert_ne!(prev, params);
+    }
+
+    #[test]
+    fn input_mute_params() {
+        let mut avc = TestAvc::default();
+
+        *avc.response_frame.borrow_mut() = vec![
+            0x0c, 0x08, 0xb8, 0x81, 0x04, 0x10, 0x02, 0x00, 0x01, 0x01, 0x70,
+        ];
+        let mut params = OxfwInputMuteParams::default();
+        TestInputProtocol::cache(&mut avc, &mut params, 100).unwrap();
+        assert_eq!(params, OxfwInputMuteParams(true));
+
+        // No control command without change.
+        avc.command_frame.borrow_mut().clear();
+        let mut prev = params.clone();
+        TestInputProtocol::update(&mut avc, &params, &mut prev, 100).unwrap();
+        assert!(avc.command_frame.borrow().is_empty());
+    }
+
+    #[derive(Default)]
+    struct TestOp(u8);
+
+    impl AvcOp for TestOp {
+        const OPCODE: u8 = 0x10;
+    }
+
+    impl AvcControl for TestOp {
+        fn build_operands(&mut self, _: &AvcAddr) -> Result<Vec<u8>, AvcCmdBuildError> {
+            Ok(vec![self.0])
+        }
+
+        fn parse_operands(
+            &mut self,
+            _: &AvcAddr,
+            operands: &[u8],
+        ) -> Result<(), AvcRespParseError> {
+            self.0 = operands[0];
+            Ok(())
+        }
+    }
+
+    impl AvcNotify for TestOp {
+        fn build_operands(&mut self, _: &AvcAddr) -> Result<Vec<u8>, AvcCmdBuildError> {
+            Ok(vec![self.0])
+        }
+
+        fn parse_operands(
+            &mut self,
+            _: &AvcAddr,
+            operands: &[u8],
+        ) -> Result<(), AvcRespParseError> {
+            self.0 = operands[0];
+            Ok(())
+        }
+    }
+
+    impl Ta1394Avc<Error> for OxfwAvc<TestAvc> {
+        fn transaction(&self, command_frame: &[u8], timeout_ms: u32) -> Result<Vec<u8>, Error> {
+            self.0.transaction(command_frame, timeout_ms)
+        }
+    }
+
+    #[test]
+    fn notify_with_stub() {
+        let avc = OxfwAvc(TestAvc::default());
+        let mut op = TestOp(0xff);
+
+        // CHANGED response.
+        *avc.0.response_frame.borrow_mut() = vec![0x0d, 0xff, 0x10, 0x5a];
+        avc.notify(&AvcAddr::Unit, &mut op, 100).unwrap();
+        assert_eq!(&*avc.0.command_frame.borrow(), &[0x03, 0xff, 0x10, 0xff]);
+        assert_eq!(op.0, 0x5a);
+
+        // REJECTED response.
+        *avc.0.response_frame.borrow_mut() = vec![0x0a, 0xff, 0x10, 0x5a];
+        let err = avc.notify(&AvcAddr::Unit, &mut op, 100).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Io));
+
+        avc.0.response_frame.borrow_mut().clear();
+        let err = avc.notify(&AvcAddr::Unit, &mut op, 100).unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Nxio));
+    }
+
+    #[test]
+    fn specific_inquiry_with_stub() {
+        let avc = OxfwAvc(TestAvc::default());
+        let mut op = TestOp(0x01);
+
+        // IMPLEMENTED/STABLE response.
+        *avc.0.response_frame.borrow_mut() = vec![0x0c, 0xff, 0x10, 0x01];
+        avc.specific_inquiry(&AvcAddr::Unit, &mut op, 100).unwrap();
+        assert_eq!(&*avc.0.command_frame.borrow(), &[0x02, 0xff, 0x10, 0x01]);
+
+        // NOT IMPLEMENTED response.
+        *avc.0.response_frame.borrow_mut() = vec![0x08, 0xff, 0x10, 0x01];
+        let err = avc
+            .specific_inquiry(&AvcAddr::Unit, &mut op, 100)
+            .unwrap_err();
+        assert_eq!(err.kind::<FileError>(), Some(FileError::Io));
+    }
+}
@@ -394,9 +394,7 @@ impl Ta1394Avc<Error> for TascamAvc {
             AvcControl::build_operands(op, addr).map_err(|err| Ta1394AvcError::CmdBuild(err))?;
         let command_frame =
             Self::compose_command_frame(AvcCmdType::Control, addr, O::OPCODE, &operands)?;
-        let response_frame = self
-            .transaction(&command_frame, timeout_ms)
-            .map_err(|cause| Ta1394AvcError::CommunicationFailure(cause))?;
+        let response_frame = self.final_transaction(&command_frame, timeout_ms)?;
         Self::detect_response_operands(&response_frame, addr, O::OPCODE)
             .and_then(|(rcode, operands)| {
                 let expected = if O::OPCODE != VendorDependent::OPCODE {
@@ -406,7 +404,7 @@ impl Ta1394Avc<Error> for TascamAvc {
                     AvcRespCode::ImplementedStable
                 };
                 if rcode != expected {
-                    Err(AvcRespParseError::UnexpectedStatus)
+                    Err(AvcRespParseError::from_unexpected_rcode(rcode))
                 } else {
                     AvcControl::parse_operands(op, addr, &operands)
                 }
@@ -467,5 +465,10 @@ mod test {
 
         let o = AvcControl::build_operands(&mut op, &AvcAddr::Unit).unwrap();
         assert_eq!(o, operands);
+
+        let mut op = TascamProto::new(VendorCmd::InputMode(0x01));
+        let operands = [0xff, 0xff, 0xff, 0x46, 0x49, 0x31, 0x12, 0x01];
+        AvcControl::parse_operands(&mut op, &AvcAddr::Unit, &operands).unwrap();
+        assert_eq!(op.op.data, operands[3..]);
     }
 }
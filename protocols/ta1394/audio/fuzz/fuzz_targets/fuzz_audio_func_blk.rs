@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022 Takashi Sakamoto
+
+//! Fuzz target to parse operands of AV/C Audio Subunit FUNCTION_BLOCK command for each type of
+//! function block, by both status and control commands. The parser should not panic.
+
+#![no_main]
+
+use {libfuzzer_sys::fuzz_target, ta1394_avc_audio::*, ta1394_avc_general::*};
+
+const FUNC_BLK_ID: u8 = 0x01;
+
+fn parse_status<O: AvcStatus>(op: &mut O, operands: &[u8]) {
+    let _ = AvcStatus::parse_operands(op, &AUDIO_SUBUNIT_0_ADDR, operands);
+}
+
+fn parse_control<O: AvcControl>(op: &mut O, operands: &[u8]) {
+    let _ = AvcControl::parse_operands(op, &AUDIO_SUBUNIT_0_ADDR, operands);
+}
+
+fuzz_target!(|operands: &[u8]| {
+    [CtlAttr::Current, CtlAttr::Minimum, CtlAttr::Maximum]
+        .iter()
+        .for_each(|&ctl_attr| {
+            let mut op = AudioSelector::new(FUNC_BLK_ID, ctl_attr, 0);
+            parse_status(&mut op, operands);
+            let mut op = AudioSelector::new(FUNC_BLK_ID, ctl_attr, 0);
+            parse_control(&mut op, operands);
+
+            let ctl = FeatureCtl::Mute(Default::default());
+            let mut op = AudioFeature::new(FUNC_BLK_ID, ctl_attr, AudioCh::Master, ctl.clone());
+            parse_status(&mut op, operands);
+            let mut op = AudioFeature::new(FUNC_BLK_ID, ctl_attr, AudioCh::Master, ctl);
+            parse_control(&mut op, operands);
+
+            let ctl = ProcessingCtl::Enable(false);
+            let mut op = AudioProcessing::new(
+                FUNC_BLK_ID,
+                ctl_attr,
+                0,
+                AudioCh::Master,
+                AudioCh::Master,
+                ctl.clone(),
+            );
+            parse_status(&mut op, operands);
+            let mut op = AudioProcessing::new(
+                FUNC_BLK_ID,
+                ctl_attr,
+                0,
+                AudioCh::Master,
+                AudioCh::Master,
+                ctl,
+            );
+            parse_control(&mut op, operands);
+        });
+});
@@ -55,6 +55,27 @@ impl AmdtpFdf {
             freq,
         }
     }
+
+    /// Instantiate for AM824 data without command-based rate control, if the frequency is
+    /// supported.
+    pub fn from_am824_frequency(freq: u32) -> Option<Self> {
+        match freq {
+            32000 | 44100 | 48000 | 88200 | 96000 | 176400 | 192000 => {
+                Some(Self::new(AmdtpEventType::Am824, false, freq))
+            }
+            _ => None,
+        }
+    }
+
+    /// The encoded frequency, if supported.
+    pub fn frequency(&self) -> Option<u32> {
+        Some(self.freq).filter(|&freq| freq > 0)
+    }
+
+    /// Whether the event is AM824 data.
+    pub fn is_am824(&self) -> bool {
+        self.ev_type == AmdtpEventType::Am824
+    }
 }
 
 impl From<&[u8]> for AmdtpFdf {
@@ -120,3 +141,45 @@ impl From<AmdtpFdf> for [u8; 3] {
         data
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn amdtpfdf_from_am824_frequency() {
+        // The frequencies supported by AM824 multi bit audio attribute in AV/C Stream Format
+        // Information Specification.
+        [
+            (22050, false),
+            (24000, false),
+            (32000, true),
+            (44100, true),
+            (48000, true),
+            (96000, true),
+            (176400, true),
+            (192000, true),
+        ]
+        .iter()
+        .for_each(|&(freq, supported)| {
+            let fdf = AmdtpFdf::from_am824_frequency(freq);
+            assert_eq!(fdf.is_some(), supported, "{}", freq);
+            if let Some(fdf) = fdf {
+                assert!(fdf.is_am824());
+                let raw: [u8; 3] = fdf.into();
+                let target = AmdtpFdf::from(&raw[..]);
+                assert_eq!(target, fdf);
+                assert_eq!(target.frequency(), Some(freq));
+            }
+        });
+
+        let fdf = AmdtpFdf::from_am824_frequency(88200).unwrap();
+        let raw: [u8; 3] = fdf.into();
+        assert_eq!(AmdtpFdf::from(&raw[..]).frequency(), Some(88200));
+
+        let raw: [u8; 3] = AmdtpFdf::new(AmdtpEventType::FloatingPoint, false, 12345).into();
+        let target = AmdtpFdf::from(&raw[..]);
+        assert!(!target.is_am824());
+        assert_eq!(target.frequency(), None);
+    }
+}
@@ -107,6 +107,21 @@ impl CtlAttr {
     }
 }
 
+impl CtlAttr {
+    /// Whether the attribute is to query capability in status command.
+    pub fn is_query(&self) -> bool {
+        matches!(
+            self,
+            Self::Resolution | Self::Minimum | Self::Maximum | Self::Default | Self::Duration
+        )
+    }
+
+    /// Whether the attribute is to change state in control command.
+    pub fn is_runtime(&self) -> bool {
+        matches!(self, Self::Current | Self::Move | Self::Delta)
+    }
+}
+
 impl std::fmt::Display for CtlAttr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let label = match self {
@@ -163,14 +178,21 @@ impl AudioFuncBlkCtl {
         Ok(ctl)
     }
 
-    fn to_raw(&self) -> Vec<u8> {
+    // The length of control data is expressed by single byte.
+    const DATA_LENGTH_MAX: usize = u8::MAX as usize;
+
+    fn to_raw(&self) -> Result<Vec<u8>, AvcCmdBuildError> {
+        if self.data.len() > Self::DATA_LENGTH_MAX {
+            Err(AvcCmdBuildError::InvalidOperands)?;
+        }
+
         let mut raw = Vec::with_capacity(Self::LENGTH_MIN);
         raw.push(self.selector);
         if self.data.len() > 0 {
             raw.push(self.data.len() as u8);
             raw.extend_from_slice(&self.data);
         }
-        raw
+        Ok(raw)
     }
 }
 
@@ -204,13 +226,18 @@ impl AudioFuncBlk {
             subunit_id: _,
         }) = addr
         {
+            // The length of selector includes the length field itself.
+            if self.audio_selector_data.len() >= u8::MAX as usize {
+                Err(AvcCmdBuildError::InvalidOperands)?;
+            }
+
             let mut operands = Vec::new();
             operands.push(self.func_blk_type.to_val());
             operands.push(self.func_blk_id);
             operands.push(self.ctl_attr.to_val());
             operands.push(1 + self.audio_selector_data.len() as u8);
             operands.extend_from_slice(&self.audio_selector_data);
-            operands.append(&mut self.ctl.to_raw());
+            operands.append(&mut self.ctl.to_raw()?);
             Ok(operands)
         } else {
             Err(AvcCmdBuildError::InvalidAddress)
@@ -287,6 +314,7 @@ pub struct AudioSelector {
 
 impl AudioSelector {
     const SELECTOR_CONTROL: u8 = 0x01;
+    const INPUT_PLUG_ID_WILDCARD: u8 = 0xff;
 
     pub fn new(func_blk_id: u8, ctl_attr: CtlAttr, input_plug_id: u8) -> Self {
         Self {
@@ -300,6 +328,25 @@ impl AudioSelector {
         }
     }
 
+    /// Query the number of inputs available for the function block, by status command for
+    /// maximum attribute with wildcard in input_plug_id field. The field in response has the
+    /// number of inputs minus one.
+    pub fn query_max_inputs<Avc: Ta1394Avc<E>, E: std::fmt::Display + Clone>(
+        avc: &Avc,
+        addr: &AvcAddr,
+        func_blk_id: u8,
+        timeout_ms: u32,
+    ) -> Result<u8, Ta1394AvcError<E>> {
+        let mut op = Self::new(func_blk_id, CtlAttr::Maximum, Self::INPUT_PLUG_ID_WILDCARD);
+        avc.status(addr, &mut op, timeout_ms)?;
+        if op.input_plug_id == Self::INPUT_PLUG_ID_WILDCARD {
+            let err = AvcRespParseError::UnexpectedOperands(4);
+            Err(Ta1394AvcError::RespParse(err))
+        } else {
+            Ok(op.input_plug_id + 1)
+        }
+    }
+
     fn build_func_blk(&mut self) -> Result<(), AvcCmdBuildError> {
         self.func_blk.audio_selector_data.clear();
         self.func_blk.audio_selector_data.push(self.input_plug_id);
@@ -313,6 +360,8 @@ impl AudioSelector {
             Err(AvcRespParseError::UnexpectedOperands(5))
         } else if self.func_blk.ctl.data.len() > 0 {
             Err(AvcRespParseError::UnexpectedOperands(7))
+        } else if self.func_blk.audio_selector_data.is_empty() {
+            Err(AvcRespParseError::TooShortResp(5))
         } else {
             self.input_plug_id = self.func_blk.audio_selector_data[0];
             Ok(())
@@ -669,9 +718,7 @@ impl GraphicEqualizerData {
                 .enumerate()
                 .filter(|(i, _)| (r[*offset + i / 8] & (1 << (i % 8)) > 0))
                 .for_each(|(_, gain)| {
-                    *gain = if r.len() < gain_index {
-                        None
-                    } else if r[gain_index] == Self::VALUE_INVALID {
+                    *gain = if r.len() <= gain_index || r[gain_index] == Self::VALUE_INVALID {
                         None
                     } else {
                         let val = Some(r[gain_index] as i8);
@@ -722,17 +769,54 @@ impl GraphicEqualizerData {
 pub struct DelayData(pub Vec<u16>);
 
 impl DelayData {
-    /// The invalid value of volume.
+    /// The invalid value of delay.
     pub const VALUE_INVALID: u16 = 0xffff;
-    /// The maximum value of volume expresses 1023.9687 ms.
+    /// The maximum value of delay expresses 1023.9375 ms.
     pub const VALUE_MAX: u16 = 0x7ffe;
-    /// The value of volume which expresses 0.0000 ms.
+    /// The value of delay which expresses 0.0000 ms.
     pub const VALUE_ZERO: u16 = 0;
 
+    // The value is in 1/32 ms unit.
+    const STEPS_PER_MS: f32 = 32.0;
+
     pub fn new(count: usize) -> Self {
         Self(vec![Self::VALUE_INVALID; count])
     }
 
+    /// Convert into milliseconds. The invalid value is converted into `f32::NAN`.
+    pub fn to_ms(&self) -> Vec<f32> {
+        self.0
+            .iter()
+            .map(|&val| {
+                if val == Self::VALUE_INVALID {
+                    f32::NAN
+                } else {
+                    val as f32 / Self::STEPS_PER_MS
+                }
+            })
+            .collect()
+    }
+
+    /// Convert from milliseconds, saturated between zero and the maximum value. `f32::NAN` is
+    /// converted into the invalid value.
+    pub fn from_ms(values: &[f32]) -> Self {
+        Self(
+            values
+                .iter()
+                .map(|&ms| {
+                    if ms.is_nan() {
+                        Self::VALUE_INVALID
+                    } else {
+                        (ms * Self::STEPS_PER_MS)
+                            .round()
+                            .max(Self::VALUE_ZERO as f32)
+                            .min(Self::VALUE_MAX as f32) as u16
+                    }
+                })
+                .collect(),
+        )
+    }
+
     fn from_raw<T: AsRef<[u8]>>(raw: &T) -> Self {
         Self(u16_vector_from_raw(raw.as_ref()))
     }
@@ -905,20 +989,26 @@ fn i8_vector_from_raw(raw: &[u8]) -> Vec<i8> {
 
 fn i16_from_raw(data: &[u8]) -> i16 {
     let mut doublet = [0; 2];
-    doublet.copy_from_slice(&data);
+    doublet.copy_from_slice(&data[..2]);
     i16::from_be_bytes(doublet)
 }
 
 impl FeatureCtl {
-    fn from_ctl(ctl: &AudioFuncBlkCtl) -> Self {
-        match ctl.selector {
+    fn from_ctl(ctl: &AudioFuncBlkCtl) -> Result<Self, AvcRespParseError> {
+        let ctl = match ctl.selector {
             Self::MUTE => Self::Mute(bool_vector_from_raw(&ctl.data)),
             Self::VOLUME => Self::Volume(VolumeData::from_raw(&ctl.data)),
+            Self::LR_BALANCE | Self::FR_BALANCE if ctl.data.len() < 2 => {
+                Err(AvcRespParseError::TooShortResp(9))?
+            }
             Self::LR_BALANCE => Self::LrBalance(LrBalanceData::from_raw(&ctl.data)),
             Self::FR_BALANCE => Self::FrBalance(FrBalanceData::from_raw(&ctl.data)),
             Self::BASS => Self::Bass(BassData::from_raw(&ctl.data)),
             Self::MID => Self::Mid(MidData::from_raw(&ctl.data)),
             Self::TREBLE => Self::Treble(TrebleData::from_raw(&ctl.data)),
+            Self::GRAPHIC_EQUALIZER if ctl.data.len() < GraphicEqualizerData::LENGTH_MIN => {
+                Err(AvcRespParseError::TooShortResp(15))?
+            }
             Self::GRAPHIC_EQUALIZER => {
                 Self::GraphicEqualizer(GraphicEqualizerData::from_raw(&ctl.data))
             }
@@ -929,11 +1019,12 @@ impl FeatureCtl {
             _ => {
                 let mut data = Vec::new();
                 data.push(ctl.selector);
-                data.push(1 + ctl.data.len() as u8);
+                data.push((1 + ctl.data.len()) as u8);
                 data.extend_from_slice(&ctl.data);
                 Self::Reserved(data)
             }
-        }
+        };
+        Ok(ctl)
     }
 }
 
@@ -985,6 +1076,33 @@ impl AudioCh {
     }
 }
 
+impl AudioCh {
+    /// Instantiate for the channel. The number of channel is zero-based, while the value in
+    /// the field is one-based.
+    pub fn from_channel_number(ch: u8) -> Self {
+        Self::Each(ch)
+    }
+
+    /// The zero-based number of channel, if the instance is for each channel.
+    pub fn channel_number(&self) -> Option<u8> {
+        match self {
+            Self::Each(ch) => Some(*ch),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for AudioCh {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Master => write!(f, "Master"),
+            Self::Each(ch) => write!(f, "Ch {}", *ch as usize + 1),
+            Self::Void => write!(f, "Void"),
+            Self::All => write!(f, "All"),
+        }
+    }
+}
+
 ///
 /// AV/C Audio Subunit FUNCTION_BLOCK command for Feature function block
 ///
@@ -1022,12 +1140,50 @@ impl AudioFeature {
         Ok(())
     }
 
+    /// Query the selectors of control supported by the function block, by specific inquiry
+    /// command for master channel with each selector from Mute (0x01) to Loudness (0x0c). The
+    /// selector is regarded as supported when the response is ImplementedStable.
+    pub fn query_supported_controls<Avc: Ta1394Avc<E>, E: std::fmt::Display + Clone>(
+        avc: &Avc,
+        addr: &AvcAddr,
+        func_blk_id: u8,
+        timeout_ms: u32,
+    ) -> Result<Vec<u8>, Ta1394AvcError<E>> {
+        let mut selectors = Vec::new();
+        for selector in FeatureCtl::MUTE..=FeatureCtl::LOUDNESS {
+            let mut op = AudioFuncBlk {
+                func_blk_type: AudioFuncBlkType::Feature,
+                func_blk_id,
+                ctl_attr: CtlAttr::Current,
+                audio_selector_data: vec![AudioCh::Master.to_val()],
+                ctl: AudioFuncBlkCtl {
+                    selector,
+                    data: Default::default(),
+                },
+            };
+            match avc.specific_inquiry(addr, &mut op, timeout_ms) {
+                Ok(_) => selectors.push(selector),
+                Err(Ta1394AvcError::RespParse(
+                    AvcRespParseError::NotImplemented
+                    | AvcRespParseError::Rejected
+                    | AvcRespParseError::UnexpectedStatus,
+                )) => (),
+                Err(err) => Err(err)?,
+            }
+        }
+        Ok(selectors)
+    }
+
     fn parse_func_blk(&mut self) -> Result<(), AvcRespParseError> {
+        if self.func_blk.audio_selector_data.is_empty() {
+            Err(AvcRespParseError::TooShortResp(5))?;
+        }
+
         let audio_ch_num = AudioCh::from_val(self.func_blk.audio_selector_data[0]);
         if audio_ch_num != self.audio_ch_num {
             Err(AvcRespParseError::UnexpectedOperands(7))
         } else {
-            self.ctl = FeatureCtl::from_ctl(&self.func_blk.ctl);
+            self.ctl = FeatureCtl::from_ctl(&self.func_blk.ctl)?;
             Ok(())
         }
     }
@@ -1061,6 +1217,133 @@ impl AvcControl for AudioFeature {
     }
 }
 
+/// The set of bass, mid, and treble controls in Feature function block(s).
+///
+/// The controls can be in the same function block or in separate function blocks, thus the
+/// identifiers of function block are given for the bass, mid, and treble controls respectively.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ToneControls {
+    /// The value of bass control, as described in `BassData`.
+    pub bass: i8,
+    /// The value of mid control, as described in `MidData`.
+    pub mid: i8,
+    /// The value of treble control, as described in `TrebleData`.
+    pub treble: i8,
+}
+
+impl ToneControls {
+    /// Read current values of the controls by three status commands. The operation aborts at the
+    /// first failure.
+    pub fn query<Avc: Ta1394Avc<E>, E: std::fmt::Display + Clone>(
+        avc: &Avc,
+        addr: &AvcAddr,
+        func_blk_ids: &[u8; 3],
+        audio_ch: AudioCh,
+        timeout_ms: u32,
+    ) -> Result<Self, Ta1394AvcError<E>> {
+        let mut ctls = Self::default();
+
+        let mut op = AudioFeature::new(
+            func_blk_ids[0],
+            CtlAttr::Current,
+            audio_ch,
+            FeatureCtl::Bass(BassData::new(1)),
+        );
+        avc.status(addr, &mut op, timeout_ms)?;
+        if let FeatureCtl::Bass(data) = op.ctl {
+            ctls.bass = data.0.first().copied().unwrap_or(BassData::VALUE_INVALID);
+        }
+
+        let mut op = AudioFeature::new(
+            func_blk_ids[1],
+            CtlAttr::Current,
+            audio_ch,
+            FeatureCtl::Mid(MidData::new(1)),
+        );
+        avc.status(addr, &mut op, timeout_ms)?;
+        if let FeatureCtl::Mid(data) = op.ctl {
+            ctls.mid = data.0.first().copied().unwrap_or(MidData::VALUE_INVALID);
+        }
+
+        let mut op = AudioFeature::new(
+            func_blk_ids[2],
+            CtlAttr::Current,
+            audio_ch,
+            FeatureCtl::Treble(TrebleData::new(1)),
+        );
+        avc.status(addr, &mut op, timeout_ms)?;
+        if let FeatureCtl::Treble(data) = op.ctl {
+            ctls.treble = data.0.first().copied().unwrap_or(TrebleData::VALUE_INVALID);
+        }
+
+        Ok(ctls)
+    }
+
+    /// Write the values of the controls by three control commands, in the order of bass, mid,
+    /// and treble. The operation aborts at the first failure, thus the former controls are
+    /// already changed.
+    pub fn update<Avc: Ta1394Avc<E>, E: std::fmt::Display + Clone>(
+        &self,
+        avc: &Avc,
+        addr: &AvcAddr,
+        func_blk_ids: &[u8; 3],
+        audio_ch: AudioCh,
+        timeout_ms: u32,
+    ) -> Result<(), Ta1394AvcError<E>> {
+        [
+            FeatureCtl::Bass(BassData(vec![self.bass])),
+            FeatureCtl::Mid(MidData(vec![self.mid])),
+            FeatureCtl::Treble(TrebleData(vec![self.treble])),
+        ]
+        .iter()
+        .zip(func_blk_ids)
+        .try_for_each(|(ctl, &func_blk_id)| {
+            let mut op = AudioFeature::new(func_blk_id, CtlAttr::Current, audio_ch, ctl.clone());
+            avc.control(addr, &mut op, timeout_ms)
+        })
+    }
+}
+
+/// The data of mode control in processing function block. The named modes are for Dolby Pro Logic
+/// processing, in the order of its specification.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProcessingMode {
+    /// Left, right, and center channels are decoded.
+    LeftRightCenter,
+    /// Left, right, and surround channels are decoded.
+    LeftRightSurround,
+    /// Left, right, center, and surround channels are decoded.
+    LeftRightCenterSurround,
+    /// The data in unknown layout or for the other mode.
+    Raw(Vec<u8>),
+}
+
+impl ProcessingMode {
+    const LEFT_RIGHT_CENTER: u8 = 0x01;
+    const LEFT_RIGHT_SURROUND: u8 = 0x02;
+    const LEFT_RIGHT_CENTER_SURROUND: u8 = 0x03;
+
+    /// Interpret the data of mode control.
+    pub fn from_bytes(data: &[u8]) -> Self {
+        match data {
+            [Self::LEFT_RIGHT_CENTER] => Self::LeftRightCenter,
+            [Self::LEFT_RIGHT_SURROUND] => Self::LeftRightSurround,
+            [Self::LEFT_RIGHT_CENTER_SURROUND] => Self::LeftRightCenterSurround,
+            _ => Self::Raw(data.to_vec()),
+        }
+    }
+
+    /// Generate the data of mode control.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::LeftRightCenter => vec![Self::LEFT_RIGHT_CENTER],
+            Self::LeftRightSurround => vec![Self::LEFT_RIGHT_SURROUND],
+            Self::LeftRightCenterSurround => vec![Self::LEFT_RIGHT_CENTER_SURROUND],
+            Self::Raw(data) => data.to_vec(),
+        }
+    }
+}
+
 /// The type of processing control.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ProcessingCtl {
@@ -1082,6 +1365,45 @@ impl ProcessingCtl {
     pub const NEG_INFINITY: i16 = 0x8000u16 as i16;
 }
 
+impl ProcessingCtl {
+    /// The state of enable control, if the control is for it.
+    pub fn is_enabled(&self) -> Option<bool> {
+        if let Self::Enable(enabled) = self {
+            Some(*enabled)
+        } else {
+            None
+        }
+    }
+
+    /// The data of mode control, if the control is for it.
+    pub fn mode_bytes(&self) -> Option<&[u8]> {
+        if let Self::Mode(data) = self {
+            Some(data)
+        } else {
+            None
+        }
+    }
+
+    /// Instantiate mode control for the mode.
+    pub fn from_mode(mode: &ProcessingMode) -> Self {
+        Self::Mode(mode.to_bytes())
+    }
+
+    /// The mode of mode control, if the control is for it.
+    pub fn mode(&self) -> Option<ProcessingMode> {
+        self.mode_bytes().map(ProcessingMode::from_bytes)
+    }
+
+    /// The entries of mixer control, if the control is for it.
+    pub fn mixer_entries(&self) -> Option<&[i16]> {
+        if let Self::Mixer(data) = self {
+            Some(data)
+        } else {
+            None
+        }
+    }
+}
+
 impl ProcessingCtl {
     fn to_ctl(&self) -> AudioFuncBlkCtl {
         match self {
@@ -1104,19 +1426,25 @@ impl ProcessingCtl {
         }
     }
 
-    fn from_ctl(ctl_blk: &AudioFuncBlkCtl) -> Self {
-        match ctl_blk.selector {
-            Self::ENABLE => Self::Enable(ctl_blk.data[0] == Self::TRUE),
+    fn from_ctl(ctl_blk: &AudioFuncBlkCtl) -> Result<Self, AvcRespParseError> {
+        let ctl = match ctl_blk.selector {
+            Self::ENABLE => {
+                if ctl_blk.data.is_empty() {
+                    Err(AvcRespParseError::TooShortResp(10))?;
+                }
+                Self::Enable(ctl_blk.data[0] == Self::TRUE)
+            }
             Self::MODE => Self::Mode(ctl_blk.data.to_vec()),
             Self::MIXER => Self::Mixer(i16_vector_from_raw(&ctl_blk.data)),
             _ => {
                 let mut data = Vec::new();
                 data.push(ctl_blk.selector);
-                data.push(1 + ctl_blk.data.len() as u8);
+                data.push((1 + ctl_blk.data.len()) as u8);
                 data.extend_from_slice(&ctl_blk.data);
                 Self::Reserved(data)
             }
-        }
+        };
+        Ok(ctl)
     }
 }
 
@@ -1174,6 +1502,10 @@ impl AudioProcessing {
     }
 
     fn parse_func_blk(&mut self) -> Result<(), AvcRespParseError> {
+        if self.func_blk.audio_selector_data.len() < 3 {
+            Err(AvcRespParseError::TooShortResp(7))?;
+        }
+
         if self.func_blk.audio_selector_data[0] != self.input_plug_id {
             Err(AvcRespParseError::UnexpectedOperands(7))?;
         }
@@ -1188,7 +1520,7 @@ impl AudioProcessing {
             Err(AvcRespParseError::UnexpectedOperands(9))?;
         }
 
-        self.ctl = ProcessingCtl::from_ctl(&self.func_blk.ctl);
+        self.ctl = ProcessingCtl::from_ctl(&self.func_blk.ctl)?;
         Ok(())
     }
 }
@@ -1223,7 +1555,256 @@ impl AvcControl for AudioProcessing {
 
 #[cfg(test)]
 mod test {
-    use crate::*;
+    use {crate::*, proptest::prelude::*};
+
+    struct TestAvc(Vec<u8>);
+
+    impl Ta1394Avc<String> for TestAvc {
+        fn transaction(&self, command_frame: &[u8], _: u32) -> Result<Vec<u8>, String> {
+            let mut frame = command_frame.to_vec();
+            // The selector of control follows to audio_selector_data.
+            let rcode = if self.0.contains(&command_frame[8]) {
+                AvcRespCode::ImplementedStable
+            } else {
+                AvcRespCode::NotImplemented
+            };
+            frame[0] = rcode.into();
+            Ok(frame)
+        }
+    }
+
+    struct TestSelectorAvc(u8);
+
+    impl Ta1394Avc<String> for TestSelectorAvc {
+        fn transaction(&self, command_frame: &[u8], _: u32) -> Result<Vec<u8>, String> {
+            assert_eq!(&command_frame[3..], &[0x80, 0x0a, 0x03, 0x02, 0xff, 0x01]);
+            let mut frame = command_frame.to_vec();
+            frame[0] = AvcRespCode::ImplementedStable.into();
+            frame[7] = self.0;
+            Ok(frame)
+        }
+    }
+
+    #[test]
+    fn selector_max_inputs() {
+        let avc = TestSelectorAvc(0x05);
+        let count =
+            AudioSelector::query_max_inputs(&avc, &AUDIO_SUBUNIT_0_ADDR, 0x0a, 100).unwrap();
+        assert_eq!(count, 6);
+
+        let avc = TestSelectorAvc(0xff);
+        let err =
+            AudioSelector::query_max_inputs(&avc, &AUDIO_SUBUNIT_0_ADDR, 0x0a, 100).unwrap_err();
+        assert_eq!(
+            err,
+            Ta1394AvcError::RespParse(AvcRespParseError::UnexpectedOperands(4))
+        );
+    }
+
+    #[derive(Default)]
+    struct TestToneAvc {
+        values: std::cell::RefCell<Vec<(u8, u8, u8)>>,
+        rejected_selector: Option<u8>,
+    }
+
+    impl Ta1394Avc<String> for TestToneAvc {
+        fn transaction(&self, command_frame: &[u8], _: u32) -> Result<Vec<u8>, String> {
+            // The identifier of function block, the selector of control, and the value.
+            let func_blk_id = command_frame[4];
+            let selector = command_frame[8];
+            let mut frame = command_frame.to_vec();
+            if Some(selector) == self.rejected_selector {
+                frame[0] = AvcRespCode::Rejected.into();
+            } else if command_frame[0] == u8::from(AvcCmdType::Control) {
+                frame[0] = AvcRespCode::Accepted.into();
+                self.values
+                    .borrow_mut()
+                    .push((func_blk_id, selector, command_frame[10]));
+            } else {
+                frame[0] = AvcRespCode::ImplementedStable.into();
+                frame[10] = self
+                    .values
+                    .borrow()
+                    .iter()
+                    .rev()
+                    .find(|(id, sel, _)| *id == func_blk_id && *sel == selector)
+                    .map(|(_, _, val)| *val)
+                    .unwrap_or(0x00);
+            }
+            Ok(frame)
+        }
+    }
+
+    #[test]
+    fn processing_mode_conversion() {
+        [
+            (ProcessingMode::LeftRightCenter, 0x01),
+            (ProcessingMode::LeftRightSurround, 0x02),
+            (ProcessingMode::LeftRightCenterSurround, 0x03),
+        ]
+        .iter()
+        .for_each(|(mode, val)| {
+            assert_eq!(&ProcessingMode::from_bytes(&[*val]), mode);
+            assert_eq!(mode.to_bytes(), vec![*val]);
+
+            let ctl = ProcessingCtl::from_mode(mode);
+            assert_eq!(ctl, ProcessingCtl::Mode(vec![*val]));
+            assert_eq!(ctl.mode().as_ref(), Some(mode));
+        });
+
+        // The data for unknown mode or in unknown layout is preserved as is.
+        [vec![0x00], vec![0x04], vec![0xde, 0xad], Vec::new()]
+            .iter()
+            .for_each(|data| {
+                let mode = ProcessingMode::from_bytes(data);
+                assert_eq!(mode, ProcessingMode::Raw(data.clone()));
+                assert_eq!(&mode.to_bytes(), data);
+
+                let ctl = ProcessingCtl::from_mode(&mode);
+                assert_eq!(ctl, ProcessingCtl::Mode(data.clone()));
+                assert_eq!(ctl.mode(), Some(mode));
+            });
+
+        assert_eq!(ProcessingCtl::Enable(true).mode(), None);
+    }
+
+    #[test]
+    fn processingctl_accessors() {
+        let ctl = ProcessingCtl::Enable(true);
+        assert_eq!(ctl.is_enabled(), Some(true));
+        assert_eq!(ctl.mode_bytes(), None);
+        assert_eq!(ctl.mixer_entries(), None);
+
+        let ctl = ProcessingCtl::Mode(vec![0x01, 0x02]);
+        assert_eq!(ctl.is_enabled(), None);
+        assert_eq!(ctl.mode_bytes(), Some(&[0x01, 0x02][..]));
+        assert_eq!(ctl.mixer_entries(), None);
+
+        let ctl = ProcessingCtl::Mixer(vec![-0x100, 0x7ffe]);
+        assert_eq!(ctl.is_enabled(), None);
+        assert_eq!(ctl.mode_bytes(), None);
+        assert_eq!(ctl.mixer_entries(), Some(&[-0x100, 0x7ffe][..]));
+
+        let ctl = ProcessingCtl::Reserved(vec![0xff, 0x01, 0x00]);
+        assert_eq!(ctl.is_enabled(), None);
+        assert_eq!(ctl.mode_bytes(), None);
+        assert_eq!(ctl.mixer_entries(), None);
+    }
+
+    #[test]
+    fn tone_controls() {
+        let avc = TestToneAvc::default();
+        let func_blk_ids = [0x01, 0x02, 0x03];
+        let ctls = ToneControls {
+            bass: -0x10,
+            mid: 0x20,
+            treble: 0x30,
+        };
+        ctls.update(
+            &avc,
+            &AUDIO_SUBUNIT_0_ADDR,
+            &func_blk_ids,
+            AudioCh::Master,
+            100,
+        )
+        .unwrap();
+        assert_eq!(
+            &*avc.values.borrow(),
+            &[(0x01, 0x05, 0xf0), (0x02, 0x06, 0x20), (0x03, 0x07, 0x30)]
+        );
+
+        let cached = ToneControls::query(
+            &avc,
+            &AUDIO_SUBUNIT_0_ADDR,
+            &func_blk_ids,
+            AudioCh::Master,
+            100,
+        )
+        .unwrap();
+        assert_eq!(cached, ctls);
+    }
+
+    #[test]
+    fn tone_controls_partial_failure() {
+        let avc = TestToneAvc {
+            rejected_selector: Some(0x06),
+            ..Default::default()
+        };
+        let func_blk_ids = [0x04; 3];
+        let ctls = ToneControls {
+            bass: 0x01,
+            mid: 0x02,
+            treble: 0x03,
+        };
+        let err = ctls
+            .update(
+                &avc,
+                &AUDIO_SUBUNIT_0_ADDR,
+                &func_blk_ids,
+                AudioCh::Each(0),
+                100,
+            )
+            .unwrap_err();
+        assert_eq!(err, Ta1394AvcError::RespParse(AvcRespParseError::Rejected));
+        // The bass is changed, while the treble is not.
+        assert_eq!(&*avc.values.borrow(), &[(0x04, 0x05, 0x01)]);
+
+        let err = ToneControls::query(
+            &avc,
+            &AUDIO_SUBUNIT_0_ADDR,
+            &func_blk_ids,
+            AudioCh::Each(0),
+            100,
+        )
+        .unwrap_err();
+        assert_eq!(err, Ta1394AvcError::RespParse(AvcRespParseError::Rejected));
+    }
+
+    #[test]
+    fn feature_supported_controls() {
+        let avc = TestAvc(vec![0x01, 0x02, 0x03, 0x0c]);
+        let selectors =
+            AudioFeature::query_supported_controls(&avc, &AUDIO_SUBUNIT_0_ADDR, 0x05, 100).unwrap();
+        assert_eq!(selectors, vec![0x01, 0x02, 0x03, 0x0c]);
+
+        let avc = TestAvc(Vec::new());
+        let selectors =
+            AudioFeature::query_supported_controls(&avc, &AUDIO_SUBUNIT_0_ADDR, 0x05, 100).unwrap();
+        assert_eq!(selectors, Vec::<u8>::new());
+
+        let err =
+            AudioFeature::query_supported_controls(&avc, &AvcAddr::Unit, 0x05, 100).unwrap_err();
+        assert_eq!(
+            err,
+            Ta1394AvcError::CmdBuild(AvcCmdBuildError::InvalidAddress)
+        );
+    }
+
+    #[test]
+    fn audioch_channel_number() {
+        let ch = AudioCh::from_channel_number(0);
+        assert_eq!(ch, AudioCh::Each(0));
+        assert_eq!(ch.to_val(), 1);
+        assert_eq!(AudioCh::from_val(ch.to_val()), ch);
+        assert_eq!(ch.channel_number(), Some(0));
+        assert_eq!(ch.to_string(), "Ch 1");
+
+        let ch = AudioCh::from_channel_number(0xfc);
+        assert_eq!(ch.to_val(), 0xfd);
+        assert_eq!(ch.channel_number(), Some(0xfc));
+        assert_eq!(ch.to_string(), "Ch 253");
+
+        [
+            (AudioCh::Master, "Master"),
+            (AudioCh::Void, "Void"),
+            (AudioCh::All, "All"),
+        ]
+        .iter()
+        .for_each(|(ch, label)| {
+            assert_eq!(ch.channel_number(), None);
+            assert_eq!(&ch.to_string(), label);
+        });
+    }
 
     #[test]
     fn func_blk_operands() {
@@ -1372,6 +1953,198 @@ mod test {
         assert_eq!(&op.ctl.data, &[]);
     }
 
+    #[test]
+    fn delay_data_in_ms() {
+        let data = DelayData(vec![
+            DelayData::VALUE_ZERO,
+            0x0020,
+            DelayData::VALUE_MAX,
+            DelayData::VALUE_INVALID,
+        ]);
+        let ms = data.to_ms();
+        assert_eq!(ms[0], 0.0);
+        assert_eq!(ms[1], 1.0);
+        assert_eq!(ms[2], 1023.9375);
+        assert!(ms[3].is_nan());
+        assert_eq!(DelayData::from_ms(&ms), data);
+
+        let data = DelayData::from_ms(&[-1.0, 0.5, 1023.9375, 2048.0, f32::INFINITY]);
+        assert_eq!(
+            data.0,
+            vec![
+                DelayData::VALUE_ZERO,
+                0x0010,
+                0x7ffe,
+                DelayData::VALUE_MAX,
+                DelayData::VALUE_MAX
+            ]
+        );
+    }
+
+    #[test]
+    fn ctlattr_classification() {
+        [
+            (CtlAttr::Resolution, true, false),
+            (CtlAttr::Minimum, true, false),
+            (CtlAttr::Maximum, true, false),
+            (CtlAttr::Default, true, false),
+            (CtlAttr::Duration, true, false),
+            (CtlAttr::Current, false, true),
+            (CtlAttr::Move, false, true),
+            (CtlAttr::Delta, false, true),
+            (CtlAttr::Reserved(0xff), false, false),
+        ]
+        .iter()
+        .for_each(|(attr, is_query, is_runtime)| {
+            assert_eq!(attr.is_query(), *is_query, "{}", attr);
+            assert_eq!(attr.is_runtime(), *is_runtime, "{}", attr);
+        });
+
+        (0..=u8::MAX).for_each(|val| {
+            let attr = CtlAttr::from_val(val);
+            assert!(!(attr.is_query() && attr.is_runtime()), "{}", attr);
+            if let CtlAttr::Reserved(_) = attr {
+                assert!(!attr.is_query() && !attr.is_runtime());
+            }
+        });
+    }
+
+    fn func_blk_type_strategy() -> impl Strategy<Value = AudioFuncBlkType> {
+        prop_oneof![
+            3 => Just(AudioFuncBlkType::Selector),
+            3 => Just(AudioFuncBlkType::Feature),
+            3 => Just(AudioFuncBlkType::Processing),
+            1 => any::<u8>().prop_map(AudioFuncBlkType::from_val),
+        ]
+    }
+
+    fn ctl_attr_strategy() -> impl Strategy<Value = CtlAttr> {
+        prop_oneof![
+            8 => prop::sample::select(vec![0x01, 0x02, 0x03, 0x04, 0x08, 0x10, 0x18, 0x19])
+                .prop_map(CtlAttr::from_val),
+            1 => any::<u8>().prop_map(CtlAttr::from_val),
+        ]
+    }
+
+    fn func_blk_ctl_strategy() -> impl Strategy<Value = AudioFuncBlkCtl> {
+        (any::<u8>(), prop::collection::vec(any::<u8>(), 0..=250))
+            .prop_map(|(selector, data)| AudioFuncBlkCtl { selector, data })
+    }
+
+    proptest! {
+        #[test]
+        fn audiofuncblk_operands_round_trip(
+            func_blk_type in func_blk_type_strategy(),
+            func_blk_id in any::<u8>(),
+            ctl_attr in ctl_attr_strategy(),
+            audio_selector_data in prop::collection::vec(any::<u8>(), 0..=250),
+            ctl in func_blk_ctl_strategy(),
+        ) {
+            let mut op = AudioFuncBlk {
+                func_blk_type,
+                func_blk_id,
+                ctl_attr,
+                audio_selector_data,
+                ctl,
+            };
+            let operands = AvcStatus::build_operands(&mut op, &AUDIO_SUBUNIT_0_ADDR).unwrap();
+
+            let mut parsed = AudioFuncBlk {
+                func_blk_type,
+                func_blk_id,
+                ctl_attr,
+                ..Default::default()
+            };
+            AvcStatus::parse_operands(&mut parsed, &AUDIO_SUBUNIT_0_ADDR, &operands).unwrap();
+            prop_assert_eq!(parsed, op);
+        }
+    }
+
+    #[test]
+    fn audiofuncblk_too_long_data() {
+        let mut op = AudioFuncBlk {
+            func_blk_type: AudioFuncBlkType::Feature,
+            func_blk_id: 0x01,
+            ctl_attr: CtlAttr::Current,
+            audio_selector_data: vec![0x01],
+            ctl: AudioFuncBlkCtl {
+                selector: 0x02,
+                data: vec![0; 255],
+            },
+        };
+        let operands = AvcControl::build_operands(&mut op, &AUDIO_SUBUNIT_0_ADDR).unwrap();
+        assert_eq!(operands[6], 0xff);
+
+        op.ctl.data.push(0);
+        assert_eq!(
+            AvcControl::build_operands(&mut op, &AUDIO_SUBUNIT_0_ADDR),
+            Err(AvcCmdBuildError::InvalidOperands)
+        );
+
+        op.ctl.data.clear();
+        op.audio_selector_data = vec![0; 254];
+        let operands = AvcStatus::build_operands(&mut op, &AUDIO_SUBUNIT_0_ADDR).unwrap();
+        assert_eq!(operands[3], 0xff);
+
+        op.audio_selector_data.push(0);
+        assert_eq!(
+            AvcStatus::build_operands(&mut op, &AUDIO_SUBUNIT_0_ADDR),
+            Err(AvcCmdBuildError::InvalidOperands)
+        );
+    }
+
+    #[test]
+    fn audiofuncblk_short_operands() {
+        // Selector function block without input plug.
+        let operands = [0x80, 0x01, 0x10, 0x01, 0x01];
+        let mut op = AudioSelector::new(0x01, CtlAttr::Current, 0x00);
+        assert_eq!(
+            AvcStatus::parse_operands(&mut op, &AUDIO_SUBUNIT_0_ADDR, &operands),
+            Err(AvcRespParseError::TooShortResp(5))
+        );
+
+        // Feature function block without audio channel.
+        let operands = [0x81, 0x01, 0x10, 0x01, 0x01];
+        let ctl = FeatureCtl::Mute(Default::default());
+        let mut op = AudioFeature::new(0x01, CtlAttr::Current, AudioCh::Master, ctl);
+        assert_eq!(
+            AvcStatus::parse_operands(&mut op, &AUDIO_SUBUNIT_0_ADDR, &operands),
+            Err(AvcRespParseError::TooShortResp(5))
+        );
+
+        // Feature function block with truncated data of LR balance.
+        let operands = [0x81, 0x01, 0x10, 0x02, 0x00, 0x03, 0x01, 0x00];
+        let ctl = FeatureCtl::LrBalance(LrBalanceData(0));
+        let mut op = AudioFeature::new(0x01, CtlAttr::Current, AudioCh::Master, ctl);
+        assert_eq!(
+            AvcControl::parse_operands(&mut op, &AUDIO_SUBUNIT_0_ADDR, &operands),
+            Err(AvcRespParseError::TooShortResp(9))
+        );
+
+        // Processing function block with lack of channels.
+        let operands = [0x82, 0x01, 0x10, 0x02, 0x00, 0x01, 0x01, 0x00];
+        let ctl = ProcessingCtl::Enable(false);
+        let mut op = AudioProcessing::new(
+            0x01,
+            CtlAttr::Current,
+            0x00,
+            AudioCh::Master,
+            AudioCh::Master,
+            ctl,
+        );
+        assert_eq!(
+            AvcStatus::parse_operands(&mut op, &AUDIO_SUBUNIT_0_ADDR, &operands),
+            Err(AvcRespParseError::TooShortResp(7))
+        );
+
+        // Processing function block without data of enable control.
+        let operands = [0x82, 0x01, 0x10, 0x04, 0x00, 0x00, 0x00, 0x01];
+        assert_eq!(
+            AvcStatus::parse_operands(&mut op, &AUDIO_SUBUNIT_0_ADDR, &operands),
+            Err(AvcRespParseError::TooShortResp(10))
+        );
+    }
+
     #[test]
     fn avcaudioselector_operands() {
         let mut op = AudioSelector::new(0xe5, CtlAttr::Duration, 0x28);
@@ -1392,23 +2165,23 @@ mod test {
     #[test]
     fn featurectl_from() {
         let ctl = FeatureCtl::Mute(vec![false, true, false]);
-        assert_eq!(ctl, FeatureCtl::from_ctl(&ctl.to_ctl()));
+        assert_eq!(Ok(ctl.clone()), FeatureCtl::from_ctl(&ctl.to_ctl()));
 
         let data = VolumeData(vec![0x1234, 0x3456, 0x789a]);
         let ctl = FeatureCtl::Volume(data);
-        assert_eq!(ctl, FeatureCtl::from_ctl(&ctl.to_ctl()));
+        assert_eq!(Ok(ctl.clone()), FeatureCtl::from_ctl(&ctl.to_ctl()));
 
         let ctl = FeatureCtl::LrBalance(LrBalanceData(-123));
-        assert_eq!(ctl, FeatureCtl::from_ctl(&ctl.to_ctl()));
+        assert_eq!(Ok(ctl.clone()), FeatureCtl::from_ctl(&ctl.to_ctl()));
 
         let ctl = FeatureCtl::FrBalance(FrBalanceData(321));
-        assert_eq!(ctl, FeatureCtl::from_ctl(&ctl.to_ctl()));
+        assert_eq!(Ok(ctl.clone()), FeatureCtl::from_ctl(&ctl.to_ctl()));
 
         let ctl = FeatureCtl::Bass(BassData(vec![10, -10, 20, -20]));
-        assert_eq!(ctl, FeatureCtl::from_ctl(&ctl.to_ctl()));
+        assert_eq!(Ok(ctl.clone()), FeatureCtl::from_ctl(&ctl.to_ctl()));
 
         let ctl = FeatureCtl::Mid(MidData(vec![30, -30, -40, 40]));
-        assert_eq!(ctl, FeatureCtl::from_ctl(&ctl.to_ctl()));
+        assert_eq!(Ok(ctl.clone()), FeatureCtl::from_ctl(&ctl.to_ctl()));
 
         let data = GraphicEqualizerData {
             ansi_band_gains: [
@@ -1479,25 +2252,25 @@ mod test {
             ],
         };
         let ctl = FeatureCtl::GraphicEqualizer(data);
-        assert_eq!(ctl, FeatureCtl::from_ctl(&ctl.to_ctl()));
+        assert_eq!(Ok(ctl.clone()), FeatureCtl::from_ctl(&ctl.to_ctl()));
 
         let ctl = FeatureCtl::Treble(TrebleData(vec![50, 60, -70, -80]));
-        assert_eq!(ctl, FeatureCtl::from_ctl(&ctl.to_ctl()));
+        assert_eq!(Ok(ctl.clone()), FeatureCtl::from_ctl(&ctl.to_ctl()));
 
         let ctl = FeatureCtl::AutomaticGain(vec![false, true, false]);
-        assert_eq!(ctl, FeatureCtl::from_ctl(&ctl.to_ctl()));
+        assert_eq!(Ok(ctl.clone()), FeatureCtl::from_ctl(&ctl.to_ctl()));
 
         let ctl = FeatureCtl::Delay(DelayData(vec![0x1234, 0x3456, 0x789a]));
-        assert_eq!(ctl, FeatureCtl::from_ctl(&ctl.to_ctl()));
+        assert_eq!(Ok(ctl.clone()), FeatureCtl::from_ctl(&ctl.to_ctl()));
 
         let ctl = FeatureCtl::BassBoost(vec![true, false, true]);
-        assert_eq!(ctl, FeatureCtl::from_ctl(&ctl.to_ctl()));
+        assert_eq!(Ok(ctl.clone()), FeatureCtl::from_ctl(&ctl.to_ctl()));
 
         let ctl = FeatureCtl::Loudness(vec![false, true, false]);
-        assert_eq!(ctl, FeatureCtl::from_ctl(&ctl.to_ctl()));
+        assert_eq!(Ok(ctl.clone()), FeatureCtl::from_ctl(&ctl.to_ctl()));
 
         let ctl = FeatureCtl::Reserved(vec![0xff, 0x04, 0xad, 0xbe, 0xef]);
-        assert_eq!(ctl, FeatureCtl::from_ctl(&ctl.to_ctl()));
+        assert_eq!(Ok(ctl.clone()), FeatureCtl::from_ctl(&ctl.to_ctl()));
     }
 
     #[test]
@@ -1531,13 +2304,13 @@ mod test {
     #[test]
     fn processingctl_from() {
         let ctl = ProcessingCtl::Enable(true);
-        assert_eq!(ctl, ProcessingCtl::from_ctl(&ctl.to_ctl()));
+        assert_eq!(Ok(ctl.clone()), ProcessingCtl::from_ctl(&ctl.to_ctl()));
 
         let ctl = ProcessingCtl::Mode(vec![0xde, 0xad, 0xbe, 0xef]);
-        assert_eq!(ctl, ProcessingCtl::from_ctl(&ctl.to_ctl()));
+        assert_eq!(Ok(ctl.clone()), ProcessingCtl::from_ctl(&ctl.to_ctl()));
 
         let ctl = ProcessingCtl::Mixer(vec![-73, -157]);
-        assert_eq!(ctl, ProcessingCtl::from_ctl(&ctl.to_ctl()));
+        assert_eq!(Ok(ctl.clone()), ProcessingCtl::from_ctl(&ctl.to_ctl()));
     }
 
     #[test]
@@ -57,6 +57,15 @@ impl SignalUnitAddr {
     }
 }
 
+impl std::fmt::Display for SignalUnitAddr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Isoc(plug_id) => write!(f, "Unit/PCR-{}", plug_id),
+            Self::Ext(plug_id) => write!(f, "Unit/Ext-{}", plug_id),
+        }
+    }
+}
+
 /// Address of plug in subunit.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct SignalSubunitAddr {
@@ -96,6 +105,33 @@ impl SignalSubunitAddr {
     }
 }
 
+impl std::fmt::Display for SignalSubunitAddr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.subunit.subunit_type {
+            AvcSubunitType::Monitor => write!(f, "Monitor"),
+            AvcSubunitType::Audio => write!(f, "Audio"),
+            AvcSubunitType::Printer => write!(f, "Printer"),
+            AvcSubunitType::Disc => write!(f, "Disc"),
+            AvcSubunitType::Tape => write!(f, "Tape"),
+            AvcSubunitType::Tuner => write!(f, "Tuner"),
+            AvcSubunitType::Ca => write!(f, "CA"),
+            AvcSubunitType::Camera => write!(f, "Camera"),
+            AvcSubunitType::Panel => write!(f, "Panel"),
+            AvcSubunitType::BulletinBoard => write!(f, "BulletinBoard"),
+            AvcSubunitType::CameraStorage => write!(f, "CameraStorage"),
+            AvcSubunitType::Music => write!(f, "Music"),
+            AvcSubunitType::VendorUnique => write!(f, "VendorUnique"),
+            AvcSubunitType::Extended => write!(f, "Extended"),
+            AvcSubunitType::Reserved(val) => write!(f, "Reserved-0x{:02x}", val),
+        }?;
+        // The identifier of subunit is omitted for the first subunit.
+        if self.subunit.subunit_id > 0 {
+            write!(f, "-{}", self.subunit.subunit_id)?;
+        }
+        write!(f, "/{}", self.plug_id)
+    }
+}
+
 /// Address of plug for signal source or destination.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum SignalAddr {
@@ -154,6 +190,15 @@ impl SignalAddr {
     }
 }
 
+impl std::fmt::Display for SignalAddr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unit(a) => a.fmt(f),
+            Self::Subunit(a) => a.fmt(f),
+        }
+    }
+}
+
 /// AV/C SIGNAL SOURCE command
 ///
 /// Described in clause 7.1.1 SIGNAL SOURCE control command format.
@@ -175,6 +220,11 @@ impl SignalSource {
         }
     }
 
+    /// The name of source, for display; e.g. the item of enumerated element.
+    pub fn source_name(&self) -> String {
+        self.src.to_string()
+    }
+
     fn build_operands(&self, for_status: bool) -> Result<Vec<u8>, AvcCmdBuildError> {
         let mut operands = Vec::new();
         operands.push(0xff);
@@ -264,6 +314,34 @@ mod test {
         assert_eq!(raw, addr.to_raw());
     }
 
+    #[test]
+    fn signaladdr_display() {
+        assert_eq!(SignalAddr::new_for_isoc_unit(0).to_string(), "Unit/PCR-0");
+        assert_eq!(SignalAddr::new_for_ext_unit(1).to_string(), "Unit/Ext-1");
+        assert_eq!(
+            SignalAddr::new_for_subunit(AvcSubunitType::Audio, 0, 0).to_string(),
+            "Audio/0"
+        );
+        assert_eq!(
+            SignalAddr::new_for_subunit(AvcSubunitType::Music, 0, 0).to_string(),
+            "Music/0"
+        );
+        assert_eq!(
+            SignalAddr::new_for_subunit(AvcSubunitType::Music, 1, 2).to_string(),
+            "Music-1/2"
+        );
+        assert_eq!(
+            SignalAddr::new_for_subunit(AvcSubunitType::Reserved(0x10), 0, 3).to_string(),
+            "Reserved-0x10/3"
+        );
+
+        let op = SignalSource {
+            src: SignalAddr::new_for_ext_unit(2),
+            dst: SignalAddr::new_for_subunit(AvcSubunitType::Music, 0, 1),
+        };
+        assert_eq!(op.source_name(), "Unit/Ext-2");
+    }
+
     #[test]
     fn signalsource_operands() {
         let operands = [0x00, 0x2e, 0x1c, 0xff, 0x05];
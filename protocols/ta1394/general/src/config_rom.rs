@@ -3,7 +3,7 @@
 
 //! Typical data layout of Configuration ROM for AV/C devices defined by 1394 Trading Association.
 
-use ieee1212_config_rom::*;
+use {ieee1212_config_rom::*, std::convert::TryFrom};
 
 /// The data of vendor.
 #[derive(Clone, Debug)]
@@ -79,3 +79,78 @@ fn detect_desc_text<'a>(entries: &'a [Entry], key_type: KeyType) -> Option<(u32,
 
     None
 }
+
+/// Retrieve the name of vendor in the content of Configuration ROM.
+pub fn vendor_name(rom: &[u8]) -> Option<String> {
+    let config_rom = ConfigRom::try_from(rom).ok()?;
+    let vendor = config_rom.get_vendor()?;
+    Some(vendor.vendor_name.to_string())
+}
+
+/// Retrieve the name of model in the content of Configuration ROM.
+pub fn model_name(rom: &[u8]) -> Option<String> {
+    let config_rom = ConfigRom::try_from(rom).ok()?;
+    let model = config_rom.get_model()?;
+    Some(model.model_name.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn text_leaf(text: &str) -> Vec<u32> {
+        let mut literal = text.as_bytes().to_vec();
+        literal.resize((literal.len() / 4 + 1) * 4, 0);
+        let mut quads: Vec<u32> = literal
+            .chunks_exact(4)
+            .map(|raw| {
+                let mut quadlet = [0; 4];
+                quadlet.copy_from_slice(raw);
+                u32::from_be_bytes(quadlet)
+            })
+            .collect();
+        quads.insert(0, 0);
+        quads.insert(0, 0);
+        quads.insert(0, (quads.len() as u32) << 16);
+        quads
+    }
+
+    fn build_config_rom() -> Vec<u8> {
+        let vendor_leaf = text_leaf("Vendor");
+        let model_leaf = text_leaf("Model");
+
+        let mut quads = vec![
+            // Bus information block.
+            0x04040000,
+            0x31333934,
+            0xf000b273,
+            0x08002851,
+            0x0100014a,
+            // Root directory.
+            0x00030000,
+            0x03123456,
+            0x81000007,
+            0xd1000001,
+            // Unit directory.
+            0x00040000,
+            0x1200a02d,
+            0x13010001,
+            0x17abcdef,
+            0x81000000 | (1 + vendor_leaf.len() as u32),
+        ];
+        quads.extend_from_slice(&vendor_leaf);
+        quads.extend_from_slice(&model_leaf);
+
+        quads.iter().flat_map(|quad| quad.to_be_bytes()).collect()
+    }
+
+    #[test]
+    fn vendor_and_model_names() {
+        let rom = build_config_rom();
+        assert_eq!(vendor_name(&rom).as_deref(), Some("Vendor"));
+        assert_eq!(model_name(&rom).as_deref(), Some("Model"));
+
+        assert_eq!(vendor_name(&rom[..40]), None);
+        assert_eq!(model_name(&rom[..40]), None);
+    }
+}
@@ -0,0 +1,555 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022 Takashi Sakamoto
+
+//! A set of AV/C commands and data for descriptor mechanism.
+//!
+//! The module includes AV/C commands to open and read descriptor, as well as helpers to parse
+//! information blocks in the descriptor. The commands are described in clause "9.6 OPEN
+//! DESCRIPTOR command" and "9.7 READ DESCRIPTOR command" of AV/C Digital Interface Command Set
+//! General Specification Version 4.1.
+
+use super::*;
+
+/// The specifier of descriptor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DescriptorSpecifier {
+    /// The (sub)unit identifier descriptor.
+    SubunitIdentifier,
+    /// The other descriptor, with the whole bytes of specifier including its type.
+    Other(Vec<u8>),
+}
+
+impl Default for DescriptorSpecifier {
+    fn default() -> Self {
+        Self::SubunitIdentifier
+    }
+}
+
+impl DescriptorSpecifier {
+    const SUBUNIT_IDENTIFIER: u8 = 0x00;
+
+    fn to_raw(&self) -> Vec<u8> {
+        match self {
+            Self::SubunitIdentifier => vec![Self::SUBUNIT_IDENTIFIER],
+            Self::Other(raw) => raw.clone(),
+        }
+    }
+}
+
+/// The subfunction of OPEN DESCRIPTOR command.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OpenDescriptorSubfunction {
+    /// Close the descriptor.
+    Close,
+    /// Open the descriptor for read access.
+    ReadOpen,
+    /// Open the descriptor for write access.
+    WriteOpen,
+    Reserved(u8),
+}
+
+impl Default for OpenDescriptorSubfunction {
+    fn default() -> Self {
+        Self::Close
+    }
+}
+
+impl OpenDescriptorSubfunction {
+    const CLOSE: u8 = 0x00;
+    const READ_OPEN: u8 = 0x01;
+    const WRITE_OPEN: u8 = 0x03;
+}
+
+impl From<u8> for OpenDescriptorSubfunction {
+    fn from(val: u8) -> Self {
+        match val {
+            Self::CLOSE => Self::Close,
+            Self::READ_OPEN => Self::ReadOpen,
+            Self::WRITE_OPEN => Self::WriteOpen,
+            _ => Self::Reserved(val),
+        }
+    }
+}
+
+impl From<OpenDescriptorSubfunction> for u8 {
+    fn from(subfunc: OpenDescriptorSubfunction) -> Self {
+        match subfunc {
+            OpenDescriptorSubfunction::Close => OpenDescriptorSubfunction::CLOSE,
+            OpenDescriptorSubfunction::ReadOpen => OpenDescriptorSubfunction::READ_OPEN,
+            OpenDescriptorSubfunction::WriteOpen => OpenDescriptorSubfunction::WRITE_OPEN,
+            OpenDescriptorSubfunction::Reserved(val) => val,
+        }
+    }
+}
+
+/// AV/C OPEN DESCRIPTOR command.
+///
+/// Described in clause "9.6 OPEN DESCRIPTOR command".
+#[derive(Debug, Default)]
+pub struct OpenDescriptor {
+    pub specifier: DescriptorSpecifier,
+    pub subfunction: OpenDescriptorSubfunction,
+}
+
+impl OpenDescriptor {
+    pub fn new(specifier: &DescriptorSpecifier, subfunction: OpenDescriptorSubfunction) -> Self {
+        Self {
+            specifier: specifier.clone(),
+            subfunction,
+        }
+    }
+}
+
+impl AvcOp for OpenDescriptor {
+    const OPCODE: u8 = 0x08;
+}
+
+impl AvcControl for OpenDescriptor {
+    fn build_operands(&mut self, _: &AvcAddr) -> Result<Vec<u8>, AvcCmdBuildError> {
+        let mut operands = self.specifier.to_raw();
+        operands.push(self.subfunction.into());
+        operands.push(0x00);
+        Ok(operands)
+    }
+
+    fn parse_operands(&mut self, _: &AvcAddr, operands: &[u8]) -> Result<(), AvcRespParseError> {
+        let specifier = self.specifier.to_raw();
+        let len = specifier.len();
+        if operands.len() < len + 2 {
+            Err(AvcRespParseError::TooShortResp(len + 2))
+        } else if operands[..len] != specifier[..] {
+            Err(AvcRespParseError::UnexpectedOperands(0))
+        } else {
+            self.subfunction = OpenDescriptorSubfunction::from(operands[len]);
+            Ok(())
+        }
+    }
+}
+
+/// The status of READ DESCRIPTOR command.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ReadDescriptorStatus {
+    /// The whole data is read.
+    Complete,
+    /// More data is left to read.
+    MoreToRead,
+    /// The length of data in command is larger than the descriptor.
+    DataLengthTooLarge,
+    Reserved(u8),
+}
+
+impl Default for ReadDescriptorStatus {
+    fn default() -> Self {
+        Self::Reserved(0xff)
+    }
+}
+
+impl ReadDescriptorStatus {
+    const COMPLETE: u8 = 0x10;
+    const MORE_TO_READ: u8 = 0x11;
+    const DATA_LENGTH_TOO_LARGE: u8 = 0x12;
+}
+
+impl From<u8> for ReadDescriptorStatus {
+    fn from(val: u8) -> Self {
+        match val {
+            Self::COMPLETE => Self::Complete,
+            Self::MORE_TO_READ => Self::MoreToRead,
+            Self::DATA_LENGTH_TOO_LARGE => Self::DataLengthTooLarge,
+            _ => Self::Reserved(val),
+        }
+    }
+}
+
+impl From<ReadDescriptorStatus> for u8 {
+    fn from(status: ReadDescriptorStatus) -> Self {
+        match status {
+            ReadDescriptorStatus::Complete => ReadDescriptorStatus::COMPLETE,
+            ReadDescriptorStatus::MoreToRead => ReadDescriptorStatus::MORE_TO_READ,
+            ReadDescriptorStatus::DataLengthTooLarge => ReadDescriptorStatus::DATA_LENGTH_TOO_LARGE,
+            ReadDescriptorStatus::Reserved(val) => val,
+        }
+    }
+}
+
+/// AV/C READ DESCRIPTOR command.
+///
+/// Described in clause "9.7 READ DESCRIPTOR command".
+#[derive(Debug, Default)]
+pub struct ReadDescriptor {
+    pub specifier: DescriptorSpecifier,
+    pub status: ReadDescriptorStatus,
+    /// The offset in descriptor to read.
+    pub address: u16,
+    /// The length of data to read. Zero means to read as much as possible.
+    pub length: u16,
+    /// The data in response.
+    pub data: Vec<u8>,
+}
+
+impl ReadDescriptor {
+    pub fn new(specifier: &DescriptorSpecifier, address: u16, length: u16) -> Self {
+        Self {
+            specifier: specifier.clone(),
+            address,
+            length,
+            ..Default::default()
+        }
+    }
+}
+
+impl AvcOp for ReadDescriptor {
+    const OPCODE: u8 = 0x09;
+}
+
+impl AvcControl for ReadDescriptor {
+    fn build_operands(&mut self, _: &AvcAddr) -> Result<Vec<u8>, AvcCmdBuildError> {
+        let mut operands = self.specifier.to_raw();
+        operands.push(0xff);
+        operands.push(0x00);
+        operands.extend_from_slice(&self.length.to_be_bytes());
+        operands.extend_from_slice(&self.address.to_be_bytes());
+        Ok(operands)
+    }
+
+    fn parse_operands(&mut self, _: &AvcAddr, operands: &[u8]) -> Result<(), AvcRespParseError> {
+        let specifier = self.specifier.to_raw();
+        let len = specifier.len();
+        if operands.len() < len + 6 {
+            Err(AvcRespParseError::TooShortResp(len + 6))?;
+        }
+
+        if operands[..len] != specifier[..] {
+            Err(AvcRespParseError::UnexpectedOperands(0))?;
+        }
+
+        self.status = ReadDescriptorStatus::from(operands[len]);
+
+        let mut doublet = [0; 2];
+        doublet.copy_from_slice(&operands[(len + 2)..(len + 4)]);
+        let length = u16::from_be_bytes(doublet) as usize;
+
+        let data = &operands[(len + 6)..];
+        if data.len() < length {
+            Err(AvcRespParseError::TooShortResp(len + 6 + length))?;
+        }
+        self.data = data[..length].to_vec();
+
+        Ok(())
+    }
+}
+
+/// The trait to read whole data of descriptor by a sequence of AV/C transactions.
+pub trait AvcDescriptorRead<T: std::fmt::Display + Clone>: Ta1394Avc<T> {
+    /// Open the descriptor, read whole data of it, then close it. The descriptor is closed even
+    /// if failing to read.
+    fn read_descriptor(
+        &self,
+        addr: &AvcAddr,
+        specifier: &DescriptorSpecifier,
+        timeout_ms: u32,
+    ) -> Result<Vec<u8>, Ta1394AvcError<T>> {
+        let mut op = OpenDescriptor::new(specifier, OpenDescriptorSubfunction::ReadOpen);
+        self.control(addr, &mut op, timeout_ms)?;
+
+        let res = read_descriptor_data(self, addr, specifier, timeout_ms);
+
+        let mut op = OpenDescriptor::new(specifier, OpenDescriptorSubfunction::Close);
+        self.control(addr, &mut op, timeout_ms)?;
+
+        res
+    }
+}
+
+impl<T: std::fmt::Display + Clone, A: Ta1394Avc<T>> AvcDescriptorRead<T> for A {}
+
+fn read_descriptor_data<T, A>(
+    avc: &A,
+    addr: &AvcAddr,
+    specifier: &DescriptorSpecifier,
+    timeout_ms: u32,
+) -> Result<Vec<u8>, Ta1394AvcError<T>>
+where
+    T: std::fmt::Display + Clone,
+    A: Ta1394Avc<T> + ?Sized,
+{
+    // Header of frame, specifier, status, reserved, length, and address.
+    let chunk = A::FRAME_SIZE - 3 - specifier.to_raw().len() - 6;
+
+    let mut data = Vec::new();
+    loop {
+        let mut op = ReadDescriptor::new(specifier, data.len() as u16, chunk as u16);
+        avc.control(addr, &mut op, timeout_ms)?;
+        data.extend_from_slice(&op.data);
+
+        if op.status != ReadDescriptorStatus::MoreToRead || op.data.is_empty() {
+            break Ok(data);
+        }
+    }
+}
+
+/// The information block in descriptor.
+///
+/// Described in clause "7.5 Information block" of AV/C Digital Interface Command Set General
+/// Specification Version 4.1.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AvcInfoBlock {
+    pub block_type: u16,
+    pub primary_fields: Vec<u8>,
+    /// The information blocks in secondary fields.
+    pub nested: Vec<AvcInfoBlock>,
+}
+
+impl AvcInfoBlock {
+    /// The type of raw text information block.
+    pub const RAW_TEXT: u16 = 0x000a;
+    /// The type of name information block.
+    pub const NAME: u16 = 0x000b;
+
+    /// Parse a sequence of information blocks.
+    pub fn parse_blocks(raw: &[u8]) -> Result<Vec<Self>, AvcRespParseError> {
+        let mut blocks = Vec::new();
+        let mut pos = 0;
+        while pos < raw.len() {
+            let (block, len) = Self::parse(&raw[pos..]).map_err(|err| err.add_offset(pos))?;
+            blocks.push(block);
+            pos += len;
+        }
+        Ok(blocks)
+    }
+
+    fn parse(raw: &[u8]) -> Result<(Self, usize), AvcRespParseError> {
+        if raw.len() < 6 {
+            Err(AvcRespParseError::TooShortResp(6))?;
+        }
+
+        let mut doublet = [0; 2];
+        doublet.copy_from_slice(&raw[..2]);
+        let compound_length = u16::from_be_bytes(doublet) as usize;
+        if raw.len() < 2 + compound_length {
+            Err(AvcRespParseError::TooShortResp(2 + compound_length))?;
+        }
+
+        doublet.copy_from_slice(&raw[2..4]);
+        let block_type = u16::from_be_bytes(doublet);
+
+        doublet.copy_from_slice(&raw[4..6]);
+        let primary_fields_length = u16::from_be_bytes(doublet) as usize;
+        if compound_length < 4 + primary_fields_length {
+            Err(AvcRespParseError::UnexpectedOperands(4))?;
+        }
+
+        let primary_fields = raw[6..(6 + primary_fields_length)].to_vec();
+        let nested = Self::parse_blocks(&raw[(6 + primary_fields_length)..(2 + compound_length)])
+            .map_err(|err| err.add_offset(6 + primary_fields_length))?;
+
+        let block = Self {
+            block_type,
+            primary_fields,
+            nested,
+        };
+        Ok((block, 2 + compound_length))
+    }
+
+    /// The text in raw text information block. The text is terminated by null character or the
+    /// end of primary fields.
+    pub fn raw_text(&self) -> Option<String> {
+        if self.block_type == Self::RAW_TEXT {
+            let text = self
+                .primary_fields
+                .split(|&b| b == 0x00)
+                .next()
+                .unwrap_or_default();
+            Some(String::from_utf8_lossy(text).to_string())
+        } else {
+            None
+        }
+    }
+
+    /// The name in nested name information block.
+    pub fn name(&self) -> Option<String> {
+        self.nested
+            .iter()
+            .filter(|block| block.block_type == Self::NAME)
+            .flat_map(|block| block.nested.iter())
+            .find_map(|block| block.raw_text())
+    }
+
+    /// Collect names of information blocks with the type, including nested blocks.
+    pub fn collect_names(blocks: &[Self], block_type: u16) -> Vec<String> {
+        blocks.iter().fold(Vec::new(), |mut names, block| {
+            if block.block_type == block_type {
+                if let Some(name) = block.name() {
+                    names.push(name);
+                }
+            }
+            names.append(&mut Self::collect_names(&block.nested, block_type));
+            names
+        })
+    }
+}
+
+/// Retrieve the subunit dependent information in the data of subunit identifier descriptor.
+///
+/// Described in clause "7.2 Subunit identifier descriptor".
+pub fn subunit_dependent_info(descriptor: &[u8]) -> Result<&[u8], AvcRespParseError> {
+    if descriptor.len() < 8 {
+        Err(AvcRespParseError::TooShortResp(8))?;
+    }
+
+    let size_of_list_id = descriptor[3] as usize;
+    let mut doublet = [0; 2];
+    doublet.copy_from_slice(&descriptor[6..8]);
+    let number_of_root_object_lists = u16::from_be_bytes(doublet) as usize;
+
+    let pos = 8 + size_of_list_id * number_of_root_object_lists;
+    if descriptor.len() < pos + 2 {
+        Err(AvcRespParseError::TooShortResp(pos + 2))?;
+    }
+
+    doublet.copy_from_slice(&descriptor[pos..(pos + 2)]);
+    let length = u16::from_be_bytes(doublet) as usize;
+    if descriptor.len() < pos + 2 + length {
+        Err(AvcRespParseError::TooShortResp(pos + 2 + length))?;
+    }
+
+    Ok(&descriptor[(pos + 2)..(pos + 2 + length)])
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, std::cell::RefCell};
+
+    // The subunit identifier descriptor of music subunit with two music plug info blocks.
+    const DESCRIPTOR: &[u8] = &[
+        0x00, 0x4c, // descriptor_length
+        0x00, // generation_ID
+        0x02, // size_of_list_ID
+        0x02, // size_of_object_ID
+        0x02, // size_of_object_position
+        0x00, 0x01, // number_of_root_object_lists
+        0x00, 0x10, // root_object_list_ID
+        0x00, 0x40, // subunit_dependent_information_length
+        // Music plug info block.
+        0x00, 0x1e, 0x81, 0x0a, 0x00, 0x02, 0x00, 0x00, //
+        // Name info block.
+        0x00, 0x16, 0x00, 0x0b, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, //
+        // Raw text info block, without null termination.
+        0x00, 0x0c, 0x00, 0x0a, 0x00, 0x08, b'A', b'n', b'a', b'l', b'o', b'g', b' ', b'1', //
+        // Music plug info block.
+        0x00, 0x1e, 0x81, 0x0a, 0x00, 0x02, 0x00, 0x01, //
+        // Name info block.
+        0x00, 0x16, 0x00, 0x0b, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, //
+        // Raw text info block, with null termination.
+        0x00, 0x0c, 0x00, 0x0a, 0x00, 0x08, b'S', b'/', b'P', b'D', b'I', b'F', 0x00, 0x00, //
+        0x00, 0x00, // manufacturer_dependent_information_length
+    ];
+
+    #[test]
+    fn open_descriptor_operands() {
+        let mut op = OpenDescriptor::new(
+            &DescriptorSpecifier::SubunitIdentifier,
+            OpenDescriptorSubfunction::ReadOpen,
+        );
+        let operands = AvcControl::build_operands(&mut op, &AvcAddr::Unit).unwrap();
+        assert_eq!(&operands, &[0x00, 0x01, 0x00]);
+
+        AvcControl::parse_operands(&mut op, &AvcAddr::Unit, &[0x00, 0x00, 0x00]).unwrap();
+        assert_eq!(op.subfunction, OpenDescriptorSubfunction::Close);
+
+        let err = AvcControl::parse_operands(&mut op, &AvcAddr::Unit, &[0x10, 0x00, 0x00]);
+        assert_eq!(err, Err(AvcRespParseError::UnexpectedOperands(0)));
+    }
+
+    #[test]
+    fn read_descriptor_operands() {
+        let mut op = ReadDescriptor::new(&DescriptorSpecifier::SubunitIdentifier, 0x0123, 0x04);
+        let operands = AvcControl::build_operands(&mut op, &AvcAddr::Unit).unwrap();
+        assert_eq!(&operands, &[0x00, 0xff, 0x00, 0x00, 0x04, 0x01, 0x23]);
+
+        let operands = [
+            0x00, 0x11, 0x00, 0x00, 0x04, 0x01, 0x23, 0xde, 0xad, 0xbe, 0xef,
+        ];
+        AvcControl::parse_operands(&mut op, &AvcAddr::Unit, &operands).unwrap();
+        assert_eq!(op.status, ReadDescriptorStatus::MoreToRead);
+        assert_eq!(&op.data, &[0xde, 0xad, 0xbe, 0xef]);
+
+        let err = AvcControl::parse_operands(&mut op, &AvcAddr::Unit, &operands[..10]);
+        assert_eq!(err, Err(AvcRespParseError::TooShortResp(11)));
+    }
+
+    #[test]
+    fn music_plug_names() {
+        let info = subunit_dependent_info(DESCRIPTOR).unwrap();
+        let blocks = AvcInfoBlock::parse_blocks(info).unwrap();
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].block_type, 0x810a);
+        assert_eq!(&blocks[0].primary_fields, &[0x00, 0x00]);
+        assert_eq!(blocks[0].name(), Some("Analog 1".to_string()));
+        assert_eq!(blocks[1].name(), Some("S/PDIF".to_string()));
+
+        let names = AvcInfoBlock::collect_names(&blocks, 0x810a);
+        assert_eq!(names, vec!["Analog 1".to_string(), "S/PDIF".to_string()]);
+
+        let err = AvcInfoBlock::parse_blocks(&info[..0x30]);
+        assert_eq!(err, Err(AvcRespParseError::TooShortResp(0x40)));
+    }
+
+    #[derive(Default)]
+    struct TestAvc {
+        descriptor: Vec<u8>,
+        opened: RefCell<bool>,
+        reads: RefCell<usize>,
+    }
+
+    impl Ta1394Avc<String> for TestAvc {
+        // To split the descriptor into several chunks.
+        const FRAME_SIZE: usize = 0x20;
+
+        fn transaction(&self, command_frame: &[u8], _: u32) -> Result<Vec<u8>, String> {
+            let mut frame = command_frame.to_vec();
+            frame[0] = AvcRespCode::Accepted.into();
+            match command_frame[2] {
+                OpenDescriptor::OPCODE => {
+                    *self.opened.borrow_mut() = command_frame[4] == 0x01;
+                }
+                ReadDescriptor::OPCODE => {
+                    if !*self.opened.borrow() {
+                        frame[0] = AvcRespCode::Rejected.into();
+                        return Ok(frame);
+                    }
+                    *self.reads.borrow_mut() += 1;
+
+                    let length = u16::from_be_bytes([frame[6], frame[7]]) as usize;
+                    let address = u16::from_be_bytes([frame[8], frame[9]]) as usize;
+                    let end = std::cmp::min(address + length, self.descriptor.len());
+                    let data = &self.descriptor[address..end];
+                    frame[4] = if end < self.descriptor.len() {
+                        0x11
+                    } else {
+                        0x10
+                    };
+                    frame[6..8].copy_from_slice(&(data.len() as u16).to_be_bytes());
+                    frame.extend_from_slice(data);
+                }
+                _ => unreachable!(),
+            }
+            Ok(frame)
+        }
+    }
+
+    #[test]
+    fn read_descriptor_with_stub() {
+        let avc = TestAvc {
+            descriptor: DESCRIPTOR.to_vec(),
+            ..Default::default()
+        };
+        let addr = AvcAddr::Subunit(AvcAddrSubunit::new(AvcSubunitType::Music, 0));
+        let data = avc
+            .read_descriptor(&addr, &DescriptorSpecifier::SubunitIdentifier, 100)
+            .unwrap();
+        assert_eq!(&data, DESCRIPTOR);
+        assert_eq!(*avc.reads.borrow(), 4);
+        assert!(!*avc.opened.borrow());
+    }
+}
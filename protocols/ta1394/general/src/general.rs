@@ -124,6 +124,17 @@ impl SubunitInfo {
             ..Default::default()
         }
     }
+
+    /// The list of subunit addresses in the page, expanded by the maximum ID of each entry.
+    pub fn subunits(&self) -> Vec<AvcAddrSubunit> {
+        self.entries
+            .iter()
+            .flat_map(|entry| {
+                (0..=entry.maximum_id)
+                    .map(move |subunit_id| AvcAddrSubunit::new(entry.subunit_type, subunit_id))
+            })
+            .collect()
+    }
 }
 
 impl AvcOp for SubunitInfo {
@@ -147,8 +158,8 @@ impl AvcStatus for SubunitInfo {
     }
 
     fn parse_operands(&mut self, _: &AvcAddr, operands: &[u8]) -> Result<(), AvcRespParseError> {
-        if operands.len() < 4 {
-            Err(AvcRespParseError::TooShortResp(4))
+        if operands.len() < 5 {
+            Err(AvcRespParseError::TooShortResp(5))
         } else {
             self.page = (operands[0] >> Self::PAGE_SHIFT) & Self::PAGE_MASK;
             self.extension_code =
@@ -211,6 +222,18 @@ impl VendorDependent {
         }
     }
 
+    /// Check whether the response is addressed to the same company as the command. Some units
+    /// put the other value into the field of response, thus the check is left to callers.
+    pub fn check_company_id(&self, operands: &[u8]) -> Result<(), AvcRespParseError> {
+        if operands.len() < 3 {
+            Err(AvcRespParseError::TooShortResp(3))
+        } else if operands[0..3] != self.company_id {
+            Err(AvcRespParseError::UnexpectedOperands(0))
+        } else {
+            Ok(())
+        }
+    }
+
     fn parse_operands(&mut self, operands: &[u8]) -> Result<(), AvcRespParseError> {
         if operands.len() > 3 {
             self.company_id.copy_from_slice(&operands[0..3]);
@@ -246,6 +269,92 @@ impl AvcStatus for VendorDependent {
     }
 }
 
+/// The state of power.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PowerState {
+    /// Powered on.
+    On,
+    /// Powered off, or in standby.
+    Off,
+}
+
+impl Default for PowerState {
+    fn default() -> Self {
+        Self::On
+    }
+}
+
+impl PowerState {
+    const ON: u8 = 0x70;
+    const OFF: u8 = 0x60;
+    const STATUS: u8 = 0x7f;
+
+    fn to_val(self) -> u8 {
+        match self {
+            Self::On => Self::ON,
+            Self::Off => Self::OFF,
+        }
+    }
+
+    fn from_val(val: u8) -> Option<Self> {
+        match val {
+            Self::ON => Some(Self::On),
+            Self::OFF => Some(Self::Off),
+            _ => None,
+        }
+    }
+}
+
+/// AV/C POWER command.
+///
+/// Some units do not allow to be powered off, and reject the control command.
+#[derive(Debug, Default)]
+pub struct UnitPower {
+    pub state: PowerState,
+}
+
+impl UnitPower {
+    pub fn new(state: PowerState) -> Self {
+        Self { state }
+    }
+}
+
+impl AvcOp for UnitPower {
+    const OPCODE: u8 = 0xb2;
+}
+
+impl AvcControl for UnitPower {
+    fn build_operands(&mut self, _: &AvcAddr) -> Result<Vec<u8>, AvcCmdBuildError> {
+        Ok(vec![self.state.to_val()])
+    }
+
+    fn parse_operands(&mut self, _: &AvcAddr, operands: &[u8]) -> Result<(), AvcRespParseError> {
+        if operands.is_empty() {
+            Err(AvcRespParseError::TooShortResp(1))
+        } else if operands[0] != self.state.to_val() {
+            Err(AvcRespParseError::UnexpectedOperands(0))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl AvcStatus for UnitPower {
+    fn build_operands(&mut self, _: &AvcAddr) -> Result<Vec<u8>, AvcCmdBuildError> {
+        Ok(vec![PowerState::STATUS])
+    }
+
+    fn parse_operands(&mut self, _: &AvcAddr, operands: &[u8]) -> Result<(), AvcRespParseError> {
+        if operands.is_empty() {
+            Err(AvcRespParseError::TooShortResp(1))
+        } else {
+            PowerState::from_val(operands[0])
+                .map(|state| self.state = state)
+                .ok_or(AvcRespParseError::UnexpectedOperands(0))
+        }
+    }
+}
+
 /// The data of unit plugs for isochronous and external inputs/outputs.
 #[derive(Debug)]
 pub struct PlugInfoUnitIsocExtData {
@@ -622,6 +731,48 @@ mod test {
         );
     }
 
+    #[test]
+    fn unitinfo_bebob_resp() {
+        // The response from BridgeCo. BeBoB unit.
+        let operands = [0x07, 0xff, 0x00, 0x07, 0xf5];
+        let mut op = UnitInfo::new();
+        AvcStatus::parse_operands(&mut op, &AvcAddr::Unit, &operands).unwrap();
+        assert_eq!(op.unit_type, AvcSubunitType::Reserved(0x1f));
+        assert_eq!(op.unit_id, 0x07);
+        assert_eq!(op.company_id, [0x00, 0x07, 0xf5]);
+    }
+
+    #[test]
+    fn subunitinfo_bebob_resp() {
+        // The response from BridgeCo. BeBoB unit, which has one audio and one music subunits.
+        let operands = [0x07, 0x08, 0x60, 0xff, 0xff];
+        let mut op = SubunitInfo::new(0, 7);
+        AvcStatus::parse_operands(&mut op, &AvcAddr::Unit, &operands).unwrap();
+        assert_eq!(op.page, 0x00);
+        assert_eq!(op.extension_code, 0x07);
+        assert_eq!(
+            op.entries,
+            vec![
+                SubunitInfoEntry::new(AvcSubunitType::Audio, 0x00),
+                SubunitInfoEntry::new(AvcSubunitType::Music, 0x00),
+            ]
+        );
+        assert_eq!(
+            op.subunits(),
+            vec![
+                AvcAddrSubunit::new(AvcSubunitType::Audio, 0),
+                AvcAddrSubunit::new(AvcSubunitType::Music, 0),
+            ]
+        );
+
+        let operands = [0x07, 0x08, 0x60];
+        let mut op = SubunitInfo::new(0, 7);
+        assert_eq!(
+            AvcStatus::parse_operands(&mut op, &AvcAddr::Unit, &operands),
+            Err(AvcRespParseError::TooShortResp(5))
+        );
+    }
+
     #[test]
     fn vendor_dependent_operands() {
         let company_id = [0x00, 0x01, 0x02];
@@ -640,6 +791,89 @@ mod test {
         AvcControl::parse_operands(&mut op, &AvcAddr::Unit, &target).unwrap();
         assert_eq!(op.company_id, company_id);
         assert_eq!(&op.data, &[0xde, 0xad, 0xbe, 0xef]);
+
+        let operands = [0x00, 0x01, 0x03, 0xde, 0xad, 0xbe, 0xef];
+        assert_eq!(
+            op.check_company_id(&operands),
+            Err(AvcRespParseError::UnexpectedOperands(0))
+        );
+        assert_eq!(op.check_company_id(&target), Ok(()));
+
+        let operands = [0x00, 0x01, 0x02];
+        assert_eq!(
+            AvcControl::parse_operands(&mut op, &AvcAddr::Unit, &operands),
+            Err(AvcRespParseError::TooShortResp(3))
+        );
+    }
+
+    #[test]
+    fn unit_power_operands() {
+        [(PowerState::On, 0x70), (PowerState::Off, 0x60)]
+            .iter()
+            .for_each(|&(state, val)| {
+                let mut op = UnitPower::new(state);
+                let operands = AvcControl::build_operands(&mut op, &AvcAddr::Unit).unwrap();
+                assert_eq!(&operands, &[val]);
+                AvcControl::parse_operands(&mut op, &AvcAddr::Unit, &operands).unwrap();
+                assert_eq!(op.state, state);
+
+                let mut op = UnitPower::default();
+                let operands = AvcStatus::build_operands(&mut op, &AvcAddr::Unit).unwrap();
+                assert_eq!(&operands, &[0x7f]);
+                AvcStatus::parse_operands(&mut op, &AvcAddr::Unit, &[val]).unwrap();
+                assert_eq!(op.state, state);
+            });
+
+        let mut op = UnitPower::new(PowerState::Off);
+        assert_eq!(
+            AvcControl::parse_operands(&mut op, &AvcAddr::Unit, &[0x70]),
+            Err(AvcRespParseError::UnexpectedOperands(0))
+        );
+        assert_eq!(
+            AvcStatus::parse_operands(&mut op, &AvcAddr::Unit, &[0x7f]),
+            Err(AvcRespParseError::UnexpectedOperands(0))
+        );
+        assert_eq!(
+            AvcStatus::parse_operands(&mut op, &AvcAddr::Unit, &[]),
+            Err(AvcRespParseError::TooShortResp(1))
+        );
+    }
+
+    struct TestPowerAvc;
+
+    impl Ta1394Avc<String> for TestPowerAvc {
+        fn transaction(&self, command_frame: &[u8], _: u32) -> Result<Vec<u8>, String> {
+            let mut frame = command_frame.to_vec();
+            // The unit does not allow to be powered off.
+            let rcode = match command_frame[3] {
+                0x60 => AvcRespCode::Rejected,
+                0x70 => AvcRespCode::Accepted,
+                _ => {
+                    frame[3] = 0x70;
+                    AvcRespCode::ImplementedStable
+                }
+            };
+            frame[0] = rcode.into();
+            Ok(frame)
+        }
+    }
+
+    #[test]
+    fn unit_power_rejected() {
+        let avc = TestPowerAvc;
+
+        let mut op = UnitPower::new(PowerState::Off);
+        assert_eq!(
+            avc.control(&AvcAddr::Unit, &mut op, 100),
+            Err(Ta1394AvcError::RespParse(AvcRespParseError::Rejected))
+        );
+
+        let mut op = UnitPower::new(PowerState::On);
+        assert_eq!(avc.control(&AvcAddr::Unit, &mut op, 100), Ok(()));
+
+        let mut op = UnitPower::new(PowerState::Off);
+        assert_eq!(avc.status(&AvcAddr::Unit, &mut op, 100), Ok(()));
+        assert_eq!(op.state, PowerState::On);
     }
 
     #[test]
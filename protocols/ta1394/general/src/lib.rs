@@ -4,6 +4,7 @@
 #![doc = include_str!("../README.md")]
 
 pub mod config_rom;
+pub mod descriptor;
 pub mod general;
 
 /// The type of subunit for AV/C address defined by 1394 Trading Association.
@@ -133,6 +134,7 @@ impl AvcAddrSubunit {
     const SUBUNIT_ID_SHIFT: usize = 0;
     const SUBUNIT_ID_MASK: u8 = 0x07;
 
+    /// Instantiate with the type and identifier of subunit. The identifier is masked to 3 bits.
     pub fn new(subunit_type: AvcSubunitType, mut subunit_id: u8) -> Self {
         subunit_id &= Self::SUBUNIT_ID_MASK;
         AvcAddrSubunit {
@@ -140,6 +142,16 @@ impl AvcAddrSubunit {
             subunit_id,
         }
     }
+
+    /// Instantiate with the type and identifier of subunit. An identifier beyond 3 bits results
+    /// in error instead of masking.
+    pub fn try_new(subunit_type: AvcSubunitType, subunit_id: u8) -> Result<Self, AvcCmdBuildError> {
+        if subunit_id > Self::SUBUNIT_ID_MASK {
+            Err(AvcCmdBuildError::InvalidAddress)
+        } else {
+            Ok(Self::new(subunit_type, subunit_id))
+        }
+    }
 }
 
 impl From<u8> for AvcAddrSubunit {
@@ -369,14 +381,30 @@ pub enum AvcRespParseError {
     UnexpectedAddr,
     /// The operation code in response frame is not expected.
     UnexpectedOpcode,
+    /// The response frame is still INTERIM after waiting for the final response.
+    Interim,
     /// Any of operand in response frame is not expected.
     UnexpectedOperands(
         /// The first offset for unexpected operand.
         usize,
     ),
+    /// The target does not implement the command.
+    NotImplemented,
+    /// The target implements the command, but rejects it.
+    Rejected,
 }
 
 impl AvcRespParseError {
+    /// The error for the response code unexpected for the command. NOT IMPLEMENTED and REJECTED
+    /// are distinguished from the others, so that callers can detect the capability of target.
+    pub fn from_unexpected_rcode(rcode: AvcRespCode) -> Self {
+        match rcode {
+            AvcRespCode::NotImplemented => Self::NotImplemented,
+            AvcRespCode::Rejected => Self::Rejected,
+            _ => Self::UnexpectedStatus,
+        }
+    }
+
     /// Add given offset to some enumerations.
     pub fn add_offset(mut self, offset: usize) -> Self {
         match &mut self {
@@ -396,9 +424,12 @@ impl std::fmt::Display for AvcRespParseError {
             Self::UnexpectedStatus => write!(f, "unexpected response status"),
             Self::UnexpectedAddr => write!(f, "unexpected response address"),
             Self::UnexpectedOpcode => write!(f, "unexpected response operation code"),
+            Self::Interim => write!(f, "no final response after interim"),
             Self::UnexpectedOperands(offset) => {
                 write!(f, "unexpected response operands at {}", offset)
             }
+            Self::NotImplemented => write!(f, "command not implemented"),
+            Self::Rejected => write!(f, "command rejected"),
         }
     }
 }
@@ -448,6 +479,47 @@ impl<T: std::fmt::Display + Clone> std::fmt::Display for Ta1394AvcError<T> {
     }
 }
 
+/// The policy to retry AV/C transaction when failing to communicate; e.g. timeout of Function
+/// Control Protocol.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AvcRetryPolicy {
+    /// The total number of attempts, including the first one.
+    pub attempts: usize,
+    /// The interval between attempts in millisecond.
+    pub backoff_ms: u32,
+}
+
+impl Default for AvcRetryPolicy {
+    fn default() -> Self {
+        Self {
+            attempts: 1,
+            backoff_ms: 0,
+        }
+    }
+}
+
+impl AvcRetryPolicy {
+    fn run<T, F>(&self, mut attempt: F) -> Result<(), Ta1394AvcError<T>>
+    where
+        T: std::fmt::Display + Clone,
+        F: FnMut() -> Result<(), Ta1394AvcError<T>>,
+    {
+        let mut count = 1;
+        loop {
+            match attempt() {
+                Err(Ta1394AvcError::CommunicationFailure(_)) if count < self.attempts => {
+                    count += 1;
+                    if self.backoff_ms > 0 {
+                        let duration = std::time::Duration::from_millis(self.backoff_ms as u64);
+                        std::thread::sleep(duration);
+                    }
+                }
+                res => break res,
+            }
+        }
+    }
+}
+
 /// For AV/C transaction defined by 1394 Trading Association.
 pub trait Ta1394Avc<T: std::fmt::Display + Clone> {
     /// The maximum size of frame in both command and response.
@@ -467,6 +539,30 @@ pub trait Ta1394Avc<T: std::fmt::Display + Clone> {
     /// The call of method is expected to yield running processor to wait for the response.
     fn transaction(&self, command_frame: &[u8], timeout_ms: u32) -> Result<Vec<u8>, T>;
 
+    /// Transmit given command frame once and return received response frame, which is expected
+    /// as final result.
+    ///
+    /// The implementation of `transaction` is responsible for waiting for the final response
+    /// after `AvcRespCode::Interim`, thus the response frame which still has it is regarded as
+    /// failure.
+    fn final_transaction(
+        &self,
+        command_frame: &[u8],
+        timeout_ms: u32,
+    ) -> Result<Vec<u8>, Ta1394AvcError<T>> {
+        let response_frame = self
+            .transaction(command_frame, timeout_ms)
+            .map_err(|cause| Ta1394AvcError::CommunicationFailure(cause))?;
+
+        if !response_frame.is_empty()
+            && AvcRespCode::from(response_frame[0] & Self::RESP_CODE_MASK) == AvcRespCode::Interim
+        {
+            Err(Ta1394AvcError::RespParse(AvcRespParseError::Interim))
+        } else {
+            Ok(response_frame)
+        }
+    }
+
     fn compose_command_frame(
         ctype: AvcCmdType,
         addr: &AvcAddr,
@@ -512,13 +608,11 @@ pub trait Ta1394Avc<T: std::fmt::Display + Clone> {
             AvcControl::build_operands(op, addr).map_err(|err| Ta1394AvcError::CmdBuild(err))?;
         let command_frame =
             Self::compose_command_frame(AvcCmdType::Control, addr, O::OPCODE, &operands)?;
-        let response_frame = self
-            .transaction(&command_frame, timeout_ms)
-            .map_err(|cause| Ta1394AvcError::CommunicationFailure(cause))?;
+        let response_frame = self.final_transaction(&command_frame, timeout_ms)?;
         Self::detect_response_operands(&response_frame, addr, O::OPCODE)
             .and_then(|(rcode, operands)| match rcode {
                 AvcRespCode::Accepted => AvcControl::parse_operands(op, addr, &operands),
-                _ => Err(AvcRespParseError::UnexpectedStatus),
+                _ => Err(AvcRespParseError::from_unexpected_rcode(rcode)),
             })
             .map_err(|err| Ta1394AvcError::RespParse(err))
     }
@@ -533,13 +627,11 @@ pub trait Ta1394Avc<T: std::fmt::Display + Clone> {
             AvcStatus::build_operands(op, addr).map_err(|err| Ta1394AvcError::CmdBuild(err))?;
         let command_frame =
             Self::compose_command_frame(AvcCmdType::Status, addr, O::OPCODE, &operands)?;
-        let response_frame = self
-            .transaction(&command_frame, timeout_ms)
-            .map_err(|cause| Ta1394AvcError::CommunicationFailure(cause))?;
+        let response_frame = self.final_transaction(&command_frame, timeout_ms)?;
         Self::detect_response_operands(&response_frame, addr, O::OPCODE)
             .and_then(|(rcode, operands)| match rcode {
                 AvcRespCode::ImplementedStable => AvcStatus::parse_operands(op, addr, &operands),
-                _ => Err(AvcRespParseError::UnexpectedStatus),
+                _ => Err(AvcRespParseError::from_unexpected_rcode(rcode)),
             })
             .map_err(|err| Ta1394AvcError::RespParse(err))
     }
@@ -554,17 +646,42 @@ pub trait Ta1394Avc<T: std::fmt::Display + Clone> {
             AvcControl::build_operands(op, addr).map_err(|err| Ta1394AvcError::CmdBuild(err))?;
         let command_frame =
             Self::compose_command_frame(AvcCmdType::SpecificInquiry, addr, O::OPCODE, &operands)?;
-        let response_frame = self
-            .transaction(&command_frame, timeout_ms)
-            .map_err(|cause| Ta1394AvcError::CommunicationFailure(cause))?;
+        let response_frame = self.final_transaction(&command_frame, timeout_ms)?;
         Self::detect_response_operands(&response_frame, addr, O::OPCODE)
             .and_then(|(rcode, operands)| match rcode {
                 AvcRespCode::ImplementedStable => AvcControl::parse_operands(op, addr, &operands),
-                _ => Err(AvcRespParseError::UnexpectedStatus),
+                _ => Err(AvcRespParseError::from_unexpected_rcode(rcode)),
             })
             .map_err(|err| Ta1394AvcError::RespParse(err))
     }
 
+    /// Perform control operation, with retries according to the policy when failing to
+    /// communicate. The other errors are reported immediately.
+    ///
+    /// The failure includes timeout of response, while the target may have already applied the
+    /// command. The retry can then apply it twice, thus the operation should be idempotent.
+    fn control_with_retry<O: AvcOp + AvcControl>(
+        &self,
+        addr: &AvcAddr,
+        op: &mut O,
+        policy: &AvcRetryPolicy,
+        timeout_ms: u32,
+    ) -> Result<(), Ta1394AvcError<T>> {
+        policy.run(|| self.control(addr, op, timeout_ms))
+    }
+
+    /// Perform status operation, with retries according to the policy when failing to
+    /// communicate. The other errors are reported immediately.
+    fn status_with_retry<O: AvcOp + AvcStatus>(
+        &self,
+        addr: &AvcAddr,
+        op: &mut O,
+        policy: &AvcRetryPolicy,
+        timeout_ms: u32,
+    ) -> Result<(), Ta1394AvcError<T>> {
+        policy.run(|| self.status(addr, op, timeout_ms))
+    }
+
     fn notify<O: AvcOp + AvcNotify>(
         &self,
         addr: &AvcAddr,
@@ -575,13 +692,11 @@ pub trait Ta1394Avc<T: std::fmt::Display + Clone> {
             AvcNotify::build_operands(op, addr).map_err(|err| Ta1394AvcError::CmdBuild(err))?;
         let command_frame =
             Self::compose_command_frame(AvcCmdType::Notify, addr, O::OPCODE, &operands)?;
-        let response_frame = self
-            .transaction(&command_frame, timeout_ms)
-            .map_err(|cause| Ta1394AvcError::CommunicationFailure(cause))?;
+        let response_frame = self.final_transaction(&command_frame, timeout_ms)?;
         Self::detect_response_operands(&response_frame, addr, O::OPCODE)
             .and_then(|(rcode, operands)| match rcode {
                 AvcRespCode::Changed => AvcNotify::parse_operands(op, addr, &operands),
-                _ => Err(AvcRespParseError::UnexpectedStatus),
+                _ => Err(AvcRespParseError::from_unexpected_rcode(rcode)),
             })
             .map_err(|err| Ta1394AvcError::RespParse(err))
     }
@@ -622,6 +737,20 @@ mod test {
         assert_eq!(0x62, u8::from(AvcAddrSubunit::from(0x62)));
     }
 
+    #[test]
+    fn avcaddrsubunit_try_new() {
+        assert_eq!(
+            AvcAddrSubunit::try_new(AvcSubunitType::Audio, 7),
+            Ok(AvcAddrSubunit::new(AvcSubunitType::Audio, 7))
+        );
+        assert_eq!(
+            AvcAddrSubunit::try_new(AvcSubunitType::Audio, 8),
+            Err(AvcCmdBuildError::InvalidAddress)
+        );
+        // The masking constructor wraps around.
+        assert_eq!(AvcAddrSubunit::new(AvcSubunitType::Audio, 8).subunit_id, 0);
+    }
+
     #[test]
     fn avcaddr_from() {
         assert_eq!(AvcAddr::from(0xff), AvcAddr::Unit);
@@ -660,4 +789,175 @@ mod test {
         assert_eq!(0x0f, u8::from(AvcRespCode::from(0x0f)));
         assert_eq!(0xff, u8::from(AvcRespCode::from(0xff)));
     }
+
+    #[derive(Default)]
+    struct InterimAvc {
+        interim_count: usize,
+        count: std::cell::Cell<usize>,
+    }
+
+    impl Ta1394Avc<String> for InterimAvc {
+        fn transaction(&self, command_frame: &[u8], _: u32) -> Result<Vec<u8>, String> {
+            let count = self.count.get();
+            self.count.set(count + 1);
+            let rcode = if count < self.interim_count {
+                AvcRespCode::Interim
+            } else {
+                AvcRespCode::ImplementedStable
+            };
+            let mut frame = command_frame.to_vec();
+            frame[0] = rcode.into();
+            Ok(frame)
+        }
+    }
+
+    struct DummyOp;
+
+    impl AvcOp for DummyOp {
+        const OPCODE: u8 = 0x01;
+    }
+
+    impl AvcStatus for DummyOp {
+        fn build_operands(&mut self, _: &AvcAddr) -> Result<Vec<u8>, AvcCmdBuildError> {
+            Ok(vec![0xff])
+        }
+
+        fn parse_operands(&mut self, _: &AvcAddr, _: &[u8]) -> Result<(), AvcRespParseError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn final_transaction() {
+        let avc = InterimAvc::default();
+        assert_eq!(avc.status(&AvcAddr::Unit, &mut DummyOp, 100), Ok(()));
+        assert_eq!(avc.count.get(), 1);
+
+        let avc = InterimAvc {
+            interim_count: usize::MAX,
+            ..Default::default()
+        };
+        assert_eq!(
+            avc.status(&AvcAddr::Unit, &mut DummyOp, 100),
+            Err(Ta1394AvcError::RespParse(AvcRespParseError::Interim))
+        );
+        assert_eq!(avc.count.get(), 1);
+    }
+
+    struct RcodeAvc(u8);
+
+    impl Ta1394Avc<String> for RcodeAvc {
+        fn transaction(&self, command_frame: &[u8], _: u32) -> Result<Vec<u8>, String> {
+            let mut frame = command_frame.to_vec();
+            frame[0] = self.0;
+            Ok(frame)
+        }
+    }
+
+    #[test]
+    fn unexpected_response_code() {
+        vec![
+            (
+                AvcRespCode::NotImplemented,
+                AvcRespParseError::NotImplemented,
+            ),
+            (AvcRespCode::Rejected, AvcRespParseError::Rejected),
+            (AvcRespCode::Accepted, AvcRespParseError::UnexpectedStatus),
+            (
+                AvcRespCode::InTransition,
+                AvcRespParseError::UnexpectedStatus,
+            ),
+        ]
+        .into_iter()
+        .for_each(|(rcode, cause)| {
+            let avc = RcodeAvc(rcode.into());
+            assert_eq!(
+                avc.status(&AvcAddr::Unit, &mut DummyOp, 100),
+                Err(Ta1394AvcError::RespParse(cause))
+            );
+        });
+
+        let avc = RcodeAvc(AvcRespCode::ImplementedStable.into());
+        assert_eq!(avc.status(&AvcAddr::Unit, &mut DummyOp, 100), Ok(()));
+    }
+
+    // Fail to communicate for the given number of times at first, then respond with the code.
+    #[derive(Default)]
+    struct FlakyAvc {
+        failures: usize,
+        rcode: u8,
+        count: std::cell::Cell<usize>,
+    }
+
+    impl Ta1394Avc<String> for FlakyAvc {
+        fn transaction(&self, command_frame: &[u8], _: u32) -> Result<Vec<u8>, String> {
+            let count = self.count.get();
+            self.count.set(count + 1);
+            if count < self.failures {
+                Err("timeout".to_string())
+            } else {
+                let mut frame = command_frame.to_vec();
+                frame[0] = self.rcode;
+                Ok(frame)
+            }
+        }
+    }
+
+    #[test]
+    fn retry_communication_failure() {
+        let policy = AvcRetryPolicy {
+            attempts: 3,
+            backoff_ms: 1,
+        };
+
+        let avc = FlakyAvc {
+            failures: 2,
+            rcode: AvcRespCode::ImplementedStable.into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            avc.status_with_retry(&AvcAddr::Unit, &mut DummyOp, &policy, 100),
+            Ok(())
+        );
+        assert_eq!(avc.count.get(), 3);
+
+        let avc = FlakyAvc {
+            failures: 3,
+            rcode: AvcRespCode::ImplementedStable.into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            avc.status_with_retry(&AvcAddr::Unit, &mut DummyOp, &policy, 100),
+            Err(Ta1394AvcError::CommunicationFailure("timeout".to_string()))
+        );
+        assert_eq!(avc.count.get(), 3);
+
+        // No retry by default.
+        let avc = FlakyAvc {
+            failures: 1,
+            rcode: AvcRespCode::ImplementedStable.into(),
+            ..Default::default()
+        };
+        assert!(avc
+            .status_with_retry(&AvcAddr::Unit, &mut DummyOp, &Default::default(), 100)
+            .is_err());
+        assert_eq!(avc.count.get(), 1);
+    }
+
+    #[test]
+    fn retry_no_parse_error() {
+        let policy = AvcRetryPolicy {
+            attempts: 3,
+            backoff_ms: 0,
+        };
+        let avc = FlakyAvc {
+            rcode: AvcRespCode::Rejected.into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            avc.status_with_retry(&AvcAddr::Unit, &mut DummyOp, &policy, 100),
+            Err(Ta1394AvcError::RespParse(AvcRespParseError::Rejected))
+        );
+        assert_eq!(avc.count.get(), 1);
+    }
 }
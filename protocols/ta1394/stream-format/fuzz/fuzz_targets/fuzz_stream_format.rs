@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2022 Takashi Sakamoto
+
+//! Fuzz target to parse stream format and to build it again. The parser should not panic, and
+//! the byte sequence built from the parsed stream format should be parsed to the same one.
+
+#![no_main]
+
+use {libfuzzer_sys::fuzz_target, ta1394_avc_stream_format::*};
+
+const LENGTH_MAX: usize = 512;
+
+fuzz_target!(|raw: &[u8]| {
+    if raw.len() > LENGTH_MAX {
+        return;
+    }
+
+    if let Ok(format) = StreamFormat::from_raw(raw) {
+        if let Ok(built) = format.to_raw() {
+            assert_eq!(StreamFormat::from_raw(&built), Ok(format));
+        }
+    }
+});
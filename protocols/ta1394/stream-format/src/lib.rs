@@ -22,6 +22,18 @@ impl Default for Am824MultiBitAudioAttr {
 }
 
 impl Am824MultiBitAudioAttr {
+    const FREQS: [u32; 8] = [22050, 24000, 32000, 44100, 48000, 96000, 176400, 192000];
+
+    /// The list of supported frequencies in ascending order.
+    pub fn all_supported_freqs() -> &'static [u32] {
+        &Self::FREQS
+    }
+
+    /// Whether the frequency is supported.
+    pub fn is_valid_freq(freq: u32) -> bool {
+        Self::FREQS.contains(&freq)
+    }
+
     const FREQ_CODE_22050: u8 = 0x00;
     const FREQ_CODE_24000: u8 = 0x01;
     const FREQ_CODE_32000: u8 = 0x02;
@@ -110,6 +122,20 @@ impl Default for Am824OneBitAudioAttr {
 }
 
 impl Am824OneBitAudioAttr {
+    const FREQS: [u32; 7] = [
+        2048000, 2822400, 3072000, 5644800, 6144000, 11289600, 12288000,
+    ];
+
+    /// The list of supported frequencies in ascending order.
+    pub fn all_supported_freqs() -> &'static [u32] {
+        &Self::FREQS
+    }
+
+    /// Whether the frequency is supported.
+    pub fn is_valid_freq(freq: u32) -> bool {
+        Self::FREQS.contains(&freq)
+    }
+
     const FREQ_CODE_2048000: u8 = 0x00;
     const FREQ_CODE_2822400: u8 = 0x01;
     const FREQ_CODE_3072000: u8 = 0x02;
@@ -178,6 +204,35 @@ impl Am824OneBitAudioAttr {
     }
 }
 
+/// The attribute for MIDI conformant data in AM824 format.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Am824MidiConformantAttr {
+    /// The number of data channels for MIDI conformant data.
+    pub data_channels: u8,
+}
+
+impl Am824MidiConformantAttr {
+    /// The maximum number of MIDI ports multiplexed in a data channel, according to IEC 61883-6.
+    pub const PORTS_PER_DATA_CHANNEL: usize = 8;
+
+    const LENGTH: usize = 2;
+
+    fn from_raw(raw: &[u8]) -> Result<Self, AvcRespParseError> {
+        if raw.len() < Self::LENGTH {
+            Err(AvcRespParseError::TooShortResp(Self::LENGTH))?;
+        }
+
+        Ok(Self {
+            data_channels: raw[0],
+        })
+    }
+
+    /// The maximum number of MIDI ports available in the data channels.
+    pub fn midi_port_count(&self) -> usize {
+        self.data_channels as usize * Self::PORTS_PER_DATA_CHANNEL
+    }
+}
+
 /// The stream type for AM824 format.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Am824Stream {
@@ -275,7 +330,7 @@ impl Am824Stream {
             }
             _ => {
                 let mut r = [0xff; 4];
-                r.copy_from_slice(&raw);
+                r.copy_from_slice(&raw[..Self::LENGTH]);
                 Self::Reserved(r)
             }
         };
@@ -335,6 +390,55 @@ impl Am824Stream {
 
         Ok(raw)
     }
+
+    /// The nominal frequency, if the stream is for audio data.
+    pub fn frequency(&self) -> Option<u32> {
+        match self {
+            Self::Iec60958_3(attrs)
+            | Self::Iec61937_3(attrs)
+            | Self::Iec61937_4(attrs)
+            | Self::Iec61937_5(attrs)
+            | Self::Iec61937_6(attrs)
+            | Self::Iec61937_7(attrs)
+            | Self::MultiBitLinearAudioRaw(attrs)
+            | Self::MultiBitLinearAudioDvd(attrs)
+            | Self::HighPrecisionMultiBitLinearAudio(attrs) => Some(attrs.freq),
+            Self::OneBitAudioPlainRaw(attrs)
+            | Self::OneBitAudioPlainSacd(attrs)
+            | Self::OneBitAudioEncodedRaw(attrs)
+            | Self::OneBitAudioEncodedSacd(attrs) => Some(attrs.freq),
+            Self::MidiConformant(_) | Self::Reserved(_) => None,
+        }
+    }
+
+    /// Whether to support command-based rate control, if the stream is for audio data.
+    pub fn rate_ctl_supported(&self) -> Option<bool> {
+        match self {
+            Self::Iec60958_3(attrs)
+            | Self::Iec61937_3(attrs)
+            | Self::Iec61937_4(attrs)
+            | Self::Iec61937_5(attrs)
+            | Self::Iec61937_6(attrs)
+            | Self::Iec61937_7(attrs)
+            | Self::MultiBitLinearAudioRaw(attrs)
+            | Self::MultiBitLinearAudioDvd(attrs)
+            | Self::HighPrecisionMultiBitLinearAudio(attrs) => Some(attrs.rate_ctl),
+            Self::OneBitAudioPlainRaw(attrs)
+            | Self::OneBitAudioPlainSacd(attrs)
+            | Self::OneBitAudioEncodedRaw(attrs)
+            | Self::OneBitAudioEncodedSacd(attrs) => Some(attrs.rate_ctl),
+            Self::MidiConformant(_) | Self::Reserved(_) => None,
+        }
+    }
+
+    /// Decode the attribute of MIDI conformant data, if the stream is for it.
+    pub fn midi_conformant_attr(&self) -> Option<Am824MidiConformantAttr> {
+        if let Self::MidiConformant(d) = self {
+            Am824MidiConformantAttr::from_raw(d).ok()
+        } else {
+            None
+        }
+    }
 }
 
 /// The stream type for compound AM824 format.
@@ -432,6 +536,41 @@ impl CompoundAm824StreamFormat {
     }
 }
 
+/// The class of stream format in compound AM824.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StreamEntryClass {
+    /// Audio data, including compressed one in IEC 61937.
+    Audio,
+    /// Multiplexed MIDI data.
+    Midi,
+    /// Information for control and synchronization.
+    Control,
+    /// The other data.
+    Data,
+}
+
+impl CompoundAm824StreamFormat {
+    /// Classify the stream format.
+    pub fn classify(&self) -> StreamEntryClass {
+        match self {
+            Self::Iec60958_3
+            | Self::Iec61937_3
+            | Self::Iec61937_4
+            | Self::Iec61937_5
+            | Self::Iec61937_6
+            | Self::Iec61937_7
+            | Self::MultiBitLinearAudioRaw
+            | Self::MultiBitLinearAudioDvd
+            | Self::HighPrecisionMultiBitLinearAudio => StreamEntryClass::Audio,
+            Self::MidiConformant => StreamEntryClass::Midi,
+            Self::SmpteTimeCodeConformant | Self::SampleCount | Self::SyncStream => {
+                StreamEntryClass::Control
+            }
+            Self::AncillaryData | Self::Reserved(_) => StreamEntryClass::Data,
+        }
+    }
+}
+
 /// The entry of stream format.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct CompoundAm824StreamEntry {
@@ -466,6 +605,11 @@ impl CompoundAm824StreamEntry {
     fn to_raw(&self) -> Result<[u8; Self::LENGTH], AvcCmdBuildError> {
         Ok([self.count, self.format.to_val()])
     }
+
+    /// Classify the entry by the stream format.
+    pub fn classify(&self) -> StreamEntryClass {
+        self.format.classify()
+    }
 }
 
 /// Whether to support command-based rate control.
@@ -622,6 +766,106 @@ impl CompoundAm824Stream {
     }
 }
 
+impl CompoundAm824Stream {
+    /// The maximum number of channels in the stream.
+    pub const CHANNEL_COUNT_MAX: usize = 256;
+
+    /// Start building the stream format for the nominal sampling frequency.
+    pub fn builder(freq: u32) -> CompoundAm824StreamBuilder {
+        CompoundAm824StreamBuilder::new(freq)
+    }
+
+    /// The number of audio channels in the stream, except for the other classes of entries.
+    pub fn audio_channel_count(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.classify() == StreamEntryClass::Audio)
+            .fold(0, |total, entry| total + entry.count as usize)
+    }
+
+    /// Whether any entry is for the format with channels.
+    pub fn supports_format(&self, format: CompoundAm824StreamFormat) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| entry.format == format && entry.count > 0)
+    }
+
+    /// The number of channels in the first entry for the format.
+    pub fn format_count(&self, format: CompoundAm824StreamFormat) -> Option<u8> {
+        self.entries
+            .iter()
+            .find(|entry| entry.format == format)
+            .map(|entry| entry.count)
+    }
+}
+
+/// The builder of stream format for compound AM824.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompoundAm824StreamBuilder {
+    freq: u32,
+    sync_src: bool,
+    rate_ctl: RateCtl,
+    entries: Vec<CompoundAm824StreamEntry>,
+}
+
+impl CompoundAm824StreamBuilder {
+    fn new(freq: u32) -> Self {
+        Self {
+            freq,
+            sync_src: Default::default(),
+            rate_ctl: RateCtl::DontCare,
+            entries: Default::default(),
+        }
+    }
+
+    /// Add the entry for the number of channels in the format.
+    pub fn add_entry(mut self, count: u8, format: CompoundAm824StreamFormat) -> Self {
+        self.entries
+            .push(CompoundAm824StreamEntry { count, format });
+        self
+    }
+
+    /// Add the entry for uncompressed linear PCM data stream.
+    pub fn add_pcm(self, count: u8) -> Self {
+        self.add_entry(count, CompoundAm824StreamFormat::MultiBitLinearAudioRaw)
+    }
+
+    /// Add the entry for multiplexed MIDI stream.
+    pub fn add_midi(self, count: u8) -> Self {
+        self.add_entry(count, CompoundAm824StreamFormat::MidiConformant)
+    }
+
+    /// Whether to be the synchronization source.
+    pub fn with_sync_src(mut self, sync_src: bool) -> Self {
+        self.sync_src = sync_src;
+        self
+    }
+
+    /// Whether to support command-based rate control. It is don't care by default.
+    pub fn with_rate_ctl(mut self, rate_ctl: RateCtl) -> Self {
+        self.rate_ctl = rate_ctl;
+        self
+    }
+
+    /// Build the stream format. The total number of channels should not exceed 256.
+    pub fn build(self) -> Result<CompoundAm824Stream, AvcCmdBuildError> {
+        let total = self
+            .entries
+            .iter()
+            .fold(0, |total, entry| total + entry.count as usize);
+        if total > CompoundAm824Stream::CHANNEL_COUNT_MAX {
+            Err(AvcCmdBuildError::InvalidOperands)?;
+        }
+
+        Ok(CompoundAm824Stream {
+            freq: self.freq,
+            sync_src: self.sync_src,
+            rate_ctl: self.rate_ctl,
+            entries: self.entries,
+        })
+    }
+}
+
 /// The type of stream format in Audio and Music hierarchy root.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum AmStream {
@@ -697,7 +941,7 @@ impl AmStream {
                 raw.append(&mut r);
             }
             AmStream::Reserved(d) => {
-                raw.copy_from_slice(d);
+                raw.extend_from_slice(d);
             }
         }
         Ok(raw)
@@ -751,7 +995,8 @@ impl StreamFormat {
         }
     }
 
-    fn from_raw(raw: &[u8]) -> Result<Self, AvcRespParseError> {
+    /// Parse the stream format from the byte sequence.
+    pub fn from_raw(raw: &[u8]) -> Result<Self, AvcRespParseError> {
         if raw.len() < Self::LENGTH_MIN {
             Err(AvcRespParseError::TooShortResp(Self::LENGTH_MIN))?;
         }
@@ -767,7 +1012,8 @@ impl StreamFormat {
         Ok(s)
     }
 
-    fn to_raw(&self) -> Result<Vec<u8>, AvcCmdBuildError> {
+    /// Build the byte sequence for the stream format.
+    pub fn to_raw(&self) -> Result<Vec<u8>, AvcCmdBuildError> {
         let mut raw = Vec::with_capacity(Self::LENGTH_MIN);
         match self {
             StreamFormat::Am(am) => {
@@ -1208,6 +1454,16 @@ impl AvcStatus for ExtendedStreamFormatSingle {
     }
 }
 
+impl AvcNotify for ExtendedStreamFormatSingle {
+    fn build_operands(&mut self, addr: &AvcAddr) -> Result<Vec<u8>, AvcCmdBuildError> {
+        AvcStatus::build_operands(self, addr)
+    }
+
+    fn parse_operands(&mut self, addr: &AvcAddr, operands: &[u8]) -> Result<(), AvcRespParseError> {
+        AvcStatus::parse_operands(self, addr, operands)
+    }
+}
+
 impl AvcControl for ExtendedStreamFormatSingle {
     fn build_operands(&mut self, addr: &AvcAddr) -> Result<Vec<u8>, AvcCmdBuildError> {
         self.op.build_operands(addr).and_then(|mut operands| {
@@ -1294,7 +1550,7 @@ impl AvcStatus for ExtendedStreamFormatList {
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
+    use {crate::*, proptest::prelude::*};
 
     #[test]
     fn am824multibitaudioattr_from() {
@@ -1314,6 +1570,35 @@ mod tests {
         assert_eq!(Ok(raw), attr.to_raw());
     }
 
+    #[test]
+    fn am824audioattr_supported_freqs() {
+        let freqs = Am824MultiBitAudioAttr::all_supported_freqs();
+        assert!(freqs.windows(2).all(|pair| pair[0] < pair[1]));
+        freqs.iter().for_each(|&freq| {
+            assert!(Am824MultiBitAudioAttr::is_valid_freq(freq));
+            let attr = Am824MultiBitAudioAttr {
+                freq,
+                rate_ctl: true,
+            };
+            let raw = attr.to_raw().unwrap();
+            assert_eq!(Am824MultiBitAudioAttr::from_raw(&raw), Ok(attr));
+        });
+        assert!(!Am824MultiBitAudioAttr::is_valid_freq(88200));
+
+        let freqs = Am824OneBitAudioAttr::all_supported_freqs();
+        assert!(freqs.windows(2).all(|pair| pair[0] < pair[1]));
+        freqs.iter().for_each(|&freq| {
+            assert!(Am824OneBitAudioAttr::is_valid_freq(freq));
+            let attr = Am824OneBitAudioAttr {
+                freq,
+                rate_ctl: false,
+            };
+            let raw = attr.to_raw().unwrap();
+            assert_eq!(Am824OneBitAudioAttr::from_raw(&raw), Ok(attr));
+        });
+        assert!(!Am824OneBitAudioAttr::is_valid_freq(48000));
+    }
+
     #[test]
     fn am824stream_from() {
         let raw = [0x06, 0xff, 0x20, 0xff];
@@ -1326,6 +1611,70 @@ mod tests {
         assert_eq!(raw, format.to_raw().unwrap());
     }
 
+    #[test]
+    fn am824stream_frequency() {
+        let multi = Am824MultiBitAudioAttr {
+            freq: 96000,
+            rate_ctl: true,
+        };
+        [
+            Am824Stream::Iec60958_3(multi),
+            Am824Stream::Iec61937_3(multi),
+            Am824Stream::Iec61937_4(multi),
+            Am824Stream::Iec61937_5(multi),
+            Am824Stream::Iec61937_6(multi),
+            Am824Stream::Iec61937_7(multi),
+            Am824Stream::MultiBitLinearAudioRaw(multi),
+            Am824Stream::MultiBitLinearAudioDvd(multi),
+            Am824Stream::HighPrecisionMultiBitLinearAudio(multi),
+        ]
+        .iter()
+        .for_each(|format| {
+            assert_eq!(format.frequency(), Some(96000));
+            assert_eq!(format.rate_ctl_supported(), Some(true));
+        });
+
+        let one = Am824OneBitAudioAttr {
+            freq: 2822400,
+            rate_ctl: false,
+        };
+        [
+            Am824Stream::OneBitAudioPlainRaw(one),
+            Am824Stream::OneBitAudioPlainSacd(one),
+            Am824Stream::OneBitAudioEncodedRaw(one),
+            Am824Stream::OneBitAudioEncodedSacd(one),
+        ]
+        .iter()
+        .for_each(|format| {
+            assert_eq!(format.frequency(), Some(2822400));
+            assert_eq!(format.rate_ctl_supported(), Some(false));
+        });
+
+        [
+            Am824Stream::MidiConformant([0x01, 0xff]),
+            Am824Stream::Reserved([0xff; 4]),
+        ]
+        .iter()
+        .for_each(|format| {
+            assert_eq!(format.frequency(), None);
+            assert_eq!(format.rate_ctl_supported(), None);
+        });
+    }
+
+    #[test]
+    fn am824midiconformantattr_from() {
+        let raw = [0x0d, 0xff, 0x02, 0xff];
+        let format = Am824Stream::from_raw(&raw).unwrap();
+        assert_eq!(format, Am824Stream::MidiConformant([0x02, 0xff]));
+
+        let attr = format.midi_conformant_attr().unwrap();
+        assert_eq!(2, attr.data_channels);
+        assert_eq!(16, attr.midi_port_count());
+
+        let format = Am824Stream::MultiBitLinearAudioRaw(Default::default());
+        assert_eq!(None, format.midi_conformant_attr());
+    }
+
     #[test]
     fn amstream_from() {
         let raw: &[u8] = &[0x00, 0x08, 0xff, 0x40, 0xff];
@@ -1349,6 +1698,11 @@ mod tests {
         let format = AmStream::from_raw(raw).unwrap();
         assert_eq!(AmStream::Fp32, format);
         assert_eq!(raw, format.to_raw().unwrap());
+
+        let format = AmStream::Reserved(vec![0x10, 0x11, 0x12, 0x13, 0x14]);
+        let raw = format.to_raw().unwrap();
+        assert_eq!(raw, vec![0x10, 0x11, 0x12, 0x13, 0x14]);
+        assert_eq!(format, AmStream::from_raw(&raw).unwrap());
     }
 
     #[test]
@@ -1391,6 +1745,27 @@ mod tests {
         assert_eq!(raw, stream_format.to_raw().unwrap());
     }
 
+    #[test]
+    fn streamformat_from_malformed() {
+        assert_eq!(
+            StreamFormat::from_raw(&[]),
+            Err(AvcRespParseError::TooShortResp(1))
+        );
+        assert_eq!(
+            StreamFormat::from_raw(&[0x90]),
+            Err(AvcRespParseError::TooShortResp(5))
+        );
+
+        // Reserved format of AM824 with trailing bytes.
+        let raw = [0x90, 0x00, 0x20, 0xff, 0x00, 0x00, 0xde, 0xad];
+        assert_eq!(
+            StreamFormat::from_raw(&raw),
+            Ok(StreamFormat::Am(AmStream::Am824(Am824Stream::Reserved([
+                0x20, 0xff, 0x00, 0x00
+            ]))))
+        );
+    }
+
     #[test]
     fn compoundam824streamformat_from() {
         assert_eq!(0x00, CompoundAm824StreamFormat::from_val(0x00).to_val());
@@ -1453,6 +1828,184 @@ mod tests {
         assert_eq!(raw, am.to_raw().unwrap());
     }
 
+    #[test]
+    fn compoundam824stream_format_query() {
+        let stream = CompoundAm824Stream::builder(48000)
+            .add_pcm(8)
+            .add_entry(0, CompoundAm824StreamFormat::Iec60958_3)
+            .add_midi(1)
+            .add_pcm(2)
+            .build()
+            .unwrap();
+
+        assert!(stream.supports_format(CompoundAm824StreamFormat::MultiBitLinearAudioRaw));
+        assert!(stream.supports_format(CompoundAm824StreamFormat::MidiConformant));
+        assert!(!stream.supports_format(CompoundAm824StreamFormat::Iec60958_3));
+        assert!(!stream.supports_format(CompoundAm824StreamFormat::SyncStream));
+
+        assert_eq!(
+            stream.format_count(CompoundAm824StreamFormat::MultiBitLinearAudioRaw),
+            Some(8)
+        );
+        assert_eq!(
+            stream.format_count(CompoundAm824StreamFormat::MidiConformant),
+            Some(1)
+        );
+        assert_eq!(
+            stream.format_count(CompoundAm824StreamFormat::Iec60958_3),
+            Some(0)
+        );
+        assert_eq!(
+            stream.format_count(CompoundAm824StreamFormat::SyncStream),
+            None
+        );
+    }
+
+    #[test]
+    fn compoundam824stream_builder() {
+        let built = CompoundAm824Stream::builder(48000)
+            .add_pcm(8)
+            .add_midi(1)
+            .with_sync_src(true)
+            .build()
+            .unwrap();
+
+        let expected = CompoundAm824Stream {
+            freq: 48000,
+            sync_src: true,
+            rate_ctl: RateCtl::DontCare,
+            entries: vec![
+                CompoundAm824StreamEntry {
+                    count: 8,
+                    format: CompoundAm824StreamFormat::MultiBitLinearAudioRaw,
+                },
+                CompoundAm824StreamEntry {
+                    count: 1,
+                    format: CompoundAm824StreamFormat::MidiConformant,
+                },
+            ],
+        };
+        assert_eq!(expected, built);
+
+        let raw = built.to_raw().unwrap();
+        assert_eq!(expected.to_raw().unwrap(), raw);
+        assert_eq!(vec![0x04, 0x05, 0x02, 0x08, 0x06, 0x01, 0x0d], raw);
+
+        let built = CompoundAm824Stream::builder(96000)
+            .add_entry(2, CompoundAm824StreamFormat::Iec60958_3)
+            .add_entry(1, CompoundAm824StreamFormat::SyncStream)
+            .with_rate_ctl(RateCtl::Supported)
+            .build()
+            .unwrap();
+        assert_eq!(
+            vec![0x05, 0x00, 0x02, 0x02, 0x00, 0x01, 0x40],
+            built.to_raw().unwrap()
+        );
+
+        let res = CompoundAm824Stream::builder(48000)
+            .add_pcm(0xff)
+            .add_midi(2)
+            .build();
+        assert_eq!(Err(AvcCmdBuildError::InvalidOperands), res);
+    }
+
+    const COMPOUND_AM824_FREQS: [(u8, u32); 9] = [
+        (0x00, 22050),
+        (0x01, 24000),
+        (0x02, 32000),
+        (0x03, 44100),
+        (0x04, 48000),
+        (0x05, 96000),
+        (0x06, 176400),
+        (0x07, 192000),
+        (0x0a, 88200),
+    ];
+
+    fn compound_am824_freq_code_strategy() -> impl Strategy<Value = u8> {
+        prop_oneof![
+            8 => prop::sample::select(COMPOUND_AM824_FREQS.iter().map(|(code, _)| *code).collect::<Vec<_>>()),
+            1 => any::<u8>(),
+        ]
+    }
+
+    fn compound_am824_format_code_strategy() -> impl Strategy<Value = u8> {
+        prop_oneof![
+            8 => prop::sample::select(vec![
+                0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x0c, 0x0d, 0x0e, 0x0f, 0x10, 0x40,
+            ]),
+            1 => any::<u8>(),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn compoundam824stream_round_trip(
+            freq_code in compound_am824_freq_code_strategy(),
+            sync_src in any::<bool>(),
+            rate_ctl_code in 0..=CompoundAm824Stream::RATE_CTL_MASK,
+            entries in prop::collection::vec(
+                (any::<u8>(), compound_am824_format_code_strategy()),
+                0..=64,
+            ),
+        ) {
+            let mut raw = vec![freq_code, ((sync_src as u8) << 2) | rate_ctl_code, entries.len() as u8];
+            entries
+                .iter()
+                .for_each(|&(count, format_code)| raw.extend_from_slice(&[count, format_code]));
+
+            let freq = COMPOUND_AM824_FREQS
+                .iter()
+                .find(|(code, _)| *code == freq_code)
+                .map(|(_, freq)| *freq);
+
+            if let Some(freq) = freq {
+                let s = CompoundAm824Stream::from_raw(&raw).unwrap();
+                prop_assert_eq!(s.freq, freq);
+                prop_assert_eq!(s.sync_src, sync_src);
+                prop_assert_eq!(s.rate_ctl, RateCtl::from_val(rate_ctl_code));
+                prop_assert_eq!(s.entries.len(), entries.len());
+                for (entry, &(count, format_code)) in s.entries.iter().zip(entries.iter()) {
+                    prop_assert_eq!(entry.count, count);
+                    prop_assert_eq!(entry.format, CompoundAm824StreamFormat::from_val(format_code));
+                }
+                prop_assert_eq!(s.to_raw().unwrap(), raw);
+            } else {
+                prop_assert_eq!(
+                    CompoundAm824Stream::from_raw(&raw),
+                    Err(AvcRespParseError::UnexpectedOperands(0))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn compoundam824stream_audio_channel_count() {
+        let raw = [
+            0x04, 0x05, 0x08, 0x02, 0x00, 0x04, 0x06, 0x01, 0x0d, 0x01, 0x0e, 0x01, 0x0f, 0x02,
+            0x10, 0x01, 0x40, 0x02, 0x0c,
+        ];
+        let stream = CompoundAm824Stream::from_raw(&raw).unwrap();
+
+        assert_eq!(
+            stream
+                .entries
+                .iter()
+                .map(|entry| entry.classify())
+                .collect::<Vec<_>>(),
+            vec![
+                StreamEntryClass::Audio,
+                StreamEntryClass::Audio,
+                StreamEntryClass::Midi,
+                StreamEntryClass::Control,
+                StreamEntryClass::Control,
+                StreamEntryClass::Data,
+                StreamEntryClass::Control,
+                StreamEntryClass::Audio,
+            ]
+        );
+        assert_eq!(stream.audio_channel_count(), 8);
+    }
+
     #[test]
     fn plug_addr_from() {
         // Unit for PCR stream.
@@ -1602,6 +2155,65 @@ mod tests {
         }
     }
 
+    // Respond CHANGED with the format to one transaction. The INTERIM response is handled by FCP
+    // implementation, thus the final response is returned.
+    #[derive(Default)]
+    struct TestNotifyAvc {
+        count: std::cell::Cell<usize>,
+        changed: Vec<u8>,
+    }
+
+    impl Ta1394Avc<String> for TestNotifyAvc {
+        fn transaction(&self, command_frame: &[u8], _: u32) -> Result<Vec<u8>, String> {
+            self.count.set(self.count.get() + 1);
+
+            let mut changed = command_frame[..3].to_vec();
+            changed[0] = AvcRespCode::Changed.into();
+            changed.extend_from_slice(&self.changed);
+            Ok(changed)
+        }
+    }
+
+    #[test]
+    fn single_notify() {
+        let plug_addr = PlugAddr {
+            direction: PlugDirection::Output,
+            mode: PlugAddrMode::Unit(UnitPlugData {
+                unit_type: UnitPlugType::Pcr,
+                plug_id: 0x03,
+            }),
+        };
+        let mut op = ExtendedStreamFormatSingle::new(&plug_addr);
+        let operands = AvcNotify::build_operands(&mut op, &AvcAddr::Unit).unwrap();
+        assert_eq!(&operands, &[0xc0, 0x01, 0x00, 0x00, 0x03, 0xff, 0xff]);
+
+        let avc = TestNotifyAvc {
+            changed: vec![
+                0xc0, 0x01, 0x00, 0x00, 0x03, 0xff, 0x00, 0x90, 0x40, 0x04, 0x00, 0x02, 0x02, 0x06,
+                0x02, 0x00,
+            ],
+            ..Default::default()
+        };
+        avc.notify(&AvcAddr::Unit, &mut op, 100).unwrap();
+        assert_eq!(avc.count.get(), 1);
+        assert_eq!(op.support_status, SupportStatus::Active);
+
+        let format = op.stream_format.as_compound_am824_stream().unwrap();
+        assert_eq!(format.freq, 48000);
+        assert_eq!(format.audio_channel_count(), 4);
+
+        // The notification for the other plug is not accepted.
+        let mut op = ExtendedStreamFormatSingle::new(&plug_addr);
+        let avc = TestNotifyAvc {
+            changed: vec![
+                0xc0, 0x01, 0x00, 0x00, 0x04, 0xff, 0x00, 0x90, 0x40, 0x04, 0x00, 0x02, 0x02, 0x06,
+                0x02, 0x00,
+            ],
+            ..Default::default()
+        };
+        assert!(avc.notify(&AvcAddr::Unit, &mut op, 100).is_err());
+    }
+
     #[test]
     fn list_operands() {
         let plug_addr = PlugAddr {
@@ -90,6 +90,32 @@ impl Default for ClkRate {
     }
 }
 
+impl ClkRate {
+    /// The frequency of media clock in Hz.
+    pub fn as_hz(&self) -> u32 {
+        match self {
+            Self::R44100 => 44100,
+            Self::R48000 => 48000,
+            Self::R88200 => 88200,
+            Self::R96000 => 96000,
+        }
+    }
+}
+
+impl std::convert::TryFrom<u32> for ClkRate {
+    type Error = ();
+
+    fn try_from(freq: u32) -> Result<Self, Self::Error> {
+        match freq {
+            44100 => Ok(Self::R44100),
+            48000 => Ok(Self::R48000),
+            88200 => Ok(Self::R88200),
+            96000 => Ok(Self::R96000),
+            _ => Err(()),
+        }
+    }
+}
+
 /// The parameters of sampling and media clock.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
 pub struct TascamClockParameters {
@@ -887,6 +913,13 @@ pub struct TascamSurfaceIsochState {
     enabled_leds: LedState,
 }
 
+impl TascamSurfaceIsochState {
+    /// The state of LEDs operated by the surface.
+    pub fn enabled_leds(&self) -> &LedState {
+        &self.enabled_leds
+    }
+}
+
 /// The trait to express specification of LEDS for isochronous models.
 pub trait TascamSurfaceLedIsochSpecification {
     const BANK_LEDS: [&'static [u16]; 4];
@@ -896,31 +929,33 @@ impl<O> TascamSurfaceLedOperation<TascamSurfaceIsochState> for O
 where
     O: TascamSurfaceLedIsochSpecification,
 {
-    fn operate_leds(
+    fn operate_leds_batch(
         state: &mut TascamSurfaceIsochState,
-        machine_value: &(MachineItem, ItemValue),
+        changes: &[(MachineItem, ItemValue)],
         req: &mut FwReq,
         node: &mut FwNode,
         timeout_ms: u32,
     ) -> Result<(), Error> {
-        if let (MachineItem::Bank, ItemValue::U16(value)) = machine_value {
-            Self::BANK_LEDS
-                .iter()
-                .enumerate()
-                .try_for_each(|(i, positions)| {
-                    let enable = *value == i as u16;
-                    operate_led_cached(
-                        &mut state.enabled_leds,
-                        req,
-                        node,
-                        positions[0],
-                        enable,
-                        timeout_ms,
-                    )
-                })?;
-        }
+        changes.iter().try_for_each(|machine_value| {
+            if let (MachineItem::Bank, ItemValue::U16(value)) = machine_value {
+                Self::BANK_LEDS
+                    .iter()
+                    .enumerate()
+                    .try_for_each(|(i, positions)| {
+                        let enable = *value == i as u16;
+                        operate_led_cached(
+                            &mut state.enabled_leds,
+                            req,
+                            node,
+                            positions[0],
+                            enable,
+                            timeout_ms,
+                        )
+                    })?;
+            }
 
-        Ok(())
+            Ok(())
+        })
     }
 
     fn clear_leds(
@@ -1131,7 +1166,27 @@ trait SurfaceBankLedOperation {
 
 #[cfg(test)]
 mod test {
-    use super::*;
+    use super::{fw1884::*, *};
+
+    #[test]
+    fn clk_rate_conversion() {
+        use std::convert::TryFrom;
+
+        [
+            (ClkRate::R44100, 44100),
+            (ClkRate::R48000, 48000),
+            (ClkRate::R88200, 88200),
+            (ClkRate::R96000, 96000),
+        ]
+        .iter()
+        .for_each(|&(rate, hz)| {
+            assert_eq!(rate.as_hz(), hz);
+            assert_eq!(ClkRate::try_from(hz), Ok(rate));
+        });
+
+        assert_eq!(ClkRate::try_from(32000), Err(()));
+        assert_eq!(ClkRate::try_from(192000), Err(()));
+    }
 
     #[test]
     fn config_flag_serdes() {
@@ -1170,4 +1225,43 @@ mod test {
 
         assert_eq!(target, orig);
     }
+
+    #[test]
+    fn surface_shifted_items() {
+        let mut state = TascamSurfaceIsochState::default();
+        Fw1884Protocol::init(&mut state);
+
+        // The bit is cleared when the button is pushed.
+        const SHIFT: u32 = 0x80000000;
+        const SAVE: u32 = 0x02000000;
+
+        // Unshifted.
+        let values = Fw1884Protocol::peek(&state, &[], 7, SHIFT | SAVE, SHIFT);
+        assert_eq!(values, vec![(MachineItem::Save, ItemValue::Bool(true))]);
+        let values = Fw1884Protocol::peek(&state, &[], 7, SHIFT, SHIFT | SAVE);
+        assert_eq!(values, vec![(MachineItem::Save, ItemValue::Bool(false))]);
+
+        // Push shift button.
+        let values = Fw1884Protocol::peek(&state, &[], 7, SHIFT | SAVE, SAVE);
+        assert_eq!(values, vec![(MachineItem::Shift, ItemValue::Bool(true))]);
+        values
+            .iter()
+            .for_each(|value| Fw1884Protocol::ack(&mut state, value));
+
+        // Shifted.
+        let values = Fw1884Protocol::peek(&state, &[], 7, SAVE, 0);
+        assert_eq!(values, vec![(MachineItem::Func(0), ItemValue::Bool(true))]);
+        let values = Fw1884Protocol::peek(&state, &[], 7, 0, SAVE);
+        assert_eq!(values, vec![(MachineItem::Func(0), ItemValue::Bool(false))]);
+
+        // Release shift button.
+        let values = Fw1884Protocol::peek(&state, &[], 7, SAVE, SHIFT | SAVE);
+        assert_eq!(values, vec![(MachineItem::Shift, ItemValue::Bool(false))]);
+        values
+            .iter()
+            .for_each(|value| Fw1884Protocol::ack(&mut state, value));
+
+        let values = Fw1884Protocol::peek(&state, &[], 7, SHIFT | SAVE, SHIFT);
+        assert_eq!(values, vec![(MachineItem::Save, ItemValue::Bool(true))]);
+    }
 }
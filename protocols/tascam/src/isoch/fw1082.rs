@@ -368,6 +368,13 @@ pub struct TascamSurfaceFw1082State {
     enabled_leds: LedState,
 }
 
+impl TascamSurfaceFw1082State {
+    /// The state of LEDs operated by the surface.
+    pub fn enabled_leds(&self) -> &LedState {
+        &self.enabled_leds
+    }
+}
+
 const SPECIFIC_ENCODER_MODES: [(SurfaceBoolValue, Fw1082EncoderMode); 3] = [
     (
         SurfaceBoolValue(8, 0x20000000),
@@ -411,63 +418,71 @@ const SPECIFIC_ENCODER_ITEM_LEDS: &[([MachineItem; 3], &[u16])] = &[
 ];
 
 impl TascamSurfaceLedOperation<TascamSurfaceFw1082State> for Fw1082Protocol {
-    fn operate_leds(
+    fn operate_leds_batch(
         state: &mut TascamSurfaceFw1082State,
-        machine_value: &(MachineItem, ItemValue),
+        changes: &[(MachineItem, ItemValue)],
         req: &mut FwReq,
         node: &mut FwNode,
         timeout_ms: u32,
     ) -> Result<(), Error> {
-        if let ItemValue::Bool(value) = machine_value.1 {
-            let curr_idx = SPECIFIC_ENCODER_MODES
-                .iter()
-                .position(|(_, m)| state.mode.eq(m))
-                .unwrap();
-
-            if let Some(positions) = SPECIFIC_ENCODER_ITEM_LEDS
-                .iter()
-                .find(|(items, _)| machine_value.0.eq(&items[curr_idx]))
-                .map(|(_, positions)| positions)
-            {
-                operate_led_cached(
-                    &mut state.enabled_leds,
-                    req,
-                    node,
-                    positions[0],
-                    value,
-                    timeout_ms,
-                )?;
-            }
-        } else if let (MachineItem::EncoderMode, ItemValue::U16(value)) = machine_value {
-            let idx = *value as usize;
-
-            // One of encode modes should be activated.
-            SPECIFIC_ENCODER_MODE_LEDS
-                .iter()
-                .enumerate()
-                .try_for_each(|(i, (_, positions))| {
+        changes.iter().try_for_each(|machine_value| {
+            if let ItemValue::Bool(value) = machine_value.1 {
+                let curr_idx = SPECIFIC_ENCODER_MODES
+                    .iter()
+                    .position(|(_, m)| state.mode.eq(m))
+                    .unwrap();
+
+                if let Some(positions) = SPECIFIC_ENCODER_ITEM_LEDS
+                    .iter()
+                    .find(|(items, _)| machine_value.0.eq(&items[curr_idx]))
+                    .map(|(_, positions)| positions)
+                {
                     operate_led_cached(
                         &mut state.enabled_leds,
                         req,
                         node,
                         positions[0],
-                        i == idx,
+                        value,
                         timeout_ms,
-                    )
-                })?;
-
-            // Recover the state of button LEDs.
-            let enabled_leds = &mut state.enabled_leds;
-            let button_states = &state.button_states;
-            SPECIFIC_ENCODER_ITEM_LEDS
-                .iter()
-                .zip(button_states)
-                .try_for_each(|((_, positions), s)| {
-                    operate_led_cached(enabled_leds, req, node, positions[0], s[idx], timeout_ms)
-                })?;
-        }
+                    )?;
+                }
+            } else if let (MachineItem::EncoderMode, ItemValue::U16(value)) = machine_value {
+                let idx = *value as usize;
+
+                // One of encode modes should be activated.
+                SPECIFIC_ENCODER_MODE_LEDS.iter().enumerate().try_for_each(
+                    |(i, (_, positions))| {
+                        operate_led_cached(
+                            &mut state.enabled_leds,
+                            req,
+                            node,
+                            positions[0],
+                            i == idx,
+                            timeout_ms,
+                        )
+                    },
+                )?;
+
+                // Recover the state of button LEDs.
+                let enabled_leds = &mut state.enabled_leds;
+                let button_states = &state.button_states;
+                SPECIFIC_ENCODER_ITEM_LEDS
+                    .iter()
+                    .zip(button_states)
+                    .try_for_each(|((_, positions), s)| {
+                        operate_led_cached(
+                            enabled_leds,
+                            req,
+                            node,
+                            positions[0],
+                            s[idx],
+                            timeout_ms,
+                        )
+                    })?;
+            }
 
-        Ok(())
+            Ok(())
+        })
     }
 
     fn clear_leds(
@@ -626,3 +641,49 @@ impl TascamSurfaceStateOperation<TascamSurfaceFw1082State> for Fw1082Protocol {
 impl FireWireLedOperation for Fw1082Protocol {
     const POSITIONS: &'static [u16] = &[0x8e];
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn peek_rotary(state: &TascamSurfaceFw1082State) -> Vec<(MachineItem, ItemValue)> {
+        Fw1082Protocol::peek(state, &[], 14, 0x00000000, 0x00000100)
+    }
+
+    fn switch_encoder_mode(state: &mut TascamSurfaceFw1082State, flag: u32) {
+        // The button is pushed when the bit is cleared.
+        let machine_values = Fw1082Protocol::peek(state, &[], 8, flag, 0x00000000);
+        machine_values
+            .iter()
+            .for_each(|machine_value| Fw1082Protocol::ack(state, machine_value));
+    }
+
+    #[test]
+    fn encoder_mode_retargets_rotary() {
+        let mut state = TascamSurfaceFw1082State::default();
+        Fw1082Protocol::init(&mut state);
+
+        assert_eq!(
+            peek_rotary(&state),
+            vec![(MachineItem::Gain, ItemValue::U16(0x0100))]
+        );
+
+        switch_encoder_mode(&mut state, 0x40000000);
+        assert_eq!(
+            peek_rotary(&state),
+            vec![(MachineItem::Rotary(0), ItemValue::U16(0x0100))]
+        );
+
+        switch_encoder_mode(&mut state, 0x80000000);
+        assert_eq!(
+            peek_rotary(&state),
+            vec![(MachineItem::Rotary(4), ItemValue::U16(0x0100))]
+        );
+
+        switch_encoder_mode(&mut state, 0x20000000);
+        assert_eq!(
+            peek_rotary(&state),
+            vec![(MachineItem::Gain, ItemValue::U16(0x0100))]
+        );
+    }
+}
@@ -22,7 +22,8 @@
 //! used to parse surface imageg and detect event and operate LED. The latter is used to monitor
 //! current state of each surface item by handling the event, and generate normalized events. It's
 //! task of runtime implementation to prepare converter between the machine event and application
-//! specific message such as ALSA Sequencer and Open Sound Control.
+//! specific message such as ALSA Sequencer and Open Sound Control. The `osc` module includes a
+//! helper for the latter.
 //!
 //! The relationship between the constrol surface, surface state, machine state, and message
 //! converter is illustrated in below diagram:
@@ -62,12 +63,21 @@ pub mod asynch;
 pub mod isoch;
 
 pub mod config_rom;
+pub mod osc;
+
+#[cfg(test)]
+mod mock;
 
 use {
     glib::{Error, FileError},
-    hinawa::{prelude::*, *},
+    hinawa::*,
 };
 
+#[cfg(not(test))]
+use hinawa::prelude::FwReqExtManual;
+#[cfg(test)]
+use mock::FwReqExtManual;
+
 const BASE_OFFSET: u64 = 0xffff00000000;
 const HW_INFO_REGISTER_OFFSET: u64 = 0x00;
 const HW_INFO_FPGA_OFFSET: u64 = 0x04;
@@ -240,6 +250,69 @@ impl Default for MachineItem {
     }
 }
 
+impl MachineItem {
+    /// Whether the item belongs to transport section.
+    pub fn is_transport(&self) -> bool {
+        matches!(
+            self,
+            Self::Rew | Self::Fwd | Self::Stop | Self::Play | Self::Record
+        )
+    }
+
+    /// Whether the item is one of bands in equalizer section.
+    pub fn is_eq_band(&self) -> bool {
+        matches!(self, Self::High | Self::HighMid | Self::LowMid | Self::Low)
+    }
+
+    /// Whether the item is specific to channel.
+    pub fn is_channel_item(&self) -> bool {
+        matches!(
+            self,
+            Self::Ol(_)
+                | Self::Rec(_)
+                | Self::Signal(_)
+                | Self::Rotary(_)
+                | Self::Select(_)
+                | Self::Solo(_)
+                | Self::Mute(_)
+                | Self::Input(_)
+                | Self::Func(_)
+                | Self::Aux(_)
+        )
+    }
+
+    /// The channel of item in channel strip switched by bank, if any.
+    pub fn strip_channel(&self) -> Option<usize> {
+        match self {
+            Self::Ol(ch)
+            | Self::Rec(ch)
+            | Self::Signal(ch)
+            | Self::Rotary(ch)
+            | Self::Select(ch)
+            | Self::Solo(ch)
+            | Self::Mute(ch)
+            | Self::Input(ch) => Some(*ch),
+            // The function and aux buttons are not the part of channel strip.
+            _ => None,
+        }
+    }
+
+    /// The same item in channel strip with the given channel. The other items are returned as is.
+    pub fn with_strip_channel(&self, ch: usize) -> Self {
+        match self {
+            Self::Ol(_) => Self::Ol(ch),
+            Self::Rec(_) => Self::Rec(ch),
+            Self::Signal(_) => Self::Signal(ch),
+            Self::Rotary(_) => Self::Rotary(ch),
+            Self::Select(_) => Self::Select(ch),
+            Self::Solo(_) => Self::Solo(ch),
+            Self::Mute(_) => Self::Mute(ch),
+            Self::Input(_) => Self::Input(ch),
+            _ => *self,
+        }
+    }
+}
+
 impl std::fmt::Display for MachineItem {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
@@ -324,6 +397,23 @@ pub struct MachineState {
     transport: MachineItem,
 }
 
+impl MachineState {
+    /// Compare two states and return the items whose value is changed, with the value in the new
+    /// state. It is useful to operate the minimum number of LEDs.
+    pub fn diff<O: MachineStateOperation>(
+        old: &MachineState,
+        new: &MachineState,
+    ) -> Vec<(MachineItem, ItemValue)> {
+        let old_values = O::get_machine_current_values(old);
+        O::get_machine_current_values(new)
+            .into_iter()
+            .zip(old_values)
+            .filter(|(new_value, old_value)| new_value.ne(old_value))
+            .map(|(new_value, _)| new_value)
+            .collect()
+    }
+}
+
 /// The event of state machine.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ItemValue {
@@ -344,6 +434,9 @@ pub trait MachineStateOperation {
     const BANK_MIN: u16 = BANK_MIN;
     const BANK_MAX: u16 = BANK_MAX;
 
+    /// The number of channel strips switched by bank.
+    const CHANNELS_PER_BANK: usize = 8;
+
     const U16_ITEM_MIN: u16 = 0;
     const U16_ITEM_MAX: u16 = 0xffffu16;
 
@@ -397,6 +490,42 @@ pub trait MachineStateOperation {
         machine_values
     }
 
+    /// The number of logical channels addressed by channel strips over banks.
+    fn logical_channel_count() -> usize {
+        if Self::HAS_BANK {
+            Self::CHANNELS_PER_BANK * (Self::BANK_MAX - Self::BANK_MIN + 1) as usize
+        } else {
+            Self::CHANNELS_PER_BANK
+        }
+    }
+
+    /// Translate the item for channel strip into the item for logical channel according to the
+    /// current bank. The other items are returned as is.
+    fn resolve_banked_channel(state: &MachineState, item: MachineItem) -> MachineItem {
+        match item.strip_channel() {
+            Some(ch) if Self::HAS_BANK => {
+                item.with_strip_channel(ch + state.bank as usize * Self::CHANNELS_PER_BANK)
+            }
+            _ => item,
+        }
+    }
+
+    /// Translate the item for logical channel into the item for channel strip according to the
+    /// current bank. None is returned when the logical channel is out of the current bank. The
+    /// other items are returned as is.
+    fn resolve_strip_channel(state: &MachineState, item: MachineItem) -> Option<MachineItem> {
+        match item.strip_channel() {
+            Some(ch) if Self::HAS_BANK => {
+                if ch / Self::CHANNELS_PER_BANK == state.bank as usize {
+                    Some(item.with_strip_channel(ch % Self::CHANNELS_PER_BANK))
+                } else {
+                    None
+                }
+            }
+            _ => Some(item),
+        }
+    }
+
     fn change_machine_value(
         state: &mut MachineState,
         input: &(MachineItem, ItemValue),
@@ -415,35 +544,22 @@ pub trait MachineStateOperation {
                 });
 
             // One of transport items should be enabled.
-            if Self::HAS_TRANSPORT
-                && Self::TRANSPORT_ITEMS
-                    .iter()
-                    .find(|i| input.0.eq(i))
-                    .is_some()
-            {
-                if input.0 != state.transport {
-                    outputs.push((state.transport, ItemValue::Bool(false)));
-                    outputs.push((input.0, ItemValue::Bool(true)));
-                    state.transport = input.0;
-                }
+            if Self::HAS_TRANSPORT && input.0.is_transport() && input.0 != state.transport {
+                outputs.push((state.transport, ItemValue::Bool(false)));
+                outputs.push((input.0, ItemValue::Bool(true)));
+                state.transport = input.0;
             }
 
             // None of, or one of equalizer band items should be enabled.
-            if Self::EQ_BAND_ITEMS.iter().find(|i| input.0.eq(i)).is_some() {
-                if value {
-                    Self::BOOL_ITEMS
-                        .iter()
-                        .zip(&mut state.bool_items)
-                        .filter(|(i, v)| {
-                            !input.0.eq(i)
-                                && **v
-                                && Self::EQ_BAND_ITEMS.iter().find(|item| item.eq(i)).is_some()
-                        })
-                        .for_each(|(i, v)| {
-                            *v = false;
-                            outputs.push((*i, ItemValue::Bool(*v)));
-                        });
-                }
+            if input.0.is_eq_band() && value {
+                Self::BOOL_ITEMS
+                    .iter()
+                    .zip(&mut state.bool_items)
+                    .filter(|(i, v)| !input.0.eq(i) && **v && i.is_eq_band())
+                    .for_each(|(i, v)| {
+                        *v = false;
+                        outputs.push((*i, ItemValue::Bool(*v)));
+                    });
             }
         } else if let ItemValue::U16(value) = input.1 {
             let _ = Self::U16_ITEMS
@@ -455,11 +571,13 @@ pub trait MachineStateOperation {
                     outputs.push((input.0, ItemValue::U16(*v)));
                 });
 
-            if Self::HAS_BANK && input.0 == MachineItem::Bank {
-                if state.bank != value && value <= Self::BANK_MAX {
-                    state.bank = value;
-                    outputs.push((MachineItem::Bank, ItemValue::U16(state.bank)));
-                }
+            if Self::HAS_BANK
+                && input.0 == MachineItem::Bank
+                && state.bank != value
+                && value <= Self::BANK_MAX
+            {
+                state.bank = value;
+                outputs.push((MachineItem::Bank, ItemValue::U16(state.bank)));
             }
         }
 
@@ -469,12 +587,31 @@ pub trait MachineStateOperation {
 
 /// The trait to operate LED in surface.
 pub trait TascamSurfaceLedOperation<T> {
+    /// Operate LEDs for the machine value.
     fn operate_leds(
         state: &mut T,
         machine_value: &(MachineItem, ItemValue),
         req: &mut FwReq,
         node: &mut FwNode,
         timeout_ms: u32,
+    ) -> Result<(), Error> {
+        Self::operate_leds_batch(
+            state,
+            std::slice::from_ref(machine_value),
+            req,
+            node,
+            timeout_ms,
+        )
+    }
+
+    /// Operate LEDs for the list of machine values in a block. The operation aborts at the first
+    /// error.
+    fn operate_leds_batch(
+        state: &mut T,
+        changes: &[(MachineItem, ItemValue)],
+        req: &mut FwReq,
+        node: &mut FwNode,
+        timeout_ms: u32,
     ) -> Result<(), Error>;
 
     fn clear_leds(
@@ -510,6 +647,13 @@ pub struct TascamSurfaceCommonState {
     enabled_leds: LedState,
 }
 
+impl TascamSurfaceCommonState {
+    /// The state of LEDs operated by the surface.
+    pub fn enabled_leds(&self) -> &LedState {
+        &self.enabled_leds
+    }
+}
+
 /// The trait to express specification of normal LEDs.
 pub trait TascamSurfaceLedNormalSpecification {
     const NORMAL_LEDS: &'static [(&'static [MachineItem], &'static [u16])];
@@ -519,33 +663,35 @@ impl<O> TascamSurfaceLedOperation<TascamSurfaceCommonState> for O
 where
     O: TascamSurfaceLedNormalSpecification,
 {
-    fn operate_leds(
+    fn operate_leds_batch(
         state: &mut TascamSurfaceCommonState,
-        machine_value: &(MachineItem, ItemValue),
+        changes: &[(MachineItem, ItemValue)],
         req: &mut FwReq,
         node: &mut FwNode,
         timeout_ms: u32,
     ) -> Result<(), Error> {
-        if let ItemValue::Bool(value) = machine_value.1 {
-            if let Some((_, positions)) = Self::NORMAL_LEDS.iter().find(|(items, _)| {
-                if items.len() == 1 {
-                    machine_value.0.eq(&items[0])
-                } else {
-                    items.iter().find(|i| machine_value.0.eq(i)).is_some()
+        changes.iter().try_for_each(|machine_value| {
+            if let ItemValue::Bool(value) = machine_value.1 {
+                if let Some((_, positions)) = Self::NORMAL_LEDS.iter().find(|(items, _)| {
+                    if items.len() == 1 {
+                        machine_value.0.eq(&items[0])
+                    } else {
+                        items.iter().find(|i| machine_value.0.eq(i)).is_some()
+                    }
+                }) {
+                    operate_led_cached(
+                        &mut state.enabled_leds,
+                        req,
+                        node,
+                        positions[0],
+                        value,
+                        timeout_ms,
+                    )?;
                 }
-            }) {
-                operate_led_cached(
-                    &mut state.enabled_leds,
-                    req,
-                    node,
-                    positions[0],
-                    value,
-                    timeout_ms,
-                )?;
             }
-        }
 
-        Ok(())
+            Ok(())
+        })
     }
 
     fn clear_leds(
@@ -568,6 +714,9 @@ pub trait TascamSurfaceStateCommonSpecification {
     const ROTARIES: &'static [(SurfaceU16Value, MachineItem)];
     /// The surface faders.
     const FADERS: &'static [(SurfaceBoolValue, SurfaceU16Value, MachineItem)];
+    /// The calibration of surface faders in the same order as `FADERS`. The value of fader without
+    /// calibration is not scaled.
+    const FADER_CALIBRATIONS: &'static [FaderCalibration] = &[];
 }
 
 impl<O> TascamSurfaceStateOperation<TascamSurfaceCommonState> for O
@@ -613,9 +762,13 @@ where
 
         Self::FADERS
             .iter()
-            .filter(|(bool_val, _, _)| detect_bool_action(bool_val, index, before, after))
-            .for_each(|(_, u16_val, item)| {
-                let value = detect_u16_value_in_image(u16_val, image);
+            .enumerate()
+            .filter(|(_, (bool_val, _, _))| detect_bool_action(bool_val, index, before, after))
+            .for_each(|(i, (_, u16_val, item))| {
+                let mut value = detect_u16_value_in_image(u16_val, image);
+                if let Some(calibration) = Self::FADER_CALIBRATIONS.get(i) {
+                    value = calibration.scale(value);
+                }
                 machine_values.push((*item, ItemValue::U16(value)));
             });
 
@@ -641,6 +794,29 @@ pub struct SurfaceBoolValue(usize, u32); // index, mask.
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq)]
 pub struct SurfaceU16Value(usize, u32, usize); // index, mask, shift
 
+/// The range of raw value for physical fader, which does not reach full scale.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FaderCalibration {
+    /// The raw value at the lowest position.
+    pub min: u16,
+    /// The raw value at the highest position.
+    pub max: u16,
+}
+
+impl FaderCalibration {
+    /// Scale the raw value within the range to full range of u16. The raw value out of the range
+    /// is clamped.
+    pub fn scale(&self, raw: u16) -> u16 {
+        if self.max <= self.min {
+            raw
+        } else {
+            let val = (raw.clamp(self.min, self.max) - self.min) as u32;
+            let range = (self.max - self.min) as u32;
+            (val * u16::MAX as u32 / range) as u16
+        }
+    }
+}
+
 fn detect_stateful_bool_action(
     bool_val: &SurfaceBoolValue,
     index: u32,
@@ -670,8 +846,29 @@ fn detect_u16_value_in_image(u16_val: &SurfaceU16Value, image: &[u32]) -> u16 {
     ((image[u16_val.0] & u16_val.1) >> u16_val.2) as u16
 }
 
+/// The state of LEDs in surface.
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
-struct LedState(Vec<u16>);
+pub struct LedState(Vec<u16>);
+
+impl LedState {
+    /// Iterate positions of LEDs currently enabled.
+    pub fn iter_enabled(&self) -> impl Iterator<Item = u16> + '_ {
+        self.0.iter().copied()
+    }
+
+    /// The number of LEDs currently enabled.
+    pub fn count(&self) -> usize {
+        self.0.len()
+    }
+
+    fn update(&mut self, pos: u16, enable: bool) {
+        if !enable {
+            self.0.retain(|&p| p != pos);
+        } else if self.0.iter().find(|&p| *p == pos).is_none() {
+            self.0.push(pos);
+        }
+    }
+}
 
 fn operate_led(
     req: &mut FwReq,
@@ -694,13 +891,7 @@ fn operate_led_cached(
     enable: bool,
     timeout_ms: u32,
 ) -> Result<(), Error> {
-    operate_led(req, node, pos, enable, timeout_ms).map(|_| {
-        if !enable {
-            state.0.retain(|&p| p != pos);
-        } else if state.0.iter().find(|&p| *p == pos).is_none() {
-            state.0.push(pos);
-        }
-    })
+    operate_led(req, node, pos, enable, timeout_ms).map(|_| state.update(pos, enable))
 }
 
 fn clear_leds(
@@ -728,3 +919,255 @@ pub trait FireWireLedOperation {
         operate_led(req, node, Self::POSITIONS[0], enable, timeout_ms)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct TestMachine;
+
+    impl MachineStateOperation for TestMachine {
+        const BOOL_ITEMS: &'static [MachineItem] = &[
+            MachineItem::Mute(0),
+            MachineItem::High,
+            MachineItem::HighMid,
+            MachineItem::LowMid,
+            MachineItem::Low,
+        ];
+        const U16_ITEMS: &'static [MachineItem] = &[MachineItem::Rotary(0)];
+        const HAS_TRANSPORT: bool = true;
+        const HAS_BANK: bool = true;
+    }
+
+    struct TestSurface;
+
+    impl TascamSurfaceStateCommonSpecification for TestSurface {
+        const STATEFUL_ITEMS: &'static [(SurfaceBoolValue, MachineItem)] = &[];
+        const STATELESS_ITEMS: &'static [(SurfaceBoolValue, MachineItem)] = &[];
+        const ROTARIES: &'static [(SurfaceU16Value, MachineItem)] = &[];
+        const FADERS: &'static [(SurfaceBoolValue, SurfaceU16Value, MachineItem)] = &[
+            (
+                SurfaceBoolValue(5, 0x00010000),
+                SurfaceU16Value(0, 0x0000ffff, 0),
+                MachineItem::Input(0),
+            ),
+            (
+                SurfaceBoolValue(5, 0x00020000),
+                SurfaceU16Value(0, 0xffff0000, 16),
+                MachineItem::Input(1),
+            ),
+        ];
+        const FADER_CALIBRATIONS: &'static [FaderCalibration] = &[FaderCalibration {
+            min: 0x0100,
+            max: 0x0f00,
+        }];
+    }
+
+    #[test]
+    fn fader_calibration() {
+        let calibration = FaderCalibration {
+            min: 0x0100,
+            max: 0x0f00,
+        };
+        assert_eq!(calibration.scale(0x0100), 0x0000);
+        assert_eq!(calibration.scale(0x0f00), 0xffff);
+        assert_eq!(calibration.scale(0x0800), 0x7fff);
+        assert_eq!(calibration.scale(0x0000), 0x0000);
+        assert_eq!(calibration.scale(0xffff), 0xffff);
+
+        let mut state = TascamSurfaceCommonState::default();
+        TestSurface::init(&mut state);
+
+        [
+            (0x0100, 0x0000),
+            (0x0f00, 0xffff),
+            (0x00ff, 0x0000),
+            (0x0f01, 0xffff),
+        ]
+        .iter()
+        .for_each(|&(raw, expected)| {
+            let image = [(raw as u32) << 16 | raw as u32];
+            let values = TestSurface::peek(&state, &image, 5, 0x00000000, 0x00030000);
+            assert_eq!(
+                values,
+                vec![
+                    (MachineItem::Input(0), ItemValue::U16(expected)),
+                    // Without calibration.
+                    (MachineItem::Input(1), ItemValue::U16(raw)),
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn machine_item_predicates() {
+        TestMachine::TRANSPORT_ITEMS
+            .iter()
+            .for_each(|item| assert!(item.is_transport()));
+        TestMachine::EQ_BAND_ITEMS
+            .iter()
+            .for_each(|item| assert!(item.is_eq_band()));
+
+        assert!(!MachineItem::Master.is_transport());
+        assert!(!MachineItem::Recall.is_eq_band());
+        assert!(MachineItem::Aux(3).is_channel_item());
+        assert!(!MachineItem::Pfl.is_channel_item());
+        assert!(!MachineItem::Record.is_channel_item());
+    }
+
+    #[test]
+    fn resolve_banked_channel() {
+        let mut state = TestMachine::create_machine_state();
+
+        [
+            (MachineItem::Select(3), MachineItem::Select(3)),
+            (MachineItem::Input(7), MachineItem::Input(7)),
+            (MachineItem::Func(2), MachineItem::Func(2)),
+            (MachineItem::Master, MachineItem::Master),
+        ]
+        .iter()
+        .for_each(|&(item, expected)| {
+            assert_eq!(TestMachine::resolve_banked_channel(&state, item), expected);
+        });
+
+        let outputs =
+            TestMachine::change_machine_value(&mut state, &(MachineItem::Bank, ItemValue::U16(2)));
+        assert_eq!(outputs, vec![(MachineItem::Bank, ItemValue::U16(2))]);
+
+        [
+            (MachineItem::Select(3), MachineItem::Select(19)),
+            (MachineItem::Input(7), MachineItem::Input(23)),
+            (MachineItem::Rec(0), MachineItem::Rec(16)),
+            (MachineItem::Rotary(5), MachineItem::Rotary(21)),
+            (MachineItem::Func(2), MachineItem::Func(2)),
+            (MachineItem::Aux(1), MachineItem::Aux(1)),
+            (MachineItem::Master, MachineItem::Master),
+        ]
+        .iter()
+        .for_each(|&(item, expected)| {
+            assert_eq!(TestMachine::resolve_banked_channel(&state, item), expected);
+            assert_eq!(
+                TestMachine::resolve_strip_channel(&state, expected),
+                Some(item)
+            );
+        });
+
+        [
+            MachineItem::Select(3),
+            MachineItem::Input(15),
+            MachineItem::Rec(24),
+        ]
+        .iter()
+        .for_each(|&item| {
+            assert_eq!(TestMachine::resolve_strip_channel(&state, item), None);
+        });
+    }
+
+    #[test]
+    fn change_machine_value() {
+        let mut state = TestMachine::create_machine_state();
+
+        let outputs = TestMachine::change_machine_value(
+            &mut state,
+            &(MachineItem::Play, ItemValue::Bool(true)),
+        );
+        assert_eq!(
+            outputs,
+            vec![
+                (MachineItem::Stop, ItemValue::Bool(false)),
+                (MachineItem::Play, ItemValue::Bool(true)),
+            ]
+        );
+
+        let outputs = TestMachine::change_machine_value(
+            &mut state,
+            &(MachineItem::High, ItemValue::Bool(true)),
+        );
+        assert_eq!(outputs, vec![(MachineItem::High, ItemValue::Bool(true))]);
+
+        let outputs = TestMachine::change_machine_value(
+            &mut state,
+            &(MachineItem::Low, ItemValue::Bool(true)),
+        );
+        assert_eq!(
+            outputs,
+            vec![
+                (MachineItem::Low, ItemValue::Bool(true)),
+                (MachineItem::High, ItemValue::Bool(false)),
+            ]
+        );
+    }
+
+    #[test]
+    fn machine_state_diff() {
+        let old = TestMachine::create_machine_state();
+        let mut new = old.clone();
+        assert_eq!(MachineState::diff::<TestMachine>(&old, &new), Vec::new());
+
+        let _ = TestMachine::change_machine_value(
+            &mut new,
+            &(MachineItem::Mute(0), ItemValue::Bool(true)),
+        );
+        assert_eq!(
+            MachineState::diff::<TestMachine>(&old, &new),
+            vec![(MachineItem::Mute(0), ItemValue::Bool(true))]
+        );
+    }
+
+    #[test]
+    fn led_state_iter_enabled() {
+        let mut req = FwReq::new();
+        let mut node = FwNode::new();
+        let mut state = LedState::default();
+        assert_eq!(state.count(), 0);
+        assert_eq!(state.iter_enabled().next(), None);
+
+        [(10, true), (3, true), (10, true), (7, true), (3, false)]
+            .iter()
+            .for_each(|&(pos, enable)| {
+                operate_led_cached(&mut state, &mut req, &mut node, pos, enable, 100).unwrap();
+            });
+        assert_eq!(state.count(), 2);
+        assert_eq!(state.iter_enabled().collect::<Vec<u16>>(), vec![10, 7]);
+
+        assert_eq!(
+            mock::take_transactions(),
+            vec![
+                mock::MockTransaction::Write(BASE_OFFSET + LED_OFFSET, 0x0001000a),
+                mock::MockTransaction::Write(BASE_OFFSET + LED_OFFSET, 0x00010003),
+                mock::MockTransaction::Write(BASE_OFFSET + LED_OFFSET, 0x0001000a),
+                mock::MockTransaction::Write(BASE_OFFSET + LED_OFFSET, 0x00010007),
+                mock::MockTransaction::Write(BASE_OFFSET + LED_OFFSET, 0x00000003),
+            ]
+        );
+    }
+
+    #[test]
+    fn surface_common_state_enabled_leds() {
+        use crate::isoch::fw1884::Fw1884Protocol;
+
+        let mut req = FwReq::new();
+        let mut node = FwNode::new();
+        let mut state = TascamSurfaceCommonState::default();
+
+        Fw1884Protocol::operate_leds_batch(
+            &mut state,
+            &[
+                (MachineItem::Ol(1), ItemValue::Bool(true)),
+                (MachineItem::Rec(0), ItemValue::Bool(true)),
+                (MachineItem::Ol(1), ItemValue::Bool(false)),
+            ],
+            &mut req,
+            &mut node,
+            100,
+        )
+        .unwrap();
+        assert_eq!(
+            state.enabled_leds().iter_enabled().collect::<Vec<u16>>(),
+            vec![5]
+        );
+
+        Fw1884Protocol::clear_leds(&mut state, &mut req, &mut node, 100).unwrap();
+        assert_eq!(state.enabled_leds().count(), 0);
+    }
+}
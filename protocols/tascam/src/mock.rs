@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: LGPL-3.0-or-later
+// Copyright (c) 2021 Takashi Sakamoto
+
+//! Mock of asynchronous transaction for unit testing.
+//!
+//! The module provides the trait with the same signature as `hinawa::prelude::FwReqExtManual`.
+//! In test build, it shadows the trait so that the transactions in protocol implementation are
+//! operated against the registers in memory, instead of actual hardware. The registers and the
+//! history of transactions are kept per thread, thus independent between tests.
+
+use {
+    glib::Error,
+    hinawa::{FwNode, FwReq, FwReqError, FwTcode},
+    std::{cell::RefCell, collections::HashMap},
+};
+
+/// The transaction recorded by the mock.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MockTransaction {
+    /// Read transaction with the address.
+    Read(u64),
+    /// Write transaction with the address and the quadlet written.
+    Write(u64, u32),
+}
+
+thread_local! {
+    static REGISTERS: RefCell<HashMap<u64, u32>> = RefCell::new(HashMap::new());
+    static TRANSACTIONS: RefCell<Vec<MockTransaction>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Take the history of transactions since the last call.
+pub fn take_transactions() -> Vec<MockTransaction> {
+    TRANSACTIONS.with(|trxs| trxs.borrow_mut().drain(..).collect())
+}
+
+/// The trait to shadow `hinawa::prelude::FwReqExtManual` in test build.
+pub trait FwReqExtManual {
+    fn transaction(
+        &self,
+        node: &FwNode,
+        tcode: FwTcode,
+        addr: u64,
+        length: usize,
+        frame: &mut [u8],
+        timeout_ms: u32,
+    ) -> Result<(), Error>;
+}
+
+impl FwReqExtManual for FwReq {
+    fn transaction(
+        &self,
+        _: &FwNode,
+        tcode: FwTcode,
+        addr: u64,
+        length: usize,
+        frame: &mut [u8],
+        _: u32,
+    ) -> Result<(), Error> {
+        assert_eq!(length, 4, "The transaction should be for quadlet");
+        assert!(frame.len() >= length, "Lack of frame for the length");
+
+        let mut quadlet = [0; 4];
+
+        match tcode {
+            FwTcode::ReadQuadletRequest => {
+                TRANSACTIONS.with(|trxs| trxs.borrow_mut().push(MockTransaction::Read(addr)));
+                let quad = REGISTERS
+                    .with(|regs| regs.borrow().get(&addr).copied())
+                    .ok_or_else(|| {
+                        let msg = format!("Register not seeded: 0x{:012x}", addr);
+                        Error::new(FwReqError::AddressError, &msg)
+                    })?;
+                frame[..4].copy_from_slice(&quad.to_be_bytes());
+                Ok(())
+            }
+            FwTcode::WriteQuadletRequest => {
+                quadlet.copy_from_slice(&frame[..4]);
+                let quad = u32::from_be_bytes(quadlet);
+                TRANSACTIONS
+                    .with(|trxs| trxs.borrow_mut().push(MockTransaction::Write(addr, quad)));
+                REGISTERS.with(|regs| regs.borrow_mut().insert(addr, quad));
+                Ok(())
+            }
+            _ => Err(Error::new(
+                FwReqError::TypeError,
+                "Unsupported transaction code",
+            )),
+        }
+    }
+}
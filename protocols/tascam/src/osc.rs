@@ -0,0 +1,336 @@
+// SPDX-License-Identifier: LGPL-3.0-or-later
+// Copyright (c) 2021 Takashi Sakamoto
+
+//! Converter from the event of state machine to Open Sound Control message.
+//!
+//! The module includes helper functions to convert the pair of machine item and its value into
+//! Open Sound Control (OSC) message. The address pattern is decided by the item, and the type of
+//! argument is decided by the value. The fader position is converted into `/fader/N` with integer
+//! argument, and the touch of fader is converted into `/fader/N/touch` with boolean argument.
+//!
+//! The reverse conversion is available as well so that the message from application such as DAW
+//! can be used to operate LED in the surface.
+
+use super::*;
+
+/// The argument of OSC message.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum OscArg {
+    /// 32 bit integer, expressed by 'i' type tag.
+    Int(i32),
+    /// Boolean, expressed by 'T' or 'F' type tag without data.
+    Bool(bool),
+}
+
+impl OscArg {
+    /// The type tag of argument.
+    pub fn type_tag(&self) -> char {
+        match self {
+            Self::Int(_) => 'i',
+            Self::Bool(true) => 'T',
+            Self::Bool(false) => 'F',
+        }
+    }
+}
+
+impl From<ItemValue> for OscArg {
+    fn from(value: ItemValue) -> Self {
+        match value {
+            ItemValue::Bool(val) => Self::Bool(val),
+            ItemValue::U16(val) => Self::Int(val as i32),
+        }
+    }
+}
+
+fn item_to_osc_address(item: &MachineItem, value: &ItemValue) -> String {
+    match item {
+        MachineItem::Master => match value {
+            ItemValue::Bool(_) => "/fader/master/touch".to_string(),
+            ItemValue::U16(_) => "/fader/master".to_string(),
+        },
+        MachineItem::Input(ch) => match value {
+            ItemValue::Bool(_) => format!("/fader/{}/touch", ch),
+            ItemValue::U16(_) => format!("/fader/{}", ch),
+        },
+        MachineItem::Ol(ch) => format!("/ol/{}", ch),
+        MachineItem::Rec(ch) => format!("/rec/{}", ch),
+        MachineItem::Signal(ch) => format!("/signal/{}", ch),
+        MachineItem::Rotary(ch) => format!("/rotary/{}", ch),
+        MachineItem::Select(ch) => format!("/select/{}", ch),
+        MachineItem::Solo(ch) => format!("/solo/{}", ch),
+        MachineItem::Mute(ch) => format!("/mute/{}", ch),
+        MachineItem::Func(ch) => format!("/func/{}", ch),
+        MachineItem::Aux(ch) => format!("/aux/{}", ch),
+        MachineItem::Shuttle => "/shuttle".to_string(),
+        MachineItem::EncoderMode => "/encoder-mode".to_string(),
+        // The other items are expressed by their names, with hyphen instead of whitespace.
+        _ => format!("/{}", item.to_string().replace(' ', "-")),
+    }
+}
+
+/// Convert the pair of machine item and its value into the address pattern and the argument of
+/// OSC message.
+pub fn machine_to_osc(item: MachineItem, value: ItemValue) -> (String, OscArg) {
+    (item_to_osc_address(&item, &value), OscArg::from(value))
+}
+
+// The number of aux buttons in surface.
+const AUX_COUNT: usize = 8;
+// The number of function buttons in surface.
+const FUNC_COUNT: usize = 10;
+
+// The items without channel.
+const GLOBAL_ITEMS: &[MachineItem] = &[
+    MachineItem::Master,
+    MachineItem::Pfl,
+    MachineItem::Read,
+    MachineItem::Wrt,
+    MachineItem::Tch,
+    MachineItem::Latch,
+    MachineItem::Wheel,
+    MachineItem::Shuttle,
+    MachineItem::Computer,
+    MachineItem::Clock,
+    MachineItem::Up,
+    MachineItem::Left,
+    MachineItem::Down,
+    MachineItem::Right,
+    MachineItem::NudgeLeft,
+    MachineItem::NudgeRight,
+    MachineItem::LocateLeft,
+    MachineItem::LocateRight,
+    MachineItem::Set,
+    MachineItem::In,
+    MachineItem::Out,
+    MachineItem::Flip,
+    MachineItem::Pan,
+    MachineItem::EncoderMode,
+    MachineItem::High,
+    MachineItem::HighMid,
+    MachineItem::LowMid,
+    MachineItem::Low,
+    MachineItem::Recall,
+    MachineItem::Gain,
+    MachineItem::Freq,
+    MachineItem::Q,
+    MachineItem::Bank,
+    MachineItem::Rew,
+    MachineItem::Fwd,
+    MachineItem::Stop,
+    MachineItem::Play,
+    MachineItem::Record,
+    MachineItem::Panel,
+    MachineItem::Save,
+    MachineItem::Revert,
+    MachineItem::AllSafe,
+    MachineItem::ClrSolo,
+    MachineItem::Markers,
+    MachineItem::Loop,
+    MachineItem::Cut,
+    MachineItem::Del,
+    MachineItem::Copy,
+    MachineItem::Paste,
+    MachineItem::Alt,
+    MachineItem::Cmd,
+    MachineItem::Undo,
+    MachineItem::Shift,
+    MachineItem::Ctrl,
+];
+
+fn osc_arg_to_item_value(arg: &OscArg) -> Option<ItemValue> {
+    match arg {
+        OscArg::Bool(val) => Some(ItemValue::Bool(*val)),
+        OscArg::Int(val) => {
+            if *val >= u16::MIN as i32 && *val <= u16::MAX as i32 {
+                Some(ItemValue::U16(*val as u16))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+fn parse_channel(label: &str, count: usize) -> Option<usize> {
+    label.parse::<usize>().ok().filter(|&ch| ch < count)
+}
+
+/// Convert the address pattern and the argument of OSC message into the pair of machine item and
+/// its value. The channel of item in channel strip is the logical channel over banks of the
+/// machine. The message with unknown address pattern, out-of-range channel, or unexpected type
+/// of argument results in `None`.
+pub fn osc_to_machine<T: MachineStateOperation>(
+    addr: &str,
+    arg: &OscArg,
+) -> Option<(MachineItem, ItemValue)> {
+    let value = osc_arg_to_item_value(arg)?;
+    let labels: Vec<&str> = addr.strip_prefix('/')?.split('/').collect();
+    let channel_count = T::logical_channel_count();
+
+    let item = match labels[..] {
+        ["fader", "master"] => MachineItem::Master,
+        ["fader", "master", "touch"] => MachineItem::Master,
+        ["fader", ch] => MachineItem::Input(parse_channel(ch, channel_count)?),
+        ["fader", ch, "touch"] => MachineItem::Input(parse_channel(ch, channel_count)?),
+        ["ol", ch] => MachineItem::Ol(parse_channel(ch, channel_count)?),
+        ["rec", ch] => MachineItem::Rec(parse_channel(ch, channel_count)?),
+        ["signal", ch] => MachineItem::Signal(parse_channel(ch, channel_count)?),
+        ["rotary", ch] => MachineItem::Rotary(parse_channel(ch, channel_count)?),
+        ["select", ch] => MachineItem::Select(parse_channel(ch, channel_count)?),
+        ["solo", ch] => MachineItem::Solo(parse_channel(ch, channel_count)?),
+        ["mute", ch] => MachineItem::Mute(parse_channel(ch, channel_count)?),
+        ["func", ch] => MachineItem::Func(parse_channel(ch, FUNC_COUNT)?),
+        ["aux", ch] => MachineItem::Aux(parse_channel(ch, AUX_COUNT)?),
+        [_] => *GLOBAL_ITEMS
+            .iter()
+            .find(|item| item_to_osc_address(item, &value) == addr)?,
+        _ => return None,
+    };
+
+    // The address pattern should be consistent with the type of argument.
+    if item_to_osc_address(&item, &value) == addr {
+        Some((item, value))
+    } else {
+        None
+    }
+}
+
+fn append_osc_string(raw: &mut Vec<u8>, s: &str) {
+    raw.extend_from_slice(s.as_bytes());
+    // The string is terminated by null character and padded to multiple of 4 bytes.
+    let padding = 4 - s.len() % 4;
+    raw.resize(raw.len() + padding, 0);
+}
+
+/// Build OSC message with the address pattern and the argument.
+pub fn build_osc_message(address: &str, arg: &OscArg) -> Vec<u8> {
+    let mut raw = Vec::new();
+    append_osc_string(&mut raw, address);
+    append_osc_string(&mut raw, &format!(",{}", arg.type_tag()));
+    if let OscArg::Int(val) = arg {
+        raw.extend_from_slice(&val.to_be_bytes());
+    }
+    raw
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, crate::isoch::fw1884::Fw1884Protocol};
+
+    struct UnbankedMachine;
+
+    impl MachineStateOperation for UnbankedMachine {
+        const BOOL_ITEMS: &'static [MachineItem] = &[];
+        const U16_ITEMS: &'static [MachineItem] = &[];
+        const HAS_TRANSPORT: bool = false;
+        const HAS_BANK: bool = false;
+    }
+
+    #[test]
+    fn bool_item_to_osc() {
+        let (addr, arg) = machine_to_osc(MachineItem::Mute(3), ItemValue::Bool(true));
+        assert_eq!(addr, "/mute/3");
+        assert_eq!(arg, OscArg::Bool(true));
+
+        let (addr, arg) = machine_to_osc(MachineItem::Input(2), ItemValue::Bool(false));
+        assert_eq!(addr, "/fader/2/touch");
+        assert_eq!(arg, OscArg::Bool(false));
+
+        let (addr, arg) = machine_to_osc(MachineItem::NudgeLeft, ItemValue::Bool(true));
+        assert_eq!(addr, "/nudge-left");
+        assert_eq!(arg, OscArg::Bool(true));
+
+        let raw = build_osc_message("/mute/3", &OscArg::Bool(true));
+        assert_eq!(&raw, b"/mute/3\0,T\0\0");
+    }
+
+    #[test]
+    fn u16_item_to_osc() {
+        let (addr, arg) = machine_to_osc(MachineItem::Rotary(5), ItemValue::U16(0x1234));
+        assert_eq!(addr, "/rotary/5");
+        assert_eq!(arg, OscArg::Int(0x1234));
+
+        let (addr, arg) = machine_to_osc(MachineItem::Input(7), ItemValue::U16(0xffff));
+        assert_eq!(addr, "/fader/7");
+        assert_eq!(arg, OscArg::Int(0xffff));
+
+        let raw = build_osc_message("/fader/7", &OscArg::Int(0xffff));
+        assert_eq!(&raw, b"/fader/7\0\0\0\0,i\0\0\0\0\xff\xff");
+    }
+
+    #[test]
+    fn osc_to_machine_valid() {
+        [
+            (MachineItem::Rec(3), ItemValue::Bool(true)),
+            (MachineItem::Func(9), ItemValue::Bool(false)),
+            (MachineItem::Input(0), ItemValue::Bool(true)),
+            (MachineItem::Input(7), ItemValue::U16(0x8000)),
+            (MachineItem::Input(23), ItemValue::U16(0x8000)),
+            (MachineItem::Select(31), ItemValue::Bool(true)),
+            (MachineItem::Aux(7), ItemValue::Bool(true)),
+            (MachineItem::Master, ItemValue::Bool(true)),
+            (MachineItem::Master, ItemValue::U16(0x1234)),
+            (MachineItem::NudgeLeft, ItemValue::Bool(true)),
+            (MachineItem::EncoderMode, ItemValue::Bool(false)),
+            (MachineItem::Wheel, ItemValue::U16(0x0010)),
+        ]
+        .iter()
+        .for_each(|&(item, value)| {
+            let (addr, arg) = machine_to_osc(item, value);
+            assert_eq!(
+                osc_to_machine::<Fw1884Protocol>(&addr, &arg),
+                Some((item, value)),
+                "{}",
+                addr
+            );
+        });
+    }
+
+    #[test]
+    fn osc_to_machine_malformed() {
+        [
+            ("rec/3", OscArg::Bool(true)),
+            ("/rec", OscArg::Bool(true)),
+            ("/rec/", OscArg::Bool(true)),
+            ("/rec/three", OscArg::Bool(true)),
+            ("/rec/-1", OscArg::Bool(true)),
+            ("/rec/3/4", OscArg::Bool(true)),
+            ("/rec/32", OscArg::Bool(true)),
+            ("/func/10", OscArg::Bool(true)),
+            ("/aux/8", OscArg::Bool(true)),
+            ("/fader/32", OscArg::Int(0)),
+            ("/fader/0/touch", OscArg::Int(0)),
+            ("/fader/0", OscArg::Bool(true)),
+            ("/fader/0", OscArg::Int(0x10000)),
+            ("/fader/0", OscArg::Int(-1)),
+            ("/nudge left", OscArg::Bool(true)),
+            ("/unknown", OscArg::Bool(true)),
+            ("/", OscArg::Bool(true)),
+            ("", OscArg::Bool(true)),
+        ]
+        .iter()
+        .for_each(|(addr, arg)| {
+            assert_eq!(
+                osc_to_machine::<Fw1884Protocol>(addr, arg),
+                None,
+                "{}",
+                addr
+            )
+        });
+    }
+
+    #[test]
+    fn osc_to_machine_unbanked() {
+        assert_eq!(
+            osc_to_machine::<UnbankedMachine>("/rec/7", &OscArg::Bool(true)),
+            Some((MachineItem::Rec(7), ItemValue::Bool(true)))
+        );
+        assert_eq!(
+            osc_to_machine::<UnbankedMachine>("/rec/8", &OscArg::Bool(true)),
+            None
+        );
+        assert_eq!(
+            osc_to_machine::<UnbankedMachine>("/fader/8", &OscArg::Int(0)),
+            None
+        );
+    }
+}
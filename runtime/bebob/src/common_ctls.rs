@@ -8,7 +8,7 @@ pub trait MediaClkFreqCtlOperation<T: MediaClockFrequencyOperation> {
     fn state_mut(&mut self) -> &mut MediaClockParameters;
 
     fn load_freq(&mut self, card_cntr: &mut CardCntr) -> Result<Vec<ElemId>, Error> {
-        let labels: Vec<String> = T::FREQ_LIST.iter().map(|&r| r.to_string()).collect();
+        let labels: Vec<String> = (0..T::FREQ_LIST.len()).filter_map(T::freq_name).collect();
 
         let elem_id = ElemId::new_by_name(ElemIfaceType::Card, 0, 0, CLK_RATE_NAME, 0);
         card_cntr.add_enum_elems(&elem_id, 1, 1, &labels, None, true)
@@ -330,7 +330,7 @@ pub trait AvcMuteCtlOperation<T: AvcMuteOperation> {
 pub trait AvcSelectorCtlOperation<T: AvcSelectorOperation> {
     const SELECTOR_NAME: &'static str;
     const SELECTOR_LABELS: &'static [&'static str];
-    const ITEM_LABELS: &'static [&'static str];
+    const ITEM_LABELS: &'static [&'static str] = T::SOURCE_NAMES;
 
     const CH_COUNT: usize = T::FUNC_BLOCK_ID_LIST.len();
 
@@ -106,8 +106,6 @@ impl AvcMuteCtlOperation<FirexonPhysOutputProtocol> for PhysOutputCtl {
 impl AvcSelectorCtlOperation<FirexonPhysOutputProtocol> for PhysOutputCtl {
     const SELECTOR_NAME: &'static str = "analog-output-3/4-source";
     const SELECTOR_LABELS: &'static [&'static str] = &["analog-output-3/4"];
-    const ITEM_LABELS: &'static [&'static str] =
-        &["mixer-output-1/2", "stream-input-3/4", "stream-input-5/6"];
 
     fn state(&self) -> &AvcSelectorParameters {
         &self.3
@@ -200,7 +200,6 @@ impl AvcSelectorCtlOperation<AudiophilePhysOutputProtocol> for PhysOutputCtl {
         "analog-output-3/4",
         "analog-output-5/6",
     ];
-    const ITEM_LABELS: &'static [&'static str] = &["mixer-output", "aux-output-1/2"];
 
     fn state(&self) -> &AvcSelectorParameters {
         &self.1
@@ -239,12 +238,6 @@ impl AvcLevelCtlOperation<AudiophileHeadphoneProtocol> for HeadphoneCtl {
 impl AvcSelectorCtlOperation<AudiophileHeadphoneProtocol> for HeadphoneCtl {
     const SELECTOR_NAME: &'static str = "headphone-source";
     const SELECTOR_LABELS: &'static [&'static str] = &["headphone-1/2"];
-    const ITEM_LABELS: &'static [&'static str] = &[
-        "mixer-output-1/2",
-        "mixer-output-3/4",
-        "mixer-output-5/6",
-        "aux-output-1/2",
-    ];
 
     fn state(&self) -> &AvcSelectorParameters {
         &self.1
@@ -211,7 +211,6 @@ impl AvcSelectorCtlOperation<Fw410PhysOutputProtocol> for PhysOutputCtl {
         "analog-output-7/8",
         "analog-output-9/10",
     ];
-    const ITEM_LABELS: &'static [&'static str] = &["mixer-output", "aux-output-1/2"];
 
     fn state(&self) -> &AvcSelectorParameters {
         &self.1
@@ -255,7 +254,6 @@ impl AvcLevelCtlOperation<Fw410HeadphoneProtocol> for HeadphoneCtl {
 impl AvcSelectorCtlOperation<Fw410HeadphoneProtocol> for HeadphoneCtl {
     const SELECTOR_NAME: &'static str = "headphone-source";
     const SELECTOR_LABELS: &'static [&'static str] = &["headphone-1/2"];
-    const ITEM_LABELS: &'static [&'static str] = &["mixer-output", "aux-output-1/2"];
 
     fn state(&self) -> &AvcSelectorParameters {
         &self.1
@@ -300,7 +298,6 @@ impl Default for SpdifInputCtl {
 impl AvcSelectorCtlOperation<Fw410SpdifOutputProtocol> for SpdifInputCtl {
     const SELECTOR_NAME: &'static str = "S/PDIF-input-source";
     const SELECTOR_LABELS: &'static [&'static str] = &["S/PDIF-input-1/2"];
-    const ITEM_LABELS: &'static [&'static str] = &["coaxial-input-1/2", "optical-input-1/2"];
 
     fn state(&self) -> &AvcSelectorParameters {
         &self.0
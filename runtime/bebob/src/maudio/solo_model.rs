@@ -143,7 +143,6 @@ impl Default for SpdifOutputCtl {
 impl AvcSelectorCtlOperation<SoloSpdifOutputProtocol> for SpdifOutputCtl {
     const SELECTOR_NAME: &'static str = "S/PDIF-output-source";
     const SELECTOR_LABELS: &'static [&'static str] = &["S/PDIF-output-1/2"];
-    const ITEM_LABELS: &'static [&'static str] = &["stream-input-3/4", "mixer-output-3/4"];
 
     fn state(&self) -> &AvcSelectorParameters {
         &self.0
@@ -98,7 +98,6 @@ impl AvcSelectorCtlOperation<FireboxPhysOutputProtocol> for PhysOutputCtl {
         "analog-output-5/6",
         "analog-output-7/8",
     ];
-    const ITEM_LABELS: &'static [&'static str] = &["stream-input", "mixer-output-1/2"];
 
     fn state(&self) -> &AvcSelectorParameters {
         &self.2
@@ -150,13 +149,6 @@ impl AvcMuteCtlOperation<FireboxHeadphoneProtocol> for HeadphoneCtl {
 impl AvcSelectorCtlOperation<FireboxHeadphoneProtocol> for HeadphoneCtl {
     const SELECTOR_NAME: &'static str = "headphone-source";
     const SELECTOR_LABELS: &'static [&'static str] = &["headphone-1/2"];
-    const ITEM_LABELS: &'static [&'static str] = &[
-        "stream-input-1/2",
-        "stream-input-3/4",
-        "stream-input-5/6",
-        "stream-input-7/8",
-        "mixer-output-1/2",
-    ];
 
     fn state(&self) -> &AvcSelectorParameters {
         &self.2
@@ -269,12 +261,6 @@ impl AvcMuteCtlOperation<FireboxMixerStreamSourceProtocol> for MixerStreamSrcCtl
 impl AvcSelectorCtlOperation<FireboxMixerStreamSourceProtocol> for MixerStreamSrcCtl {
     const SELECTOR_NAME: &'static str = "mixer-stream-source";
     const SELECTOR_LABELS: &'static [&'static str] = &["mixer-stream-source-1/2"];
-    const ITEM_LABELS: &'static [&'static str] = &[
-        "stream-input-1/2",
-        "stream-input-3/4",
-        "stream-input-5/6",
-        "stream-input-7/8",
-    ];
 
     fn state(&self) -> &AvcSelectorParameters {
         &self.2
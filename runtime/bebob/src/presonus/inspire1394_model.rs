@@ -221,7 +221,6 @@ impl AvcMuteCtlOperation<Inspire1394PhysOutputProtocol> for PhysOutputCtl {
 impl AvcSelectorCtlOperation<Inspire1394PhysOutputProtocol> for PhysOutputCtl {
     const SELECTOR_NAME: &'static str = "output-source";
     const SELECTOR_LABELS: &'static [&'static str] = &["analog-output-1/2"];
-    const ITEM_LABELS: &'static [&'static str] = &["mixer-output-1/2", "stream-input-1/2"];
 
     fn state(&self) -> &AvcSelectorParameters {
         &self.2
@@ -66,12 +66,6 @@ impl Default for MonitorSourceCtl {
 impl AvcSelectorCtlOperation<AureonMonitorSourceProtocol> for MonitorSourceCtl {
     const SELECTOR_NAME: &'static str = "monitor-source";
     const SELECTOR_LABELS: &'static [&'static str] = &["monitor-source-1/2"];
-    const ITEM_LABELS: &'static [&'static str] = &[
-        "analog-input-1/2",
-        "analog-input-3/4",
-        "analog-input-5/6",
-        "digital-input-1/2",
-    ];
 
     fn state(&self) -> &AvcSelectorParameters {
         &self.0
@@ -143,7 +137,6 @@ impl Default for SpdifOutputCtl {
 impl AvcSelectorCtlOperation<AureonSpdifOutputProtocol> for SpdifOutputCtl {
     const SELECTOR_NAME: &'static str = "spdif-output-source";
     const SELECTOR_LABELS: &'static [&'static str] = &["spdif-output-1/2"];
-    const ITEM_LABELS: &'static [&'static str] = &["mixer-output-1/2", "stream-input-9/10"];
 
     fn state(&self) -> &AvcSelectorParameters {
         &self.0
@@ -55,7 +55,6 @@ impl Default for PhysInputCtl {
 impl AvcSelectorCtlOperation<Phase88PhysInputProtocol> for PhysInputCtl {
     const SELECTOR_NAME: &'static str = "analog-input-7/8-source";
     const SELECTOR_LABELS: &'static [&'static str] = &["analog-intput-7/8"];
-    const ITEM_LABELS: &'static [&'static str] = &["line", "mic"];
 
     fn state(&self) -> &AvcSelectorParameters {
         &self.0
@@ -155,13 +154,6 @@ impl AvcMuteCtlOperation<Phase88MixerStreamSourceProtocol> for MixerStreamSrcCtl
 impl AvcSelectorCtlOperation<Phase88MixerStreamSourceProtocol> for MixerStreamSrcCtl {
     const SELECTOR_NAME: &'static str = "mixer-stream-source";
     const SELECTOR_LABELS: &'static [&'static str] = &["stream-source-1/2"];
-    const ITEM_LABELS: &'static [&'static str] = &[
-        "stream-input-1/2",
-        "stream-input-3/4",
-        "stream-input-5/6",
-        "stream-input-7/8",
-        "stream-input-9/10",
-    ];
 
     fn state(&self) -> &AvcSelectorParameters {
         &self.2
@@ -213,14 +205,6 @@ impl AvcMuteCtlOperation<Phase88MixerOutputProtocol> for MixerOutputCtl {
 impl AvcSelectorCtlOperation<Phase88MixerOutputProtocol> for MixerOutputCtl {
     const SELECTOR_NAME: &'static str = "mixer-output-volume";
     const SELECTOR_LABELS: &'static [&'static str] = &["mixer-output-1/2"];
-    const ITEM_LABELS: &'static [&'static str] = &[
-        "analog-output-1/2",
-        "analog-output-3/4",
-        "analog-output-5/6",
-        "analog-output-7/8",
-        "digital-output-1/2",
-        "unused",
-    ];
 
     fn state(&self) -> &AvcSelectorParameters {
         &self.2
@@ -113,7 +113,6 @@ impl Default for CoaxPhysInputCtl {
 impl AvcSelectorCtlOperation<GoPhase24CoaxPhysInputProtocol> for CoaxPhysInputCtl {
     const SELECTOR_NAME: &'static str = "analog-input-level";
     const SELECTOR_LABELS: &'static [&'static str] = &["analog-input-1/2"];
-    const ITEM_LABELS: &'static [&'static str] = &["low", "middle", "high"];
 
     fn state(&self) -> &AvcSelectorParameters {
         &self.0
@@ -136,14 +135,6 @@ impl Default for CoaxPhysOutputCtl {
 impl AvcSelectorCtlOperation<GoPhase24CoaxPhysOutputProtocol> for CoaxPhysOutputCtl {
     const SELECTOR_NAME: &'static str = "phys-output-source";
     const SELECTOR_LABELS: &'static [&'static str] = &["analog-output-1/2", "analog-output-3/4"];
-    const ITEM_LABELS: &'static [&'static str] = &[
-        "stream-input-1/2",
-        "stream-input-3/4",
-        "analog-input-1/2",
-        "digital-input-1/2",
-        "mixer-output-1/2",
-        "stream-input-5/6",
-    ];
 
     fn state(&self) -> &AvcSelectorParameters {
         &self.0
@@ -166,14 +157,6 @@ impl Default for CoaxHeadphoneCtl {
 impl AvcSelectorCtlOperation<GoPhase24CoaxHeadphoneProtocol> for CoaxHeadphoneCtl {
     const SELECTOR_NAME: &'static str = "headphone-source";
     const SELECTOR_LABELS: &'static [&'static str] = &["headphone-1/2"];
-    const ITEM_LABELS: &'static [&'static str] = &[
-        "stream-input-1/2",
-        "stream-input-3/4",
-        "analog-input-1/2",
-        "digital-input-1/2",
-        "mixer-output-1/2",
-        "stream-input-5/6",
-    ];
 
     fn state(&self) -> &AvcSelectorParameters {
         &self.0
@@ -234,14 +217,6 @@ impl AvcSelectorCtlOperation<GoPhase24OptPhysOutputProtocol> for OptPhysOutputCt
         "analog-output-3/4",
         "digital-output-1/2",
     ];
-    const ITEM_LABELS: &'static [&'static str] = &[
-        "stream-input-1/2",
-        "stream-input-3/4",
-        "analog-input-1/2",
-        "digital-input-1/2",
-        "mixer-output-1/2",
-        "stream-input-5/6",
-    ];
 
     fn state(&self) -> &AvcSelectorParameters {
         &self.2
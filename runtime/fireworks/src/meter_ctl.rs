@@ -138,11 +138,11 @@ where
                 Ok(true)
             }
             MIDI_IN_DETECT_NAME => {
-                elem_value.set_bool(&self.1.detected_midi_inputs[..T::MIDI_INPUT_COUNT]);
+                elem_value.set_bool(&self.1.detected_midi_inputs);
                 Ok(true)
             }
             MIDI_OUT_DETECT_NAME => {
-                elem_value.set_bool(&self.1.detected_midi_outputs[..T::MIDI_OUTPUT_COUNT]);
+                elem_value.set_bool(&self.1.detected_midi_outputs);
                 Ok(true)
             }
             INPUT_METERS_NAME => {
@@ -211,27 +211,7 @@ pub(crate) fn clk_rate_to_str(rate: &ClkRate) -> &'static str {
 }
 
 pub(crate) fn target_port_to_string(port: &TargetPort) -> String {
-    match port {
-        TargetPort::Disabled => "Disabled".to_string(),
-        TargetPort::AnalogPair(ch) => format!("Analog-{}/{}", *ch * 2 + 1, *ch * 2 + 2),
-        TargetPort::AesEbuPair => "AES/EBU-1/2".to_string(),
-        TargetPort::PhonePair => "Phone-1/2".to_string(),
-        TargetPort::MainPair => "Main-1/2".to_string(),
-        TargetPort::SpdifPair => "SPDIF-1/2".to_string(),
-        TargetPort::AdatPair(ch) => format!("ADAT-{}/{}", *ch * 2 + 1, *ch * 2 + 2),
-        TargetPort::Analog6Pairs => "Analog-1/2/3/4/5/6".to_string(),
-        TargetPort::Analog8Pairs => "Analog-1/2/3/4/5/6/7/8".to_string(),
-        TargetPort::OpticalAPair(ch) => format!("Optical-A-{}/{}", *ch + 1, *ch + 2),
-        TargetPort::OpticalBPair(ch) => format!("Optical-B-{}/{}", *ch + 1, *ch + 2),
-        TargetPort::Analog(ch) => format!("Analog-{}", *ch + 1),
-        TargetPort::AesEbu(ch) => format!("AES/EBU-{}", *ch + 1),
-        TargetPort::Phone(ch) => format!("Phone-{}", *ch + 1),
-        TargetPort::Main(ch) => format!("Main-{}", *ch + 1),
-        TargetPort::Spdif(ch) => format!("S/PDIF-{}", *ch + 1),
-        TargetPort::Adat(ch) => format!("ADAT-{}", *ch + 1),
-        TargetPort::OpticalA(ch) => format!("Optical-A-{}", *ch + 1),
-        TargetPort::OpticalB(ch) => format!("Optical-B-{}", *ch + 1),
-    }
+    port.to_string()
 }
 
 pub(crate) fn nominal_signal_level_to_str(level: &NominalSignalLevel) -> &'static str {
@@ -190,7 +190,8 @@ where
             let outputs = T::change_machine_value(self.state_mut(), input);
             debug!(?outputs, ?input);
             outputs.iter().try_for_each(|output| {
-                let event = converter.seq_event_from_machine_event(output)?;
+                let item = T::resolve_banked_channel(self.state(), output.0);
+                let event = converter.seq_event_from_machine_event(&(item, output.1))?;
                 seq_cntr.schedule_event(event)?;
                 self.ack(output, node)
             })
@@ -205,12 +206,18 @@ where
         events: &[Event],
     ) -> Result<(), Error> {
         events.iter().try_for_each(|event| {
-            let input = converter.seq_event_to_machine_event(event)?;
+            let (item, value) = converter.seq_event_to_machine_event(event)?;
+            // The logical channel out of the current bank is not available in channel strip.
+            let input = match T::resolve_strip_channel(self.state(), item) {
+                Some(item) => (item, value),
+                None => return Ok(()),
+            };
             let outputs = T::change_machine_value(self.state_mut(), &input);
             debug!(?outputs, ?input);
             outputs.iter().try_for_each(|output| {
                 if !output.eq(&input) {
-                    let event = converter.seq_event_from_machine_event(output)?;
+                    let item = T::resolve_banked_channel(self.state(), output.0);
+                    let event = converter.seq_event_from_machine_event(&(item, output.1))?;
                     seq_cntr.schedule_event(event)?;
                 }
                 self.ack(output, node)
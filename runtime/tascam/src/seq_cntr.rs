@@ -275,9 +275,19 @@ impl<T: MachineStateOperation> EventConverter<T> {
         &self,
         machine_value: &(MachineItem, ItemValue),
     ) -> Result<Event, Error> {
+        // The bank of logical channel is expressed by the channel of event.
+        let &(machine_item, item_value) = machine_value;
+        let (channel, machine_item) = match machine_item.strip_channel() {
+            Some(ch) if T::HAS_BANK => (
+                (ch / T::CHANNELS_PER_BANK) as u8,
+                machine_item.with_strip_channel(ch % T::CHANNELS_PER_BANK),
+            ),
+            _ => (0, machine_item),
+        };
+
         self.map
             .iter()
-            .position(|item| machine_value.0.eq(item))
+            .position(|item| machine_item.eq(item))
             .ok_or_else(|| {
                 let msg = format!("Unsupported machine item: {}", machine_value.0);
                 Error::new(FileError::Inval, &msg)
@@ -285,7 +295,7 @@ impl<T: MachineStateOperation> EventConverter<T> {
             .and_then(|index| {
                 let param = index as u32;
 
-                let value = match machine_value.1 {
+                let value = match item_value {
                     ItemValue::Bool(val) => {
                         if val {
                             BOOL_TRUE
@@ -299,7 +309,7 @@ impl<T: MachineStateOperation> EventConverter<T> {
                 let mut ev = Event::new(EventType::Controller);
 
                 let mut data = ev.ctl_data()?;
-                data.set_channel(0);
+                data.set_channel(channel);
                 data.set_param(param);
                 data.set_value(value);
 
@@ -319,17 +329,23 @@ impl<T: MachineStateOperation> EventConverter<T> {
 
         // NOTE: At present, controller event is handled for my convenience.
         ev.ctl_data().and_then(|data| {
-            if data.channel() != 0 {
-                let msg = format!("Channel {} is not supported yet.", data.channel());
-                Err(Error::new(FileError::Inval, &msg))?;
-            }
-
             let index = data.param();
             let &machine_item = self.map.iter().nth(index as usize).ok_or_else(|| {
                 let msg = format!("Unsupported control number: {}", index);
                 Error::new(FileError::Inval, &msg)
             })?;
 
+            let channel = data.channel();
+            if channel != 0 {
+                if !T::HAS_BANK
+                    || machine_item.strip_channel().is_none()
+                    || channel as u16 > T::BANK_MAX
+                {
+                    let msg = format!("Channel {} is not supported for {}", channel, machine_item);
+                    Err(Error::new(FileError::Inval, &msg))?;
+                }
+            }
+
             let value = data.value();
             let item_value = if T::BOOL_ITEMS.iter().find(|i| machine_item.eq(i)).is_some() {
                 ItemValue::Bool(value == BOOL_TRUE)
@@ -348,6 +364,13 @@ impl<T: MachineStateOperation> EventConverter<T> {
                 unreachable!();
             };
 
+            let machine_item = match machine_item.strip_channel() {
+                Some(ch) => {
+                    machine_item.with_strip_channel(ch + channel as usize * T::CHANNELS_PER_BANK)
+                }
+                None => machine_item,
+            };
+
             Ok((machine_item, item_value))
         })
     }